@@ -5,4 +5,20 @@ pub(crate) enum Body {
     Text(BodyText),
     Json(BodyJson),
     Binary(BodyBinary),
+    Form(BodyForm),
+    Stream(BodyStream),
+}
+
+/// Where the data of a streamed request body comes from.
+pub(crate) enum BodySource {
+    /// A blocking reader, consumed by the first request that sends it.
+    Reader(Box<dyn Read + Send>),
+    /// An async reader, consumed by the first request that sends it.
+    AsyncReader(Box<dyn AsyncRead + Unpin + Send>),
+    /// A file, opened again each time the request is sent.
+    File(PathBuf),
+    /// A multipart form, encoded again each time the request is sent.
+    Multipart(MultipartForm),
+    /// Opened sources read one after another.
+    Chain(VecDeque<BodySource>),
 }
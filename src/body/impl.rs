@@ -16,6 +16,8 @@ impl Display for Body {
                 serde_json::to_string(json).unwrap_or_else(|_| String::from("{}"))
             ),
             Self::Binary(binary) => write!(f, "{:?}", binary),
+            Self::Form(form) => write!(f, "{}", form),
+            Self::Stream(_) => Ok(()),
         }
     }
 }
@@ -29,6 +31,237 @@ impl Serialize for Body {
             Self::Text(text) => text.serialize(serializer),
             Self::Json(json) => json.serialize(serializer),
             Self::Binary(binary) => binary.serialize(serializer),
+            Self::Form(form) => form.serialize(serializer),
+            Self::Stream(_) => serializer.serialize_unit(),
+        }
+    }
+}
+
+impl Debug for BodyStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BodyStream")
+            .field("length", &self.length)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Two streamed bodies are equal when they share the same source.
+impl PartialEq for BodyStream {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.source, &other.source)
+    }
+}
+
+impl Eq for BodyStream {}
+
+impl BodyStream {
+    pub(crate) fn new(source: BodySource, length: Option<u64>) -> Self {
+        Self {
+            source: Arc::new(Mutex::new(Some(source))),
+            length,
+        }
+    }
+
+    /// Returns the length of the body, reading it from the file system for file bodies.
+    pub(crate) fn get_length(&self) -> Option<u64> {
+        match self.source.lock().ok()?.as_ref()? {
+            BodySource::File(path) => std::fs::metadata(path).ok().map(|metadata| metadata.len()),
+            BodySource::Multipart(multipart_form) => multipart_form.get_length(),
+            _ => self.length,
+        }
+    }
+
+    /// Returns whether the body can be sent again, as file bodies and multipart forms
+    /// without reader parts can.
+    pub(crate) fn is_replayable(&self) -> bool {
+        self.source
+            .lock()
+            .is_ok_and(|source| match source.as_ref() {
+                Some(BodySource::File(_)) => true,
+                Some(BodySource::Multipart(multipart_form)) => multipart_form.is_replayable(),
+                _ => false,
+            })
+    }
+
+    /// Takes the source of the body, leaving file bodies and multipart forms in place to be
+    /// opened again.
+    fn take_source(&self) -> Result<BodySource, RequestError> {
+        let mut source: MutexGuard<'_, Option<BodySource>> = self
+            .source
+            .lock()
+            .map_err(|err| RequestError::Request(err.to_string()))?;
+        match source.take() {
+            Some(BodySource::File(path)) => {
+                *source = Some(BodySource::File(path.clone()));
+                Ok(BodySource::File(path))
+            }
+            Some(BodySource::Multipart(multipart_form)) => {
+                *source = Some(BodySource::Multipart(multipart_form.clone()));
+                Ok(BodySource::Multipart(multipart_form))
+            }
+            Some(body_source) => Ok(body_source),
+            None => Err(RequestError::Request(
+                "request body stream has already been sent and cannot be sent again".to_string(),
+            )),
+        }
+    }
+
+    /// Opens the body for a blocking request.
+    pub(crate) fn open(&self) -> Result<BodyReader, RequestError> {
+        let length: Option<u64> = self.get_length();
+        let source: BodySource = match self.take_source()? {
+            BodySource::File(path) => {
+                BodySource::Reader(Box::new(std::fs::File::open(&path).map_err(|err| {
+                    RequestError::Request(format!("{}: {}", path.display(), err))
+                })?))
+            }
+            BodySource::Multipart(multipart_form) => multipart_form.open()?,
+            source => source,
+        };
+        Ok(BodyReader {
+            source,
+            remaining: length,
+            runtime: None,
+        })
+    }
+
+    /// Opens the body for an async request.
+    pub(crate) async fn open_async(&self) -> Result<BodyReader, RequestError> {
+        let length: Option<u64> = self.get_length();
+        let source: BodySource = match self.take_source()? {
+            BodySource::File(path) => {
+                BodySource::AsyncReader(Box::new(tokio::fs::File::open(&path).await.map_err(
+                    |err| RequestError::Request(format!("{}: {}", path.display(), err)),
+                )?))
+            }
+            BodySource::Multipart(multipart_form) => multipart_form.open_async().await?,
+            source => source,
+        };
+        Ok(BodyReader {
+            source,
+            remaining: length,
+            runtime: None,
+        })
+    }
+}
+
+impl BodyReader {
+    /// Returns how many bytes the next read may return, capped by the remaining length.
+    fn get_read_size(&self, buffer_size: usize) -> usize {
+        match self.remaining {
+            Some(remaining) => buffer_size.min(usize::try_from(remaining).unwrap_or(usize::MAX)),
+            None => buffer_size,
+        }
+    }
+
+    /// Accounts for `n` bytes read, failing when the source ends before the declared length.
+    fn consume(&mut self, n: usize) -> Result<usize, RequestError> {
+        if let Some(remaining) = self.remaining.as_mut() {
+            if n == 0 && *remaining > 0 {
+                return Err(RequestError::Request(format!(
+                    "request body ended {} bytes before its declared length",
+                    remaining
+                )));
+            }
+            *remaining -= n as u64;
+        }
+        Ok(n)
+    }
+
+    /// Reads the next chunk of the body into `buffer`, returning `0` once it is complete.
+    pub(crate) fn read(&mut self, buffer: &mut [u8]) -> Result<usize, RequestError> {
+        let size: usize = self.get_read_size(buffer.len());
+        if size == 0 {
+            return Ok(0);
+        }
+        let result: std::io::Result<usize> = match &mut self.source {
+            BodySource::Chain(sources) => loop {
+                let Some(source) = sources.front_mut() else {
+                    break Ok(0);
+                };
+                match Self::read_source(source, &mut buffer[..size], &mut self.runtime) {
+                    Ok(0) => {
+                        sources.pop_front();
+                    }
+                    result => break result,
+                }
+            },
+            source => Self::read_source(source, &mut buffer[..size], &mut self.runtime),
+        };
+        let n: usize = result.map_err(|err| RequestError::Request(err.to_string()))?;
+        self.consume(n)
+    }
+
+    /// Reads from an opened source, blocking on a runtime created on demand for async readers.
+    fn read_source(
+        source: &mut BodySource,
+        buffer: &mut [u8],
+        runtime: &mut Option<Runtime>,
+    ) -> std::io::Result<usize> {
+        match source {
+            BodySource::Reader(reader) => reader.read(buffer),
+            BodySource::AsyncReader(reader) => {
+                if runtime.is_none() {
+                    *runtime = Some(RuntimeBuilder::new_current_thread().enable_all().build()?);
+                }
+                match runtime.as_ref() {
+                    Some(runtime) => runtime.block_on(reader.read(buffer)),
+                    None => Ok(0),
+                }
+            }
+            _ => Ok(0),
+        }
+    }
+
+    /// Reads the next chunk of the body asynchronously, returning `0` once it is complete.
+    pub(crate) async fn read_async(&mut self, buffer: &mut Vec<u8>) -> Result<usize, RequestError> {
+        let size: usize = self.get_read_size(buffer.len());
+        if size == 0 {
+            return Ok(0);
+        }
+        let result: std::io::Result<usize> = match &mut self.source {
+            BodySource::Chain(sources) => loop {
+                let Some(source) = sources.front_mut() else {
+                    break Ok(0);
+                };
+                match Self::read_source_async(source, buffer, size).await {
+                    Ok(0) => {
+                        sources.pop_front();
+                    }
+                    result => break result,
+                }
+            },
+            source => Self::read_source_async(source, buffer, size).await,
+        };
+        let n: usize = result.map_err(|err| RequestError::Request(err.to_string()))?;
+        self.consume(n)
+    }
+
+    /// Reads up to `size` bytes from an opened source asynchronously.
+    ///
+    /// Blocking readers are read on the blocking thread pool.
+    async fn read_source_async(
+        source: &mut BodySource,
+        buffer: &mut Vec<u8>,
+        size: usize,
+    ) -> std::io::Result<usize> {
+        match source {
+            BodySource::AsyncReader(reader) => reader.read(&mut buffer[..size]).await,
+            BodySource::Reader(reader) => {
+                let mut blocking_reader: Box<dyn Read + Send> =
+                    std::mem::replace(reader, Box::new(std::io::empty()));
+                let mut chunk: Vec<u8> = std::mem::take(buffer);
+                let (blocking_reader, chunk, result) = tokio::task::spawn_blocking(move || {
+                    let result: std::io::Result<usize> = blocking_reader.read(&mut chunk[..size]);
+                    (blocking_reader, chunk, result)
+                })
+                .await
+                .map_err(std::io::Error::other)?;
+                *reader = blocking_reader;
+                *buffer = chunk;
+                result
+            }
+            _ => Ok(0),
         }
     }
 }
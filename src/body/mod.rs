@@ -1,6 +1,8 @@
 pub(crate) mod r#enum;
 pub(crate) mod r#impl;
+pub(crate) mod r#struct;
 pub(crate) mod r#type;
 
 pub(crate) use r#enum::*;
+pub(crate) use r#struct::*;
 pub(crate) use r#type::*;
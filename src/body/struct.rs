@@ -0,0 +1,24 @@
+use crate::*;
+
+/// A request body read from its source while it is being sent.
+///
+/// # Fields
+/// - `source`: The source of the body data, taken when it can only be read once.
+/// - `length`: The length of the body, if known in advance.
+#[derive(Clone)]
+pub(crate) struct BodyStream {
+    pub(crate) source: Arc<Mutex<Option<BodySource>>>,
+    pub(crate) length: Option<u64>,
+}
+
+/// An opened streamed body being read chunk by chunk.
+///
+/// # Fields
+/// - `source`: The reader the body data is read from.
+/// - `remaining`: The number of bytes still expected when the length is known.
+/// - `runtime`: The runtime used to read an async source from a blocking request.
+pub(crate) struct BodyReader {
+    pub(crate) source: BodySource,
+    pub(crate) remaining: Option<u64>,
+    pub(crate) runtime: Option<Runtime>,
+}
@@ -1,6 +1,319 @@
 #[cfg(test)]
 use crate::*;
 
+#[cfg(test)]
+fn read_local_request(stream: &mut TcpStream) -> Vec<u8> {
+    let mut request: Vec<u8> = Vec::new();
+    let mut buffer: [u8; 1024] = [0u8; 1024];
+    loop {
+        if let Some(pos) = SharedResponseHandler::find_double_crlf(&request, 0) {
+            if SharedResponseHandler::is_chunked(&request[..pos + 4]) {
+                if request.len() > pos + 4 && request.ends_with(b"0\r\n\r\n") {
+                    break;
+                }
+            } else {
+                let content_length: usize =
+                    SharedResponseHandler::get_content_length(&request[..pos + 4]);
+                if request.len() >= pos + 4 + content_length {
+                    break;
+                }
+            }
+        }
+        match stream.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => request.extend_from_slice(&buffer[..n]),
+        }
+    }
+    request
+}
+
+#[cfg(test)]
+fn spawn_local_server(responses: Vec<Vec<u8>>) -> (String, JoinHandle<Vec<Vec<u8>>>) {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url: String = format!("http://{}", listener.local_addr().unwrap());
+    let handle: JoinHandle<Vec<Vec<u8>>> = spawn(move || {
+        let mut requests: Vec<Vec<u8>> = Vec::new();
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            stream
+                .set_read_timeout(Some(Duration::from_secs(5)))
+                .unwrap();
+            requests.push(read_local_request(&mut stream));
+            stream.write_all(&response).unwrap();
+            stream.flush().unwrap();
+            let mut rest: [u8; 1024] = [0u8; 1024];
+            while let Ok(n) = stream.read(&mut rest) {
+                if n == 0 {
+                    break;
+                }
+            }
+        }
+        requests
+    });
+    (url, handle)
+}
+
+#[cfg(test)]
+fn spawn_stalled_server(head: Vec<u8>) -> u16 {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port: u16 = listener.local_addr().unwrap().port();
+    spawn(move || {
+        let mut streams: Vec<TcpStream> = Vec::new();
+        for stream in listener.incoming() {
+            let mut stream: TcpStream = stream.unwrap();
+            stream.write_all(&head).unwrap();
+            streams.push(stream);
+        }
+    });
+    port
+}
+
+#[cfg(test)]
+fn spawn_trickle_server(interval: Duration) -> u16 {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port: u16 = listener.local_addr().unwrap().port();
+    spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream: TcpStream = stream.unwrap();
+            spawn(move || {
+                read_local_request(&mut stream);
+                let mut result: std::io::Result<()> =
+                    stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 1000\r\n\r\n");
+                while result.is_ok() {
+                    thread_sleep(interval);
+                    result = stream.write_all(b"a");
+                }
+            });
+        }
+    });
+    port
+}
+
+#[cfg(test)]
+fn spawn_endless_redirect_server() -> String {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url: String = format!("http://{}", listener.local_addr().unwrap());
+    spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream: TcpStream = stream.unwrap();
+            spawn(move || {
+                while let request = read_local_request(&mut stream)
+                    && !request.is_empty()
+                {
+                    if !request.starts_with(b"GET /redirect ") {
+                        let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok");
+                        continue;
+                    }
+                    let mut result: std::io::Result<()> = stream.write_all(
+                        b"HTTP/1.1 302 Found\r\nLocation: /ok\r\nContent-Length: 1099511627776\r\n\r\n",
+                    );
+                    while result.is_ok() {
+                        result = stream.write_all(&[b'a'; 8192]);
+                    }
+                    return;
+                }
+            });
+        }
+    });
+    url
+}
+
+#[cfg(test)]
+fn spawn_keep_alive_server(responses: Vec<Vec<u8>>) -> (String, JoinHandle<usize>) {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url: String = format!("http://{}", listener.local_addr().unwrap());
+    let handle: JoinHandle<usize> = spawn(move || {
+        let mut connections: usize = 0;
+        let mut current: Option<TcpStream> = None;
+        for response in responses {
+            loop {
+                let stream: &mut TcpStream = match current.as_mut() {
+                    Some(stream) => stream,
+                    None => {
+                        let (stream, _) = listener.accept().unwrap();
+                        stream
+                            .set_read_timeout(Some(Duration::from_secs(5)))
+                            .unwrap();
+                        connections += 1;
+                        current.insert(stream)
+                    }
+                };
+                if read_local_request(stream).is_empty() {
+                    current = None;
+                    continue;
+                }
+                stream.write_all(&response).unwrap();
+                break;
+            }
+        }
+        connections
+    });
+    (url, handle)
+}
+
+#[cfg(test)]
+async fn respond_http2(request: Http2Request<RecvStream>, mut respond: SendResponse<Bytes>) {
+    let (parts, mut body) = request.into_parts();
+    let mut request_body: Vec<u8> = Vec::new();
+    while let Some(data) = body.data().await {
+        let data: Bytes = data.unwrap();
+        let _ = body.flow_control().release_capacity(data.len());
+        request_body.extend_from_slice(&data);
+    }
+    if parts.uri.path() == "/redirect" {
+        let response: Response<()> = Response::builder()
+            .status(302)
+            .header("location", "/echo")
+            .body(())
+            .unwrap();
+        respond.send_response(response, true).unwrap();
+        return;
+    }
+    let body: String = format!(
+        "{} {} {} {}",
+        parts.method,
+        parts.uri.authority().unwrap(),
+        parts.uri.path(),
+        String::from_utf8_lossy(&request_body)
+    );
+    let response: Response<()> = Response::builder()
+        .status(200)
+        .header("content-type", "text/plain")
+        .body(())
+        .unwrap();
+    let mut send_stream: SendStream<Bytes> = respond.send_response(response, false).unwrap();
+    send_stream.send_data(Bytes::from(body), false).unwrap();
+    let mut trailers: HeaderMap = HeaderMap::new();
+    trailers.insert("x-trailer", "done".parse().unwrap());
+    send_stream.send_trailers(trailers).unwrap();
+}
+
+#[cfg(test)]
+fn spawn_http2_server(tls: Option<Arc<ServerConfig>>) -> (u16, Arc<AtomicUsize>) {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let port: u16 = listener.local_addr().unwrap().port();
+    let connections: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+    let accepted: Arc<AtomicUsize> = Arc::clone(&connections);
+    spawn(move || {
+        let runtime: Runtime = RuntimeBuilder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async move {
+            let listener: AsyncTcpListener = AsyncTcpListener::from_std(listener).unwrap();
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                accepted.fetch_add(1, Ordering::SeqCst);
+                let tls: Option<Arc<ServerConfig>> = tls.clone();
+                tokio::spawn(async move {
+                    let stream: BoxAsyncReadWrite = match tls {
+                        Some(tls) => Box::new(TlsAcceptor::from(tls).accept(stream).await.unwrap()),
+                        None => Box::new(stream),
+                    };
+                    let mut connection = h2::server::handshake(stream).await.unwrap();
+                    while let Some(Ok((request, respond))) = connection.accept().await {
+                        tokio::spawn(respond_http2(request, respond));
+                    }
+                });
+            }
+        });
+    });
+    (port, connections)
+}
+
+#[cfg(test)]
+fn self_signed_server_config() -> (Certificate, ServerConfig) {
+    let CertifiedKey { cert, signing_key } =
+        generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let server_config: ServerConfig = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(
+            vec![cert.der().clone()],
+            PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(signing_key.serialize_der())),
+        )
+        .unwrap();
+    (cert, server_config)
+}
+
+#[cfg(test)]
+fn spawn_tls_server<F, Fut>(server_config: ServerConfig, handler: F) -> u16
+where
+    F: Fn(BoxAsyncReadWrite) -> Fut + Copy + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let port: u16 = listener.local_addr().unwrap().port();
+    let acceptor: TlsAcceptor = TlsAcceptor::from(Arc::new(server_config));
+    spawn(move || {
+        let runtime: Runtime = RuntimeBuilder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async move {
+            let listener: AsyncTcpListener = AsyncTcpListener::from_std(listener).unwrap();
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let acceptor: TlsAcceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    if let Ok(stream) = acceptor.accept(stream).await {
+                        handler(Box::new(stream)).await;
+                    }
+                });
+            }
+        });
+    });
+    port
+}
+
+#[cfg(test)]
+async fn serve_tls_ok(mut stream: BoxAsyncReadWrite) {
+    let mut request: Vec<u8> = Vec::new();
+    let mut buffer: [u8; 1024] = [0u8; 1024];
+    while !request.ends_with(b"\r\n\r\n") {
+        match stream.read(&mut buffer).await {
+            Ok(0) | Err(_) => return,
+            Ok(size) => request.extend_from_slice(&buffer[..size]),
+        }
+    }
+    let _ = stream
+        .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+        .await;
+    let _ = stream.shutdown().await;
+}
+
+#[cfg(test)]
+async fn serve_connect_tunnel(mut stream: BoxAsyncReadWrite) {
+    let mut request: Vec<u8> = Vec::new();
+    let mut buffer: [u8; 1024] = [0u8; 1024];
+    while !request.ends_with(b"\r\n\r\n") {
+        match stream.read(&mut buffer).await {
+            Ok(0) | Err(_) => return,
+            Ok(size) => request.extend_from_slice(&buffer[..size]),
+        }
+    }
+    let request: String = String::from_utf8_lossy(&request).to_string();
+    let target: &str = request.split_whitespace().nth(1).unwrap_or_default();
+    let Ok(mut upstream) = AsyncTcpStream::connect(target).await else {
+        return;
+    };
+    let _ = stream
+        .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+        .await;
+    let _ = copy_bidirectional(&mut stream, &mut upstream).await;
+}
+
+#[cfg(test)]
+async fn serve_websocket_echo(stream: BoxAsyncReadWrite) {
+    let mut websocket: WebSocketStream<BoxAsyncReadWrite> = accept_async(stream).await.unwrap();
+    while let Some(Ok(message)) = websocket.next().await {
+        if message.is_text() || message.is_binary() {
+            websocket.send(message).await.unwrap();
+        }
+    }
+}
+
 #[tokio::test]
 async fn test_async_http_get_request() {
     let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
@@ -1028,3 +1341,2623 @@ fn test_https_over_socks5_proxy_sync() {
         }
     }
 }
+
+#[test]
+fn test_local_put_request() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+    header.insert("Content-Type", "text/plain");
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .put(&format!("{}/item", url))
+        .text("hello")
+        .headers(header)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    assert_eq!(response.text().get_body(), "ok");
+    let requests: Vec<Vec<u8>> = server.join().unwrap();
+    let request: Cow<'_, str> = String::from_utf8_lossy(&requests[0]);
+    assert!(request.starts_with("PUT /item HTTP/1.1\r\n"));
+    assert!(request.to_lowercase().contains("content-length: 5\r\n"));
+    assert!(request.ends_with("\r\n\r\nhello"));
+}
+
+#[test]
+fn test_local_head_request() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 1024\r\n\r\n".to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .head(&url)
+        .timeout(10000)
+        .http1_1_only()
+        .build_sync();
+    let start_time: Instant = Instant::now();
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    assert!(start_time.elapsed() < Duration::from_secs(5));
+    assert_eq!(response.binary().get_status_code(), 200);
+    assert!(response.binary().get_body().is_empty());
+    let requests: Vec<Vec<u8>> = server.join().unwrap();
+    let request: Cow<'_, str> = String::from_utf8_lossy(&requests[0]);
+    assert!(request.starts_with("HEAD / HTTP/1.1\r\n"));
+    assert!(!request.to_lowercase().contains("content-length"));
+}
+
+#[tokio::test]
+async fn test_local_async_delete_and_patch_request() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+    header.insert("Content-Type", "application/json");
+    let mut delete_request: BoxAsyncRequestTrait = RequestBuilder::new()
+        .delete(&format!("{}/item/1", url))
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    let response: BoxResponseTrait = delete_request.send().await.unwrap();
+    assert_eq!(response.binary().get_status_code(), 204);
+    let mut patch_request: BoxAsyncRequestTrait = RequestBuilder::new()
+        .patch(&format!("{}/item/1", url))
+        .json(json_value!({"name": "value"}))
+        .headers(header)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    let response: BoxResponseTrait = patch_request.send().await.unwrap();
+    assert_eq!(response.binary().get_status_code(), 200);
+    let requests: Vec<Vec<u8>> = server.join().unwrap();
+    let delete: Cow<'_, str> = String::from_utf8_lossy(&requests[0]);
+    assert!(delete.starts_with("DELETE /item/1 HTTP/1.1\r\n"));
+    assert!(!delete.to_lowercase().contains("content-length"));
+    let patch: Cow<'_, str> = String::from_utf8_lossy(&requests[1]);
+    assert!(patch.starts_with("PATCH /item/1 HTTP/1.1\r\n"));
+    assert!(patch.ends_with("{\"name\":\"value\"}"));
+}
+
+#[test]
+fn test_local_chunked_response() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5;name=value\r\nhello\r\n8\r\n\r\nworld!\r\n0\r\nX-Checksum: abc\r\n\r\n"
+            .to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .timeout(10000)
+        .http1_1_only()
+        .build_sync();
+    let start_time: Instant = Instant::now();
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    assert!(start_time.elapsed() < Duration::from_secs(5));
+    assert_eq!(response.binary().get_body(), b"hello\r\nworld!".to_vec());
+    assert_eq!(
+        response.binary().get_headers().get("X-Checksum"),
+        Some(&"abc".to_string())
+    );
+    server.join().unwrap();
+}
+
+#[test]
+fn test_local_framing_ignores_decoy_headers() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nX-Original-Transfer-Encoding: chunked\r\n\
+        X-Original-Content-Length: 100\r\nContent-Length: 2\r\n\r\nok"
+            .to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    assert_eq!(response.binary().get_body(), b"ok".to_vec());
+    drop(request_builder);
+    server.join().unwrap();
+    assert!(!SharedResponseHandler::is_chunked(
+        b"HTTP/1.1 200 OK\r\nX-Original-Transfer-Encoding: chunked\r\n\r\n"
+    ));
+    assert_eq!(
+        SharedResponseHandler::get_content_length(
+            b"HTTP/1.1 200 OK\r\nX-Content-Length: 100\r\nContent-Length: 2\r\n\r\n"
+        ),
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_local_async_chunked_response() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\nA\r\n0123456789\r\n1a\r\nabcdefghijklmnopqrstuvwxyz\r\n0\r\n\r\n"
+            .to_vec(),
+    ]);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .timeout(10000)
+        .http1_1_only()
+        .build_async();
+    let start_time: Instant = Instant::now();
+    let response: BoxResponseTrait = request_builder.send().await.unwrap();
+    assert!(start_time.elapsed() < Duration::from_secs(5));
+    assert_eq!(
+        response.text().get_body(),
+        "0123456789abcdefghijklmnopqrstuvwxyz"
+    );
+    server.join().unwrap();
+}
+
+#[test]
+fn test_client_reuses_keep_alive_connection() {
+    let response: Vec<u8> = b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec();
+    let (url, server) = spawn_keep_alive_server(vec![response.clone(); 3]);
+    let client: Client = Client::new();
+    for _ in 0..3 {
+        let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+            .get(&url)
+            .client(&client)
+            .timeout(4000)
+            .http1_1_only()
+            .build_sync();
+        let response: BoxResponseTrait = request_builder.send().unwrap();
+        assert_eq!(response.text().get_body(), "ok");
+    }
+    assert_eq!(client.idle_connections(), 1);
+    assert_eq!(server.join().unwrap(), 1);
+}
+
+#[test]
+fn test_client_retries_stale_connection() {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url: String = format!("http://{}", listener.local_addr().unwrap());
+    let server: JoinHandle<()> = spawn(move || {
+        for body in ["first", "again"] {
+            let (mut stream, _) = listener.accept().unwrap();
+            stream
+                .set_read_timeout(Some(Duration::from_secs(5)))
+                .unwrap();
+            read_local_request(&mut stream);
+            let response: String = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    let client: Client = Client::new();
+    for body in ["first", "again"] {
+        let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+            .get(&url)
+            .client(&client)
+            .timeout(4000)
+            .http1_1_only()
+            .build_sync();
+        let response: BoxResponseTrait = request_builder.send().unwrap();
+        assert_eq!(response.text().get_body(), body);
+    }
+    server.join().unwrap();
+}
+
+#[test]
+fn test_client_does_not_resend_unsafe_stale_requests() {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url: String = format!("http://{}", listener.local_addr().unwrap());
+    let server: JoinHandle<Vec<Vec<u8>>> = spawn(move || {
+        let mut requests: Vec<Vec<u8>> = Vec::new();
+        for stall in [Duration::ZERO, Duration::from_millis(800), Duration::ZERO] {
+            let (mut stream, _) = listener.accept().unwrap();
+            stream
+                .set_read_timeout(Some(Duration::from_secs(5)))
+                .unwrap();
+            requests.push(read_local_request(&mut stream));
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok")
+                .unwrap();
+            requests.push(read_local_request(&mut stream));
+            thread_sleep(stall);
+        }
+        requests
+    });
+    let client: Client = Client::new();
+    let send = |path: &str, post: bool, timeout: u64| -> RequestResult {
+        let mut request_builder: RequestBuilder = RequestBuilder::new();
+        if post {
+            request_builder
+                .post(&format!("{url}{path}"))
+                .body("payload");
+        } else {
+            request_builder.get(&format!("{url}{path}"));
+        }
+        request_builder
+            .client(&client)
+            .timeout(timeout)
+            .http1_1_only()
+            .build_sync()
+            .send()
+    };
+    assert!(send("/first", false, 300).is_ok());
+    let _ = send("/post", true, 300);
+    assert!(send("/third", false, 300).is_ok());
+    let error: RequestError = send("/slow", false, 300).err().unwrap();
+    assert_eq!(get_timeout_kind(&error), Some(RequestTimeoutKind::Read));
+    assert!(send("/fifth", false, 4000).is_ok());
+    drop(client);
+    let request_lines: Vec<String> = server
+        .join()
+        .unwrap()
+        .iter()
+        .map(|request| {
+            String::from_utf8_lossy(request)
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(
+        request_lines,
+        [
+            "GET /first HTTP/1.1",
+            "POST /post HTTP/1.1",
+            "GET /third HTTP/1.1",
+            "GET /slow HTTP/1.1",
+            "GET /fifth HTTP/1.1",
+            "",
+        ]
+    );
+}
+
+#[test]
+fn test_client_does_not_pool_closed_or_expired_connections() {
+    let (url, server) = spawn_keep_alive_server(vec![
+        b"HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let client: Client = Client::new();
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .client(&client)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    request_builder.send().unwrap();
+    assert_eq!(client.idle_connections(), 0);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .client(&client)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    request_builder.send().unwrap();
+    assert_eq!(server.join().unwrap(), 2);
+    let (url, server) = spawn_keep_alive_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let client: Client = Client::builder().idle_timeout(0).build();
+    for _ in 0..2 {
+        let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+            .get(&url)
+            .client(&client)
+            .timeout(4000)
+            .http1_1_only()
+            .build_sync();
+        request_builder.send().unwrap();
+    }
+    assert_eq!(server.join().unwrap(), 2);
+}
+
+#[tokio::test]
+async fn test_async_client_reuses_keep_alive_connection() {
+    let (url, server) = spawn_keep_alive_server(vec![
+        b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\nok\r\n0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let client: Client = Client::builder().max_connections(1).build();
+    for _ in 0..2 {
+        let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+            .get(&url)
+            .client(&client)
+            .timeout(4000)
+            .http1_1_only()
+            .build_async();
+        let response: BoxResponseTrait = request_builder.send().await.unwrap();
+        assert_eq!(response.text().get_body(), "ok");
+    }
+    assert_eq!(client.idle_connections(), 1);
+    assert_eq!(server.join().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_local_sync_http2_inside_runtime() {
+    let (port, _) = spawn_http2_server(None);
+    let client: Client = Client::new();
+    for client in [None, Some(&client)] {
+        let mut request_builder: RequestBuilder = RequestBuilder::new();
+        request_builder
+            .get(&format!("http://127.0.0.1:{}/echo", port))
+            .timeout(4000)
+            .http2_only();
+        if let Some(client) = client {
+            request_builder.client(client);
+        }
+        let mut request_builder: BoxRequestTrait = request_builder.build_sync();
+        let response: BoxResponseTrait = request_builder.send().unwrap();
+        assert_eq!(
+            response.text().get_body(),
+            format!("GET 127.0.0.1:{} /echo ", port)
+        );
+        let mut response_stream: ResponseStream = request_builder.send_stream().unwrap();
+        let mut body: String = String::new();
+        response_stream.read_to_string(&mut body).unwrap();
+        assert_eq!(body, format!("GET 127.0.0.1:{} /echo ", port));
+    }
+    drop(client);
+}
+
+#[test]
+fn test_local_http2_prior_knowledge_request() {
+    let (port, connections) = spawn_http2_server(None);
+    let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+    header.insert("Content-Type", "text/plain");
+    header.insert("Connection", "keep-alive");
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .post(&format!("http://127.0.0.1:{}/echo", port))
+        .text("hello")
+        .headers(header)
+        .timeout(4000)
+        .http2_only()
+        .build_sync();
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    let response: HttpResponseText = response.text();
+    assert_eq!(response.get_http_version(), HttpVersion::HTTP2);
+    assert_eq!(response.get_status_code(), 200);
+    assert_eq!(
+        response.get_body(),
+        format!("POST 127.0.0.1:{} /echo hello", port)
+    );
+    assert_eq!(
+        response.get_headers().get("x-trailer").map(String::as_str),
+        Some("done")
+    );
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_local_http2_client_reuses_connection() {
+    let (port, connections) = spawn_http2_server(None);
+    let client: Client = Client::new();
+    for _ in 0..2 {
+        let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+            .get(&format!("http://127.0.0.1:{}/redirect", port))
+            .client(&client)
+            .redirect()
+            .timeout(4000)
+            .http2_only()
+            .build_sync();
+        let response: BoxResponseTrait = request_builder.send().unwrap();
+        assert_eq!(
+            response.text().get_body(),
+            format!("GET 127.0.0.1:{} /echo ", port)
+        );
+    }
+    assert_eq!(client.idle_connections(), 1);
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_local_async_http2_multiplexes_requests() {
+    let (port, connections) = spawn_http2_server(None);
+    let client: Client = Client::builder().max_connections(1).build();
+    let requests = (0..4).map(|index: usize| {
+        let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+            .get(&format!("http://127.0.0.1:{}/{}", port, index))
+            .client(&client)
+            .timeout(4000)
+            .http2_only()
+            .build_async();
+        async move { request_builder.send().await.unwrap().text().get_body() }
+    });
+    let bodies: Vec<String> = futures::future::join_all(requests).await;
+    for (index, body) in bodies.iter().enumerate() {
+        assert_eq!(body, &format!("GET 127.0.0.1:{} /{} ", port, index));
+    }
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_local_async_http2_over_tls() {
+    let CertifiedKey { cert, signing_key } =
+        generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let mut server_config: ServerConfig = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(
+            vec![cert.der().clone()],
+            PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(signing_key.serialize_der())),
+        )
+        .unwrap();
+    server_config.alpn_protocols = vec![HTTP2_ALPN.to_vec()];
+    let (port, _) = spawn_http2_server(Some(Arc::new(server_config.clone())));
+    let mut request_builder: RequestBuilder = RequestBuilder::new();
+    request_builder
+        .get(&format!("https://localhost:{}/secure", port))
+        .timeout(4000)
+        .http2_only();
+    request_builder.add_root_certificate_der(cert.der());
+    let mut request: BoxAsyncRequestTrait = request_builder.build_async();
+    let response: BoxResponseTrait = request.send().await.unwrap();
+    assert_eq!(
+        response.text().get_body(),
+        format!("GET localhost:{} /secure ", port)
+    );
+    server_config.alpn_protocols.clear();
+    let (port, _) = spawn_http2_server(Some(Arc::new(server_config)));
+    request_builder.get(&format!("https://localhost:{}/secure", port));
+    let mut request: BoxAsyncRequestTrait = request_builder.build_async();
+    assert!(matches!(
+        request.send().await,
+        Err(RequestError::TlsConnectorBuild(_))
+    ));
+}
+
+#[test]
+fn test_resolve_url() {
+    let base: &str = "http://a/b/c/d;p?q";
+    for (reference, expected) in [
+        ("g:h", "g:h"),
+        ("g", "http://a/b/c/g"),
+        ("./g", "http://a/b/c/g"),
+        ("g/", "http://a/b/c/g/"),
+        ("/g", "http://a/g"),
+        ("//g", "http://g"),
+        ("?y", "http://a/b/c/d;p?y"),
+        ("g?y", "http://a/b/c/g?y"),
+        ("#s", "http://a/b/c/d;p?q#s"),
+        ("g?y#s", "http://a/b/c/g?y#s"),
+        (";x", "http://a/b/c/;x"),
+        ("", "http://a/b/c/d;p?q"),
+        (".", "http://a/b/c/"),
+        ("./", "http://a/b/c/"),
+        ("..", "http://a/b/"),
+        ("../g", "http://a/b/g"),
+        ("../..", "http://a/"),
+        ("../../g", "http://a/g"),
+        ("../../../g", "http://a/g"),
+        ("/./g", "http://a/g"),
+        ("/../g", "http://a/g"),
+        ("g.", "http://a/b/c/g."),
+        ("..g", "http://a/b/c/..g"),
+        ("./../g", "http://a/b/g"),
+        ("g/./h", "http://a/b/c/g/h"),
+        ("g/../h", "http://a/b/c/h"),
+        ("HTTPS://Example.com/x", "https://Example.com/x"),
+    ] {
+        assert_eq!(resolve_url(base, reference), expected, "{}", reference);
+    }
+    assert_eq!(
+        resolve_url("https://example.com", "login"),
+        "https://example.com/login"
+    );
+    assert_eq!(
+        resolve_url("https://example.com/a#top", "/b"),
+        "https://example.com/b#top"
+    );
+}
+
+#[test]
+fn test_local_relative_redirect() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 302 Found\r\nLocation: /api/v1/list\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 301 Moved Permanently\r\nLocation: ../v2/item?id=1\r\nContent-Length: 0\r\n\r\n"
+            .to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&format!("{}/start", url))
+        .redirect()
+        .max_redirect_times(8)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    assert_eq!(response.text().get_body(), "ok");
+    let requests: Vec<Vec<u8>> = server.join().unwrap();
+    assert!(requests[1].starts_with(b"GET /api/v1/list HTTP/1.1\r\n"));
+    assert!(requests[2].starts_with(b"GET /api/v2/item?id=1 HTTP/1.1\r\n"));
+}
+
+#[tokio::test]
+async fn test_local_async_relative_redirect_loop() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 302 Found\r\nLocation: /b\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 302 Found\r\nLocation: ./b\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&format!("{}/a", url))
+        .redirect()
+        .max_redirect_times(8)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert!(matches!(
+        request_builder.send().await,
+        Err(RequestError::RedirectUrlDeadLoop)
+    ));
+    server.join().unwrap();
+}
+
+#[test]
+fn test_local_redirect_method_and_body_policy() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 307 Temporary Redirect\r\nLocation: /b\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 302 Found\r\nLocation: /c\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+    header.insert("Content-Type", "text/plain");
+    header.insert("Authorization", "Bearer token");
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .post(&format!("{}/a", url))
+        .headers(header)
+        .text("hello")
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    assert_eq!(response.text().get_body(), "ok");
+    let requests: Vec<String> = server
+        .join()
+        .unwrap()
+        .iter()
+        .map(|request: &Vec<u8>| String::from_utf8_lossy(request).to_lowercase())
+        .collect();
+    assert!(requests[1].starts_with("post /b http/1.1\r\n"));
+    assert!(requests[1].ends_with("\r\n\r\nhello"));
+    assert!(requests[1].contains("authorization: bearer token"));
+    assert!(requests[2].starts_with("get /c http/1.1\r\n"));
+    assert!(!requests[2].contains("content-type"));
+    assert!(!requests[2].contains("content-length"));
+    assert!(requests[2].contains("authorization: bearer token"));
+}
+
+#[tokio::test]
+async fn test_local_async_redirect_policy_preserves_method() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 301 Moved Permanently\r\nLocation: /b\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+    header.insert("Content-Type", "text/plain");
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .post(&format!("{}/a", url))
+        .headers(header)
+        .text("hello")
+        .redirect()
+        .redirect_policy(RedirectPolicy::new().preserve_method(true))
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    request_builder.send().await.unwrap();
+    let requests: Vec<Vec<u8>> = server.join().unwrap();
+    assert!(requests[1].starts_with(b"POST /b HTTP/1.1\r\n"));
+    assert!(requests[1].ends_with(b"\r\n\r\nhello"));
+}
+
+#[test]
+fn test_local_cross_origin_redirect_strips_credentials() {
+    let (target_url, target) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let (url, server) = spawn_local_server(vec![
+        format!(
+            "HTTP/1.1 302 Found\r\nLocation: {}/landing\r\nContent-Length: 0\r\n\r\n",
+            target_url
+        )
+        .into_bytes(),
+    ]);
+    let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+    header.insert("Authorization", "Basic dXNlcjpwYXNz");
+    header.insert("Cookie", "session=1");
+    header.insert("Proxy-Authorization", "Basic dXNlcjpwYXNz");
+    header.insert("X-Trace", "1");
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .headers(header)
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    request_builder.send().unwrap();
+    server.join().unwrap();
+    let request: String = String::from_utf8_lossy(&target.join().unwrap()[0]).to_lowercase();
+    assert!(!request.contains("authorization"));
+    assert!(!request.contains("cookie"));
+    assert!(request.contains("x-trace: 1"));
+}
+
+#[test]
+fn test_local_basic_auth() {
+    let (target_url, target) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 302 Found\r\nLocation: /next\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        format!(
+            "HTTP/1.1 302 Found\r\nLocation: {}/landing\r\nContent-Length: 0\r\n\r\n",
+            target_url
+        )
+        .into_bytes(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&format!("{}/start", url))
+        .basic_auth("user", "pass")
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    request_builder.send().unwrap();
+    drop(request_builder);
+    for request in server.join().unwrap() {
+        let request: String = String::from_utf8_lossy(&request).to_lowercase();
+        assert!(request.contains("authorization: basic dxnlcjpwyxnz"));
+    }
+    let request: String = String::from_utf8_lossy(&target.join().unwrap()[0]).to_lowercase();
+    assert!(!request.contains("authorization"));
+    let (target_url, target) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let (url, server) = spawn_local_server(vec![
+        format!(
+            "HTTP/1.1 302 Found\r\nLocation: {}/landing\r\nContent-Length: 0\r\n\r\n",
+            target_url
+        )
+        .into_bytes(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .bearer_auth("token")
+        .redirect()
+        .redirect_policy(RedirectPolicy::new().strip_credentials(false))
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    request_builder.send().unwrap();
+    drop(request_builder);
+    server.join().unwrap();
+    let request: String = String::from_utf8_lossy(&target.join().unwrap()[0]).to_lowercase();
+    assert!(request.contains("authorization: bearer token"));
+}
+
+#[test]
+fn test_credentials_redacted_in_debug() {
+    let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+    header.insert("Cookie", "session=cookie-secret");
+    let mut request_builder: RequestBuilder = RequestBuilder::new();
+    request_builder
+        .get("http://127.0.0.1/")
+        .headers(header)
+        .bearer_auth("bearer-secret")
+        .digest_auth("user", "digest-secret")
+        .http_proxy_auth("127.0.0.1", 8080, "user", "proxy-secret");
+    let output: String = format!("{:?} {:?}", request_builder, request_builder.http_request);
+    for secret in [
+        "cookie-secret",
+        "bearer-secret",
+        "digest-secret",
+        "proxy-secret",
+    ] {
+        assert!(!output.contains(secret));
+    }
+    assert!(output.contains(REDACTED));
+    let mut websocket_builder: WebSocketBuilder = WebSocketBuilder::new();
+    websocket_builder
+        .connect("ws://127.0.0.1/")
+        .basic_auth("user", "pass");
+    assert_eq!(
+        websocket_builder
+            .websocket
+            .header
+            .get(AUTHORIZATION)
+            .unwrap(),
+        "Basic dXNlcjpwYXNz"
+    );
+    assert!(!format!("{:?}", websocket_builder).contains("dXNlcjpwYXNz"));
+}
+
+#[test]
+fn test_redirect_policy_refuses_https_downgrade() {
+    let mut request_builder: RequestBuilder = RequestBuilder::new();
+    request_builder.get("https://example.com/");
+    let mut http_request: HttpRequest = request_builder.http_request.clone();
+    http_request.config.write().unwrap().url_obj = http_request.parse_url().unwrap();
+    assert!(matches!(
+        http_request.apply_redirect_policy("http://example.com/"),
+        Err(RequestError::Request(_))
+    ));
+    assert!(
+        http_request
+            .apply_redirect_policy("https://example.com/next")
+            .is_ok()
+    );
+    request_builder.redirect_policy(RedirectPolicy::new().allow_https_downgrade(true));
+    let mut http_request: HttpRequest = request_builder.http_request.clone();
+    http_request.config.write().unwrap().url_obj = http_request.parse_url().unwrap();
+    assert!(
+        http_request
+            .apply_redirect_policy("http://example.com/")
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_local_redirect_history() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 302 Found\r\nLocation: /b\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 301 Moved Permanently\r\nLocation: /c\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&format!("{}/a", url))
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    let redirect_history: Vec<RedirectHop> = response.redirect_history();
+    assert_eq!(redirect_history.len(), 2);
+    assert_eq!(redirect_history[0].get_url(), format!("{}/a", url));
+    assert_eq!(redirect_history[0].get_status_code(), 302);
+    assert_eq!(
+        redirect_history[0]
+            .get_headers()
+            .get("Location")
+            .map(String::as_str),
+        Some("/b")
+    );
+    assert_eq!(redirect_history[1].get_url(), format!("{}/b", url));
+    assert_eq!(redirect_history[1].get_status_code(), 301);
+    assert_eq!(response.text().get_redirect_history(), redirect_history);
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    assert!(response.redirect_history().is_empty());
+    server.join().unwrap();
+}
+
+#[test]
+fn test_local_repeated_response_headers() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nSet-Cookie: a=1; Path=/\r\nVia: 1.1 first\r\nset-cookie: b=2\r\nVia: 1.1 second\r\nContent-Length: 2\r\n\r\nok"
+            .to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let response: HttpResponseText = request_builder.send().unwrap().text();
+    assert_eq!(
+        response.get_header_all("SET-COOKIE"),
+        vec!["a=1; Path=/".to_string(), "b=2".to_string()]
+    );
+    let header_map: ResponseHeaderMap = response.get_header_map();
+    assert_eq!(header_map.get("via"), Some("1.1 first"));
+    assert_eq!(header_map.get_all("Via"), vec!["1.1 first", "1.1 second"]);
+    assert_eq!(header_map.len(), 5);
+    assert_eq!(
+        header_map.iter().map(|(key, _)| key).collect::<Vec<&str>>(),
+        vec!["Set-Cookie", "Via", "set-cookie", "Via", "Content-Length"]
+    );
+    assert!(header_map.get_all("Link").is_empty());
+    assert_eq!(
+        response.get_headers().get("Via").map(String::as_str),
+        Some("1.1 second")
+    );
+    assert_eq!(
+        response.binary().get_header_all("set-cookie"),
+        response.get_header_all("set-cookie")
+    );
+    server.join().unwrap();
+}
+
+#[test]
+fn test_parse_http_date() {
+    for value in [
+        "Sun, 06 Nov 1994 08:49:37 GMT",
+        "Sunday, 06-Nov-94 08:49:37 GMT",
+        "Sun Nov  6 08:49:37 1994",
+    ] {
+        assert_eq!(parse_http_date(value), Some(784111777), "{}", value);
+    }
+    assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+    assert_eq!(parse_http_date("not a date"), None);
+}
+
+#[test]
+fn test_cookie_jar_matching_and_persistence() {
+    let cookie_jar: CookieJar = CookieJar::new();
+    cookie_jar.add_cookie_str(
+        "a=1; Domain=.Example.com; Path=/",
+        "https://www.example.com/",
+    );
+    cookie_jar.add_cookie_str("b=2; Path=/docs", "https://example.com/");
+    cookie_jar.add_cookie_str("c=3; Secure; SameSite=Lax", "https://example.com/");
+    cookie_jar.add_cookie_str("d=4; Domain=other.com", "https://example.com/");
+    cookie_jar.add_cookie_str("e=5; Secure", "http://example.com/");
+    cookie_jar.add_cookie_str(
+        "f=6; Expires=Thu, 01 Jan 1970 00:00:00 GMT",
+        "https://example.com/",
+    );
+    cookie_jar.add_cookie_str(
+        "g=7; Expires=Thu, 01 Jan 1970 00:00:00 GMT; Max-Age=3600",
+        "https://example.com/",
+    );
+    assert_eq!(
+        cookie_jar.get_cookie_header("https://example.com/docs/page"),
+        Some("b=2; a=1; c=3; g=7".to_string())
+    );
+    assert_eq!(
+        cookie_jar.get_cookie_header("http://api.example.com/docs"),
+        Some("a=1".to_string())
+    );
+    assert_eq!(cookie_jar.get_cookie_header("https://example.org/"), None);
+    assert_eq!(cookie_jar.len(), 4);
+    cookie_jar.add_cookie_str("a=1; Domain=example.com; Max-Age=0", "https://example.com/");
+    assert_eq!(cookie_jar.len(), 3);
+    let mut saved: Vec<u8> = Vec::new();
+    cookie_jar.save(&mut saved).unwrap();
+    let loaded: CookieJar = CookieJar::new();
+    loaded.load(&mut saved.as_slice()).unwrap();
+    assert_eq!(loaded.get_cookies(), cookie_jar.get_cookies());
+    let cookie: StoredCookie = loaded
+        .get_cookies()
+        .into_iter()
+        .find(|cookie: &StoredCookie| cookie.get_name() == "c")
+        .unwrap();
+    assert!(cookie.is_secure());
+    assert!(cookie.is_host_only());
+    assert_eq!(cookie.get_same_site(), Some("Lax".to_string()));
+    assert!(loaded.load(&mut "[{}]".as_bytes()).is_err());
+}
+
+#[test]
+fn test_local_cookie_jar_across_redirects() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 303 See Other\r\nLocation: /home\r\nSet-Cookie: session=abc; Path=/; HttpOnly\r\nSet-Cookie: scoped=1; Path=/admin\r\nSet-Cookie: secure=1; Secure\r\nContent-Length: 0\r\n\r\n"
+            .to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+        b"HTTP/1.1 200 OK\r\nSet-Cookie: session=; Path=/; Max-Age=0\r\nContent-Length: 2\r\n\r\nok"
+            .to_vec(),
+    ]);
+    let cookie_jar: CookieJar = CookieJar::new();
+    let client: Client = Client::builder()
+        .max_idle_per_host(0)
+        .cookie_jar(&cookie_jar)
+        .build();
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .post(&format!("{}/login", url))
+        .body("user=me")
+        .client(&client)
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    request_builder.send().unwrap();
+    let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+    header.insert("Cookie", "user=1");
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&format!("{}/admin/panel", url))
+        .headers(header)
+        .client(&client)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    request_builder.send().unwrap();
+    assert_eq!(cookie_jar.get_cookie_header(&url), None);
+    assert_eq!(cookie_jar.len(), 1);
+    let requests: Vec<String> = server
+        .join()
+        .unwrap()
+        .iter()
+        .map(|request: &Vec<u8>| String::from_utf8_lossy(request).to_string())
+        .collect();
+    assert!(!requests[0].to_lowercase().contains("cookie"));
+    assert!(requests[1].starts_with("GET /home HTTP/1.1\r\n"));
+    assert!(requests[1].contains("cookie: session=abc\r\n"));
+    assert!(requests[2].contains("Cookie: user=1; scoped=1; session=abc\r\n"));
+}
+
+#[test]
+fn test_local_response_stream() {
+    let mut encoder: GzEncoder<Vec<u8>> = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&b"streamed ".repeat(64)).unwrap();
+    let compressed: Vec<u8> = encoder.finish().unwrap();
+    let mut gzip_response: Vec<u8> = format!(
+        "HTTP/1.1 200 OK\r\nContent-Encoding: gzip\r\nContent-Length: {}\r\n\r\n",
+        compressed.len()
+    )
+    .into_bytes();
+    gzip_response.extend_from_slice(&compressed);
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 302 Found\r\nLocation: /b\r\nContent-Length: 5\r\n\r\nmoved".to_vec(),
+        b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n6\r\n world\r\n0\r\nx-trailer: done\r\n\r\n"
+            .to_vec(),
+        gzip_response,
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&format!("{}/a", url))
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let mut response_stream: ResponseStream = request_builder.send_stream().unwrap();
+    assert_eq!(response_stream.get_status_code(), 200);
+    assert_eq!(
+        response_stream
+            .get_headers()
+            .get("Transfer-Encoding")
+            .map(String::as_str),
+        Some("chunked")
+    );
+    assert_eq!(
+        response_stream.get_response().get_redirect_history().len(),
+        1
+    );
+    let mut body: String = String::new();
+    response_stream.read_to_string(&mut body).unwrap();
+    assert_eq!(body, "hello world");
+    drop(response_stream);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let mut response_stream: ResponseStream = request_builder.send_stream().unwrap();
+    let mut body: Vec<u8> = Vec::new();
+    let mut buffer: [u8; 7] = [0; 7];
+    loop {
+        let n: usize = response_stream.read(&mut buffer).unwrap();
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&buffer[..n]);
+    }
+    assert_eq!(body, b"streamed ".repeat(64));
+    drop(response_stream);
+    let requests: Vec<Vec<u8>> = server.join().unwrap();
+    assert!(requests[1].starts_with(b"GET /b HTTP/1.1\r\n"));
+}
+
+#[tokio::test]
+async fn test_local_async_response_stream_reuses_connection() {
+    let (url, server) = spawn_keep_alive_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\n0123456789".to_vec(),
+        b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n3\r\ndef\r\n0\r\n\r\n"
+            .to_vec(),
+    ]);
+    let client: Client = Client::new();
+    let mut bodies: Vec<Vec<u8>> = Vec::new();
+    for _ in 0..2 {
+        let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+            .get(&url)
+            .client(&client)
+            .timeout(4000)
+            .http1_1_only()
+            .build_async();
+        let mut response_stream: AsyncResponseStream = request_builder.send_stream().await.unwrap();
+        assert_eq!(response_stream.get_status_code(), 200);
+        let mut body: Vec<u8> = Vec::new();
+        while let Some(chunk) = response_stream.next().await {
+            body.extend_from_slice(&chunk.unwrap());
+        }
+        bodies.push(body);
+    }
+    assert_eq!(bodies, vec![b"0123456789".to_vec(), b"abcdef".to_vec()]);
+    drop(client);
+    assert_eq!(server.join().unwrap(), 1);
+}
+
+#[test]
+fn test_local_http2_response_stream() {
+    let (port, _) = spawn_http2_server(None);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&format!("http://127.0.0.1:{}/redirect", port))
+        .redirect()
+        .timeout(4000)
+        .http2_only()
+        .build_sync();
+    let mut response_stream: ResponseStream = request_builder.send_stream().unwrap();
+    assert_eq!(response_stream.get_status_code(), 200);
+    assert_eq!(
+        response_stream.get_response().get_redirect_history().len(),
+        1
+    );
+    let mut body: String = String::new();
+    response_stream.read_to_string(&mut body).unwrap();
+    assert_eq!(body, format!("GET 127.0.0.1:{} /echo ", port));
+}
+
+#[test]
+fn test_local_streamed_request_body() {
+    let path: PathBuf = std::env::temp_dir().join(format!(
+        "http-request-body-{}-{}.txt",
+        std::process::id(),
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    std::fs::write(&path, "file body").unwrap();
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+        b"HTTP/1.1 307 Temporary Redirect\r\nLocation: /b\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+        b"HTTP/1.1 307 Temporary Redirect\r\nLocation: /b\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .post(&url)
+        .body_reader(&b"hello world"[..], None)
+        .buffer(4)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert_eq!(request_builder.send().unwrap().text().get_body(), "ok");
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .put(&format!("{}/a", url))
+        .body_file(&path)
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert_eq!(request_builder.send().unwrap().text().get_body(), "ok");
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .post(&format!("{}/a", url))
+        .body_reader(&b"once"[..], Some(4))
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert!(request_builder.send().is_err());
+    std::fs::remove_file(&path).unwrap();
+    let requests: Vec<String> = server
+        .join()
+        .unwrap()
+        .iter()
+        .map(|request: &Vec<u8>| String::from_utf8_lossy(request).into_owned())
+        .collect();
+    assert!(requests[0].contains("transfer-encoding: chunked\r\n"));
+    assert!(!requests[0].contains("content-length"));
+    assert!(requests[0].ends_with("\r\n\r\n4\r\nhell\r\n4\r\no wo\r\n3\r\nrld\r\n0\r\n\r\n"));
+    assert!(requests[1].starts_with("PUT /a HTTP/1.1\r\n"));
+    assert!(requests[1].contains("content-length: 9\r\n"));
+    assert!(requests[1].ends_with("\r\n\r\nfile body"));
+    assert!(requests[2].starts_with("PUT /b HTTP/1.1\r\n"));
+    assert!(requests[2].ends_with("\r\n\r\nfile body"));
+    assert!(requests[3].contains("content-length: 4\r\n"));
+    assert!(requests[3].ends_with("\r\n\r\nonce"));
+}
+
+#[tokio::test]
+async fn test_local_async_streamed_request_body() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .post(&url)
+        .body_async_reader(&b"async body"[..], Some(10))
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert_eq!(
+        request_builder.send().await.unwrap().text().get_body(),
+        "ok"
+    );
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .patch(&url)
+        .body_reader(std::io::Cursor::new(b"blocking body".to_vec()), None)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert_eq!(
+        request_builder.send().await.unwrap().text().get_body(),
+        "ok"
+    );
+    let requests: Vec<String> = server
+        .join()
+        .unwrap()
+        .iter()
+        .map(|request: &Vec<u8>| String::from_utf8_lossy(request).into_owned())
+        .collect();
+    assert!(requests[0].contains("content-length: 10\r\n"));
+    assert!(requests[0].ends_with("\r\n\r\nasync body"));
+    assert!(requests[1].contains("transfer-encoding: chunked\r\n"));
+    assert!(requests[1].ends_with("\r\n\r\nD\r\nblocking body\r\n0\r\n\r\n"));
+}
+
+#[test]
+fn test_local_http2_streamed_request_body() {
+    let (port, _) = spawn_http2_server(None);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .post(&format!("http://127.0.0.1:{}/echo", port))
+        .body_reader(&b"streamed over h2"[..], None)
+        .buffer(5)
+        .timeout(4000)
+        .http2_only()
+        .build_sync();
+    let response: HttpResponseText = request_builder.send().unwrap().text();
+    assert_eq!(
+        response.get_body(),
+        format!("POST 127.0.0.1:{} /echo streamed over h2", port)
+    );
+}
+
+#[test]
+fn test_local_multipart_form() {
+    let path: PathBuf = std::env::temp_dir().join(format!(
+        "http-request-multipart-{}-{}.txt",
+        std::process::id(),
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    std::fs::write(&path, "file content").unwrap();
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut form: MultipartForm = MultipartForm::new();
+    form.text("title", "a \"quoted\" name").part(
+        "avatar",
+        MultipartPart::bytes(vec![0u8, 159, 146, 150])
+            .file_name("avatar.png")
+            .content_type("image/png"),
+    );
+    form.part("notes", &MultipartPart::file(&path));
+    let boundary: String = form.get_boundary();
+    assert_ne!(boundary, MultipartForm::new().get_boundary());
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .post(&url)
+        .multipart(&form)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert_eq!(request_builder.send().unwrap().text().get_body(), "ok");
+    let mut streamed_form: MultipartForm = MultipartForm::new();
+    streamed_form.text("kind", "log").part(
+        "log",
+        MultipartPart::reader(&b"streamed log"[..], None).file_name("app.log"),
+    );
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .post(&url)
+        .multipart(&streamed_form)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert_eq!(request_builder.send().unwrap().text().get_body(), "ok");
+    std::fs::remove_file(&path).unwrap();
+    let requests: Vec<Vec<u8>> = server.join().unwrap();
+    let mut expected_body: Vec<u8> = format!(
+        "--{0}\r\nContent-Disposition: form-data; name=\"title\"\r\n\r\na \"quoted\" name\r\n--{0}\r\nContent-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\nContent-Type: image/png\r\n\r\n",
+        boundary
+    )
+    .into_bytes();
+    expected_body.extend_from_slice(&[0u8, 159, 146, 150]);
+    expected_body.extend_from_slice(
+        format!(
+            "\r\n--{0}\r\nContent-Disposition: form-data; name=\"notes\"; filename=\"{1}\"\r\nContent-Type: application/octet-stream\r\n\r\nfile content\r\n--{0}--\r\n",
+            boundary,
+            path.file_name().unwrap().to_string_lossy()
+        )
+        .as_bytes(),
+    );
+    let head: String = String::from_utf8_lossy(&requests[0]).into_owned();
+    assert!(head.contains(&format!(
+        "content-type: multipart/form-data; boundary={}\r\n",
+        boundary
+    )));
+    assert!(head.contains(&format!("content-length: {}\r\n", expected_body.len())));
+    assert!(requests[0].ends_with(&expected_body));
+    let streamed: String = String::from_utf8_lossy(&requests[1]).into_owned();
+    assert!(streamed.contains("transfer-encoding: chunked\r\n"));
+    let body_start: usize = SharedResponseHandler::find_double_crlf(&requests[1], 0).unwrap() + 4;
+    let mut chunked_decoder: ChunkedDecoder = ChunkedDecoder::default();
+    let mut streamed_body: Vec<u8> = Vec::new();
+    chunked_decoder
+        .decode(&requests[1][body_start..], &mut streamed_body)
+        .unwrap();
+    assert!(chunked_decoder.is_done());
+    assert_eq!(
+        String::from_utf8(streamed_body).unwrap(),
+        format!(
+            "--{0}\r\nContent-Disposition: form-data; name=\"kind\"\r\n\r\nlog\r\n--{0}\r\nContent-Disposition: form-data; name=\"log\"; filename=\"app.log\"\r\nContent-Type: application/octet-stream\r\n\r\nstreamed log\r\n--{0}--\r\n",
+            streamed_form.get_boundary()
+        )
+    );
+}
+
+#[test]
+fn test_local_form_and_query() {
+    let (url, server) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .query(&[("q", "rust & http"), ("lang", "zh/cn")])
+        .post(&format!("{}/search?page=1", url))
+        .query(&[("tag", "café")])
+        .form(&[("name", "Jane Doe"), ("note", "a+b=c&d")])
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert_eq!(request_builder.send().unwrap().text().get_body(), "ok");
+    let mut params: HashMap<&str, u32> = HashMap::new();
+    params.insert("id", 7);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&format!("{}/raw path?x=a b", url))
+        .query(&params)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert_eq!(request_builder.send().unwrap().text().get_body(), "ok");
+    let requests: Vec<Vec<u8>> = server.join().unwrap();
+    let request: String = String::from_utf8_lossy(&requests[0]).into_owned();
+    assert!(request.starts_with(
+        "POST /search?page=1&q=rust+%26+http&lang=zh%2Fcn&tag=caf%C3%A9 HTTP/1.1\r\n"
+    ));
+    assert!(request.contains("content-type: application/x-www-form-urlencoded\r\n"));
+    assert!(request.contains("content-length: 32\r\n"));
+    assert!(request.ends_with("\r\n\r\nname=Jane+Doe&note=a%2Bb%3Dc%26d"));
+    let request: String = String::from_utf8_lossy(&requests[1]).into_owned();
+    assert!(request.starts_with("GET /raw%20path?x=a%20b&id=7 HTTP/1.1\r\n"));
+}
+
+#[test]
+fn test_local_custom_root_certificates() {
+    let (cert, server_config) = self_signed_server_config();
+    let port: u16 = spawn_tls_server(server_config, serve_tls_ok);
+    let url: String = format!("https://localhost:{}/", port);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert!(request_builder.send().is_err());
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .tls_built_in_root_certs(false)
+        .add_root_certificate_pem(cert.pem())
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert_eq!(request_builder.send().unwrap().text().get_body(), "ok");
+    let directory: PathBuf = std::env::temp_dir().join(format!(
+        "http-request-certs-{}-{}",
+        std::process::id(),
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(directory.join("local.pem"), cert.pem()).unwrap();
+    std::fs::write(directory.join("readme.txt"), "not a certificate").unwrap();
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificates_from_path(&directory)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert_eq!(request_builder.send().unwrap().text().get_body(), "ok");
+    std::fs::remove_dir_all(&directory).unwrap();
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificate_pem("not a certificate")
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    match request_builder.send() {
+        Err(RequestError::TlsConnectorBuild(message)) => {
+            assert!(message.contains("no certificate found"))
+        }
+        other => panic!("unexpected result: {:?}", other.map(|_| ())),
+    }
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .tls_built_in_root_certs(false)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    assert!(matches!(
+        request_builder.send(),
+        Err(RequestError::TlsConnectorBuild(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_local_websocket_custom_root_certificate() {
+    let (cert, server_config) = self_signed_server_config();
+    let port: u16 = spawn_tls_server(server_config, serve_websocket_echo);
+    let url: String = format!("wss://localhost:{}/echo", port);
+    let mut websocket: WebSocket = WebSocketBuilder::new()
+        .connect(&url)
+        .timeout(4000)
+        .add_root_certificate_der(cert.der())
+        .build_async();
+    websocket.send_text_async("hello").await.unwrap();
+    assert_eq!(
+        websocket.receive_async().await.unwrap(),
+        WebSocketMessage::Text("hello".to_string())
+    );
+    let mut websocket: WebSocket = WebSocketBuilder::new()
+        .connect(&url)
+        .timeout(4000)
+        .build_async();
+    let error: WebSocketError = websocket.send_text_async("hello").await.unwrap_err();
+    assert_eq!(error.kind, WebSocketErrorKind::Tls);
+}
+
+#[tokio::test]
+async fn test_local_mutual_tls() {
+    let CertifiedKey {
+        cert: client_cert,
+        signing_key: client_key,
+    } = generate_simple_self_signed(vec!["client.localhost".to_string()]).unwrap();
+    let mut client_roots: RootCertStore = RootCertStore::empty();
+    client_roots.add(client_cert.der().clone()).unwrap();
+    let CertifiedKey { cert, signing_key } =
+        generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let server_config: ServerConfig = ServerConfig::builder()
+        .with_client_cert_verifier(
+            WebPkiClientVerifier::builder(Arc::new(client_roots))
+                .build()
+                .unwrap(),
+        )
+        .with_single_cert(
+            vec![cert.der().clone()],
+            PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(signing_key.serialize_der())),
+        )
+        .unwrap();
+    let port: u16 = spawn_tls_server(server_config.clone(), serve_tls_ok);
+    let url: String = format!("https://localhost:{}/", port);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificate_der(cert.der())
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert!(request_builder.send().await.is_err());
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificate_der(cert.der())
+        .client_certificate_pem(client_cert.pem(), client_key.serialize_pem())
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert_eq!(
+        request_builder.send().await.unwrap().text().get_body(),
+        "ok"
+    );
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificate_der(cert.der())
+        .client_certificate_der(vec![client_cert.der().to_vec()], client_key.serialize_der())
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert_eq!(
+        request_builder.send().await.unwrap().text().get_body(),
+        "ok"
+    );
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificate_der(cert.der())
+        .client_certificate_pem(client_cert.pem(), "not a key")
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    match request_builder.send().await {
+        Err(RequestError::TlsConnectorBuild(message)) => {
+            assert!(message.contains("invalid client private key"))
+        }
+        other => panic!("unexpected result: {:?}", other.map(|_| ())),
+    }
+    let port: u16 = spawn_tls_server(server_config, serve_websocket_echo);
+    let mut websocket: WebSocket = WebSocketBuilder::new()
+        .connect(&format!("wss://localhost:{}/echo", port))
+        .timeout(4000)
+        .add_root_certificate_der(cert.der())
+        .client_certificate_pem(client_cert.pem(), client_key.serialize_pem())
+        .build_async();
+    websocket.send_text_async("hello").await.unwrap();
+    assert_eq!(
+        websocket.receive_async().await.unwrap(),
+        WebSocketMessage::Text("hello".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_local_danger_accept_invalid_certs() {
+    let (cert, server_config) = self_signed_server_config();
+    let port: u16 = spawn_tls_server(server_config.clone(), serve_tls_ok);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&format!("https://localhost:{}/", port))
+        .danger_accept_invalid_certs(true)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert_eq!(
+        request_builder.send().await.unwrap().text().get_body(),
+        "ok"
+    );
+    let ip_url: String = format!("https://127.0.0.1:{}/", port);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&ip_url)
+        .add_root_certificate_der(cert.der())
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert!(request_builder.send().await.is_err());
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&ip_url)
+        .add_root_certificate_der(cert.der())
+        .danger_accept_invalid_hostnames(true)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert_eq!(
+        request_builder.send().await.unwrap().text().get_body(),
+        "ok"
+    );
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&ip_url)
+        .danger_accept_invalid_hostnames(true)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert!(request_builder.send().await.is_err());
+    let port: u16 = spawn_tls_server(server_config, serve_websocket_echo);
+    let mut websocket: WebSocket = WebSocketBuilder::new()
+        .connect(&format!("wss://localhost:{}/echo", port))
+        .timeout(4000)
+        .danger_accept_invalid_certs(true)
+        .build_async();
+    websocket.send_text_async("hello").await.unwrap();
+    assert_eq!(
+        websocket.receive_async().await.unwrap(),
+        WebSocketMessage::Text("hello".to_string())
+    );
+}
+
+#[test]
+fn test_sha256() {
+    let hex = |digest: [u8; 32]| -> String {
+        digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+    };
+    assert_eq!(
+        hex(sha256(b"")),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        hex(sha256(b"abc")),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+        hex(sha256(
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+        )),
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+    );
+}
+
+#[tokio::test]
+async fn test_local_certificate_pinning() {
+    let (cert, server_config) = self_signed_server_config();
+    let spki: Vec<u8> = EndEntityCert::try_from(cert.der())
+        .unwrap()
+        .subject_public_key_info()
+        .as_ref()
+        .to_vec();
+    let pin: String = format!("sha256/{}", base64_encode(&sha256(&spki)));
+    let wrong_pin: String = base64_encode(&sha256(b"another key"));
+    let port: u16 = spawn_tls_server(server_config.clone(), serve_tls_ok);
+    let url: String = format!("https://localhost:{}/", port);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificate_der(cert.der())
+        .pin_public_key_sha256(&wrong_pin)
+        .pin_public_key_sha256(&pin)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert_eq!(
+        request_builder.send().await.unwrap().text().get_body(),
+        "ok"
+    );
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificate_der(cert.der())
+        .pin_certificate(cert.pem())
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert_eq!(
+        request_builder.send().await.unwrap().text().get_body(),
+        "ok"
+    );
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificate_der(cert.der())
+        .pin_public_key_sha256(&wrong_pin)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    let error: RequestError = request_builder.send().await.err().unwrap();
+    assert!(is_certificate_pin_mismatch(&error), "{}", error);
+    let sync_url: String = url.clone();
+    let sync_pin: String = wrong_pin.clone();
+    let cert_der: Vec<u8> = cert.der().to_vec();
+    let sync_result: Result<(), RequestError> = spawn_blocking(move || {
+        RequestBuilder::new()
+            .get(&sync_url)
+            .add_root_certificate_der(&cert_der)
+            .pin_public_key_sha256(&sync_pin)
+            .timeout(4000)
+            .http1_1_only()
+            .build_sync()
+            .send()
+            .map(|_| ())
+    })
+    .await
+    .unwrap();
+    let error: RequestError = sync_result.unwrap_err();
+    assert!(is_certificate_pin_mismatch(&error), "{}", error);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificate_der(cert.der())
+        .pin_public_key_sha256("not a pin")
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    let error: RequestError = request_builder.send().await.err().unwrap();
+    assert!(!is_certificate_pin_mismatch(&error));
+    let port: u16 = spawn_tls_server(server_config, serve_websocket_echo);
+    let mut websocket: WebSocket = WebSocketBuilder::new()
+        .connect(&format!("wss://localhost:{}/echo", port))
+        .add_root_certificate_der(cert.der())
+        .pin_public_key_sha256(&wrong_pin)
+        .timeout(4000)
+        .build_async();
+    let error: WebSocketError = websocket.send_text_async("hello").await.unwrap_err();
+    assert_eq!(error.kind, WebSocketErrorKind::Tls);
+    assert!(error.to_string().contains(CERTIFICATE_PIN_MISMATCH));
+}
+
+#[tokio::test]
+async fn test_local_certificate_pinning_through_https_proxy() {
+    let (cert, server_config) = self_signed_server_config();
+    let (proxy_cert, proxy_server_config) = self_signed_server_config();
+    let spki: Vec<u8> = EndEntityCert::try_from(cert.der())
+        .unwrap()
+        .subject_public_key_info()
+        .as_ref()
+        .to_vec();
+    let pin: String = base64_encode(&sha256(&spki));
+    let proxy_port: u16 = spawn_tls_server(proxy_server_config, serve_connect_tunnel);
+    let port: u16 = spawn_tls_server(server_config.clone(), serve_tls_ok);
+    let url: String = format!("https://localhost:{}/", port);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .https_proxy("localhost", proxy_port)
+        .add_root_certificate_der(cert.der())
+        .add_root_certificate_der(proxy_cert.der())
+        .pin_public_key_sha256(&pin)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert_eq!(
+        request_builder.send().await.unwrap().text().get_body(),
+        "ok"
+    );
+    let sync_url: String = url.clone();
+    let sync_pin: String = pin.clone();
+    let cert_der: Vec<u8> = cert.der().to_vec();
+    let proxy_cert_der: Vec<u8> = proxy_cert.der().to_vec();
+    let sync_body: String = spawn_blocking(move || {
+        RequestBuilder::new()
+            .get(&sync_url)
+            .https_proxy("localhost", proxy_port)
+            .add_root_certificate_der(&cert_der)
+            .add_root_certificate_der(&proxy_cert_der)
+            .pin_public_key_sha256(&sync_pin)
+            .timeout(4000)
+            .http1_1_only()
+            .build_sync()
+            .send()
+            .unwrap()
+            .text()
+            .get_body()
+    })
+    .await
+    .unwrap();
+    assert_eq!(sync_body, "ok");
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .https_proxy("localhost", proxy_port)
+        .add_root_certificate_der(cert.der())
+        .add_root_certificate_der(proxy_cert.der())
+        .pin_public_key_sha256(&base64_encode(&sha256(b"another key")))
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    let error: RequestError = request_builder.send().await.err().unwrap();
+    assert!(is_certificate_pin_mismatch(&error), "{}", error);
+    let port: u16 = spawn_tls_server(server_config, serve_websocket_echo);
+    let mut websocket: WebSocket = WebSocketBuilder::new()
+        .connect(&format!("wss://localhost:{}/echo", port))
+        .https_proxy("localhost", proxy_port)
+        .add_root_certificate_der(cert.der())
+        .add_root_certificate_der(proxy_cert.der())
+        .pin_public_key_sha256(&pin)
+        .timeout(4000)
+        .build_async();
+    websocket.send_text_async("hello").await.unwrap();
+    assert_eq!(
+        websocket.receive_async().await.unwrap(),
+        WebSocketMessage::Text("hello".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_local_tls_info() {
+    let (cert, server_config) = self_signed_server_config();
+    let port: u16 = spawn_tls_server(server_config.clone(), serve_tls_ok);
+    let url: String = format!("https://localhost:{}/", port);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .add_root_certificate_der(cert.der())
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    let tls_info: TlsInfo = request_builder.send().await.unwrap().tls_info().unwrap();
+    assert_eq!(tls_info.get_protocol_version(), "TLSv1_3");
+    assert!(tls_info.get_cipher_suite().starts_with("TLS13_"));
+    assert_eq!(tls_info.get_alpn_protocol(), None);
+    assert_eq!(tls_info.get_peer_certificates(), vec![cert.der().to_vec()]);
+    let cert_der: Vec<u8> = cert.der().to_vec();
+    let sync_tls_info: Option<TlsInfo> = spawn_blocking(move || {
+        RequestBuilder::new()
+            .get(&url)
+            .add_root_certificate_der(&cert_der)
+            .timeout(4000)
+            .http1_1_only()
+            .build_sync()
+            .send()
+            .unwrap()
+            .text()
+            .get_tls_info()
+    })
+    .await
+    .unwrap();
+    assert_eq!(sync_tls_info, Some(tls_info));
+    let (plain_url, _) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&plain_url)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    assert_eq!(request_builder.send().await.unwrap().tls_info(), None);
+    let mut http2_config: ServerConfig = server_config.clone();
+    http2_config.alpn_protocols = vec![HTTP2_ALPN.to_vec()];
+    let (port, _) = spawn_http2_server(Some(Arc::new(http2_config)));
+    let client: Client = Client::builder().build();
+    for _ in 0..2 {
+        let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+            .get(&format!("https://localhost:{}/secure", port))
+            .client(&client)
+            .add_root_certificate_der(cert.der())
+            .timeout(4000)
+            .http2_only()
+            .build_async();
+        let tls_info: TlsInfo = request_builder.send().await.unwrap().tls_info().unwrap();
+        assert_eq!(tls_info.get_alpn_protocol(), Some("h2".to_string()));
+    }
+    let port: u16 = spawn_tls_server(server_config, serve_websocket_echo);
+    let mut websocket: WebSocket = WebSocketBuilder::new()
+        .connect(&format!("wss://localhost:{}/echo", port))
+        .add_root_certificate_der(cert.der())
+        .timeout(4000)
+        .build_async();
+    assert_eq!(websocket.get_tls_info(), None);
+    websocket.send_text_async("hello").await.unwrap();
+    let tls_info: TlsInfo = websocket.get_tls_info().unwrap();
+    assert_eq!(tls_info.get_peer_certificates(), vec![cert.der().to_vec()]);
+}
+
+#[tokio::test]
+async fn test_local_async_timeouts() {
+    let assert_timeout = |result: Result<(), RequestError>, step: &str| match result {
+        Err(RequestError::Request(msg)) => {
+            assert!(is_timeout(&RequestError::Request(msg.clone())), "{}", msg);
+            assert!(msg.contains(step), "{}", msg);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    };
+    let port: u16 = spawn_stalled_server(Vec::new());
+    let started: Instant = Instant::now();
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&format!("http://127.0.0.1:{}/", port))
+        .timeout(200)
+        .http1_1_only()
+        .build_async();
+    assert_timeout(
+        request_builder.send().await.map(|_| ()),
+        "reading the response",
+    );
+    assert!(started.elapsed() < Duration::from_secs(3));
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&format!("https://localhost:{}/", port))
+        .timeout(200)
+        .http1_1_only()
+        .build_async();
+    assert_timeout(
+        request_builder.send().await.map(|_| ()),
+        "performing the TLS handshake",
+    );
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get("http://example.com/")
+        .http_proxy("127.0.0.1", port)
+        .timeout(200)
+        .http1_1_only()
+        .build_async();
+    assert_timeout(
+        request_builder.send().await.map(|_| ()),
+        "negotiating with the proxy",
+    );
+    let port: u16 =
+        spawn_stalled_server(b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nhello".to_vec());
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&format!("http://127.0.0.1:{}/", port))
+        .timeout(200)
+        .http1_1_only()
+        .build_async();
+    let mut response_stream: AsyncResponseStream = request_builder.send_stream().await.unwrap();
+    assert_eq!(
+        response_stream.next().await.unwrap().unwrap(),
+        b"hello".to_vec()
+    );
+    assert_timeout(
+        response_stream.next().await.unwrap().map(|_| ()),
+        "reading the response",
+    );
+}
+
+#[tokio::test]
+async fn test_local_split_timeouts() {
+    let assert_error = |result: Result<(), RequestError>, kind: RequestTimeoutKind| match result {
+        Err(err) => assert_eq!(get_timeout_kind(&err), Some(kind), "{}", err),
+        other => panic!("unexpected result: {:?}", other),
+    };
+    let port: u16 = spawn_stalled_server(Vec::new());
+    let started: Instant = Instant::now();
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&format!("http://127.0.0.1:{}/", port))
+        .timeout(10000)
+        .read_timeout(200)
+        .http1_1_only()
+        .build_async();
+    assert_error(
+        request_builder.send().await.map(|_| ()),
+        RequestTimeoutKind::Read,
+    );
+    assert!(started.elapsed() < Duration::from_secs(3));
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&format!("http://127.0.0.1:{}/", port))
+        .timeout(10000)
+        .read_timeout(200)
+        .http1_1_only()
+        .build_sync();
+    let result: Result<(), RequestError> =
+        spawn_blocking(move || request_builder.send().map(|_| ()))
+            .await
+            .unwrap();
+    assert_error(result, RequestTimeoutKind::Read);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get("http://example.com/")
+        .http_proxy("127.0.0.1", port)
+        .timeout(10000)
+        .connect_timeout(200)
+        .http1_1_only()
+        .build_async();
+    assert_error(
+        request_builder.send().await.map(|_| ()),
+        RequestTimeoutKind::Connect,
+    );
+    let trickle_port: u16 = spawn_trickle_server(Duration::from_millis(50));
+    let started: Instant = Instant::now();
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&format!("http://127.0.0.1:{}/", trickle_port))
+        .timeout(10000)
+        .total_timeout(500)
+        .http1_1_only()
+        .build_async();
+    assert_error(
+        request_builder.send().await.map(|_| ()),
+        RequestTimeoutKind::Deadline,
+    );
+    assert!(started.elapsed() < Duration::from_secs(3));
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&format!("http://127.0.0.1:{}/", trickle_port))
+        .timeout(10000)
+        .total_timeout(500)
+        .http1_1_only()
+        .build_sync();
+    let result: Result<(), RequestError> =
+        spawn_blocking(move || request_builder.send().map(|_| ()))
+            .await
+            .unwrap();
+    assert_error(result, RequestTimeoutKind::Deadline);
+    let (url, _handle) = spawn_local_server(vec![
+        format!(
+            "HTTP/1.1 302 Found\r\nLocation: http://127.0.0.1:{}/\r\nContent-Length: 0\r\n\r\n",
+            trickle_port
+        )
+        .into_bytes(),
+    ]);
+    let started: Instant = Instant::now();
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .timeout(10000)
+        .total_timeout(500)
+        .redirect()
+        .http1_1_only()
+        .build_async();
+    assert_error(
+        request_builder.send().await.map(|_| ()),
+        RequestTimeoutKind::Deadline,
+    );
+    assert!(started.elapsed() < Duration::from_secs(3));
+}
+
+#[test]
+fn test_retry_policy() {
+    let mut retry_policy: RetryPolicy = RetryPolicy::new();
+    retry_policy
+        .initial_backoff(100)
+        .max_backoff(300)
+        .jitter(false);
+    assert_eq!(retry_policy.get_backoff(1), Duration::from_millis(100));
+    assert_eq!(retry_policy.get_backoff(2), Duration::from_millis(200));
+    assert_eq!(retry_policy.get_backoff(3), Duration::from_millis(300));
+    assert_eq!(retry_policy.get_backoff(100), Duration::from_millis(300));
+    retry_policy.jitter(true);
+    for attempt in 1..10 {
+        let backoff: Duration = retry_policy.get_backoff(attempt);
+        assert!(backoff >= Duration::from_millis(50), "{:?}", backoff);
+        assert!(backoff <= Duration::from_millis(300), "{:?}", backoff);
+    }
+    assert_eq!(
+        RetryPolicy::parse_retry_after(" 5 "),
+        Some(Duration::from_secs(5))
+    );
+    assert_eq!(
+        RetryPolicy::parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT"),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(RetryPolicy::parse_retry_after("soon"), None);
+    assert!(retry_policy.is_retryable_status(503));
+    assert!(!retry_policy.is_retryable_status(500));
+    assert!(retry_policy.is_retryable_error(&RequestError::TcpStreamConnect(String::new())));
+    assert!(
+        retry_policy.is_retryable_error(&RequestError::Request(format!(
+            "{} while reading the response after 10ms",
+            READ_TIMEOUT
+        )))
+    );
+    assert!(
+        !retry_policy.is_retryable_error(&RequestError::Request(format!(
+            "{} while reading the response after 10ms",
+            REQUEST_DEADLINE_EXCEEDED
+        )))
+    );
+    assert!(!retry_policy.is_retryable_error(&RequestError::InvalidUrl(String::new())));
+    assert!(retry_policy.is_retryable_method(&Method::GET));
+    assert!(retry_policy.is_retryable_method(&Method::PUT));
+    assert!(!retry_policy.is_retryable_method(&Method::POST));
+    retry_policy.retry_non_idempotent(true);
+    assert!(retry_policy.is_retryable_method(&Method::POST));
+}
+
+#[tokio::test]
+async fn test_local_retry_policy() {
+    let attempts: Arc<Mutex<Vec<RetryAttempt>>> = Arc::new(Mutex::new(Vec::new()));
+    let recorded: Arc<Mutex<Vec<RetryAttempt>>> = attempts.clone();
+    let mut retry_policy: RetryPolicy = RetryPolicy::new();
+    retry_policy
+        .initial_backoff(10)
+        .max_backoff(2000)
+        .on_attempt(move |retry_attempt| recorded.lock().unwrap().push(retry_attempt.clone()));
+    let (url, handle) = spawn_local_server(vec![
+        b"HTTP/1.1 503 Service Unavailable\r\nRetry-After: 1\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 502 Bad Gateway\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let started: Instant = Instant::now();
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .retry_policy(&retry_policy)
+        .http1_1_only()
+        .build_sync();
+    let response: BoxResponseTrait = spawn_blocking(move || request_builder.send())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.binary().get_status_code(), 200);
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    assert_eq!(handle.join().unwrap().len(), 3);
+    let recorded_attempts: Vec<RetryAttempt> = attempts.lock().unwrap().drain(..).collect();
+    assert_eq!(recorded_attempts.len(), 3);
+    assert_eq!(recorded_attempts[0].get_status_code(), Some(503));
+    assert_eq!(
+        recorded_attempts[0].get_delay(),
+        Some(Duration::from_secs(1))
+    );
+    assert_eq!(recorded_attempts[1].get_status_code(), Some(502));
+    assert!(recorded_attempts[1].will_retry());
+    assert_eq!(recorded_attempts[2].get_attempt(), 3);
+    assert!(!recorded_attempts[2].will_retry());
+    let (url, handle) = spawn_local_server(vec![
+        b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .post(&url)
+        .text("body")
+        .retry_policy(&retry_policy)
+        .http1_1_only()
+        .build_async();
+    let response: BoxResponseTrait = request_builder.send().await.unwrap();
+    assert_eq!(response.binary().get_status_code(), 503);
+    assert_eq!(handle.join().unwrap().len(), 1);
+    assert_eq!(attempts.lock().unwrap().drain(..).count(), 1);
+    let (url, handle) = spawn_local_server(vec![
+        b"HTTP/1.1 429 Too Many Requests\r\nRetry-After: 60\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .retry_policy(&retry_policy)
+        .http1_1_only()
+        .build_async();
+    let response: BoxResponseTrait = request_builder.send().await.unwrap();
+    assert_eq!(response.binary().get_status_code(), 429);
+    assert_eq!(handle.join().unwrap().len(), 1);
+    assert_eq!(attempts.lock().unwrap().drain(..).count(), 1);
+    let port: u16 = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&format!("http://127.0.0.1:{}/", port))
+        .retry_policy(&retry_policy)
+        .http1_1_only()
+        .build_async();
+    assert!(matches!(
+        request_builder.send().await,
+        Err(RequestError::TcpStreamConnect(_))
+    ));
+    let recorded_attempts: Vec<RetryAttempt> = attempts.lock().unwrap().drain(..).collect();
+    assert_eq!(recorded_attempts.len(), 3);
+    assert!(
+        recorded_attempts
+            .iter()
+            .all(|retry_attempt| retry_attempt.get_error().is_some())
+    );
+}
+
+#[tokio::test]
+async fn test_local_stream_retry_policy() {
+    let mut retry_policy: RetryPolicy = RetryPolicy::new();
+    retry_policy.initial_backoff(10);
+    let (url, handle) = spawn_local_server(vec![
+        b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 4\r\n\r\nbusy".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .retry_policy(&retry_policy)
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let body: String = spawn_blocking(move || {
+        let mut response_stream: ResponseStream = request_builder.send_stream().unwrap();
+        assert_eq!(response_stream.get_status_code(), 200);
+        let mut body: String = String::new();
+        response_stream.read_to_string(&mut body).unwrap();
+        body
+    })
+    .await
+    .unwrap();
+    assert_eq!(body, "ok");
+    assert_eq!(handle.join().unwrap().len(), 2);
+    let (url, handle) = spawn_local_server(vec![
+        b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 4\r\n\r\nbusy".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .retry_policy(&retry_policy)
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    let mut response_stream: AsyncResponseStream = request_builder.send_stream().await.unwrap();
+    assert_eq!(response_stream.get_status_code(), 200);
+    assert_eq!(response_stream.next().await.unwrap().unwrap(), b"ok");
+    drop(response_stream);
+    assert_eq!(handle.join().unwrap().len(), 2);
+}
+
+#[test]
+fn test_local_retry_after_redirect() {
+    let (target_url, target) = spawn_local_server(vec![
+        b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n".to_vec(),
+    ]);
+    let (url, server) = spawn_local_server(vec![
+        format!(
+            "HTTP/1.1 303 See Other\r\nLocation: {}/next\r\nContent-Length: 0\r\n\r\n",
+            target_url
+        )
+        .into_bytes(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut retry_policy: RetryPolicy = RetryPolicy::new();
+    retry_policy.initial_backoff(10).retry_non_idempotent(true);
+    let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+    header.insert("Authorization", "Bearer token");
+    header.insert("Content-Type", "text/plain");
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .post(&url)
+        .headers(header)
+        .text("payload")
+        .retry_policy(&retry_policy)
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    assert_eq!(response.binary().get_status_code(), 200);
+    drop(request_builder);
+    let redirected: String = String::from_utf8_lossy(&target.join().unwrap()[0]).to_lowercase();
+    assert!(redirected.starts_with("get /next"));
+    assert!(!redirected.contains("authorization"));
+    let requests: Vec<Vec<u8>> = server.join().unwrap();
+    let retried: String = String::from_utf8_lossy(&requests[1]).to_lowercase();
+    assert!(retried.starts_with("post / "));
+    assert!(retried.contains("authorization: bearer token"));
+    assert!(retried.ends_with("payload"));
+}
+
+#[cfg(test)]
+struct RecordingMiddleware {
+    name: &'static str,
+    events: Arc<Mutex<Vec<String>>>,
+    url: Option<String>,
+    short_circuit: bool,
+    recover: bool,
+}
+
+#[cfg(test)]
+impl Middleware for RecordingMiddleware {
+    fn on_request(
+        &self,
+        request: &mut HttpRequest,
+    ) -> Result<Option<HttpResponseBinary>, RequestError> {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("{} request", self.name));
+        request.set_header("X-Middleware", self.name);
+        if let Some(url) = &self.url {
+            request.set_url(url);
+        }
+        if self.short_circuit {
+            let mut response: HttpResponseBinary = HttpResponseBinary::new(203);
+            response.set_body("cached");
+            return Ok(Some(response));
+        }
+        Ok(None)
+    }
+
+    fn on_response(
+        &self,
+        _request: &HttpRequest,
+        response: &mut HttpResponseBinary,
+    ) -> Result<(), RequestError> {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("{} response", self.name));
+        response.set_header("X-Seen-By", self.name);
+        Ok(())
+    }
+
+    fn on_error(
+        &self,
+        _request: &HttpRequest,
+        error: RequestError,
+    ) -> Result<HttpResponseBinary, RequestError> {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("{} error", self.name));
+        if self.recover {
+            return Ok(HttpResponseBinary::new(503));
+        }
+        Err(error)
+    }
+}
+
+#[cfg(test)]
+fn recording_middleware(
+    name: &'static str,
+    events: &Arc<Mutex<Vec<String>>>,
+) -> RecordingMiddleware {
+    RecordingMiddleware {
+        name,
+        events: events.clone(),
+        url: None,
+        short_circuit: false,
+        recover: false,
+    }
+}
+
+#[tokio::test]
+async fn test_local_middleware() {
+    let closed_url: String = format!(
+        "http://127.0.0.1:{}/",
+        TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port()
+    );
+    let events: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let (url, handle) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut client_middleware: RecordingMiddleware = recording_middleware("client", &events);
+    client_middleware.url = Some(url);
+    let client: Client = Client::builder().middleware(client_middleware).build();
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&closed_url)
+        .client(&client)
+        .middleware(recording_middleware("request", &events))
+        .http1_1_only()
+        .build_sync();
+    let response: BoxResponseTrait = spawn_blocking(move || request_builder.send())
+        .await
+        .unwrap()
+        .unwrap();
+    let response: HttpResponseBinary = response.binary();
+    assert_eq!(response.get_status_code(), 200);
+    assert_eq!(response.get_body(), b"ok".to_vec());
+    assert_eq!(
+        response.get_header_all("x-seen-by"),
+        vec!["client".to_string()]
+    );
+    drop(client);
+    let request: String = String::from_utf8(handle.join().unwrap().remove(0)).unwrap();
+    assert!(request.contains("X-Middleware: request\r\n"), "{}", request);
+    assert_eq!(
+        events.lock().unwrap().drain(..).collect::<Vec<String>>(),
+        vec![
+            "client request",
+            "request request",
+            "request response",
+            "client response"
+        ]
+    );
+    let mut recovering_middleware: RecordingMiddleware = recording_middleware("recover", &events);
+    recovering_middleware.recover = true;
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&closed_url)
+        .middleware(recovering_middleware)
+        .http1_1_only()
+        .build_async();
+    let response: BoxResponseTrait = request_builder.send().await.unwrap();
+    assert_eq!(response.binary().get_status_code(), 503);
+    assert_eq!(response.binary().get_status_text(), HttpStatus::phrase(503));
+    assert_eq!(
+        events.lock().unwrap().drain(..).collect::<Vec<String>>(),
+        vec!["recover request", "recover error"]
+    );
+    let mut caching_middleware: RecordingMiddleware = recording_middleware("cache", &events);
+    caching_middleware.short_circuit = true;
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&closed_url)
+        .middleware(recording_middleware("outer", &events))
+        .middleware(caching_middleware)
+        .middleware(recording_middleware("inner", &events))
+        .http1_1_only()
+        .build_async();
+    let response: HttpResponseBinary = request_builder.send().await.unwrap().binary();
+    assert_eq!(response.get_status_code(), 203);
+    assert_eq!(response.get_body(), b"cached".to_vec());
+    assert_eq!(
+        response.get_header_all("x-seen-by"),
+        vec!["outer".to_string()]
+    );
+    assert_eq!(
+        events.lock().unwrap().drain(..).collect::<Vec<String>>(),
+        vec!["outer request", "cache request", "outer response"]
+    );
+}
+
+#[tokio::test]
+async fn test_local_stream_middleware() {
+    let events: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let (url, handle) = spawn_local_server(vec![
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .middleware(recording_middleware("request", &events))
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let body: String = spawn_blocking(move || {
+        let mut response_stream: ResponseStream = request_builder.send_stream().unwrap();
+        assert_eq!(
+            response_stream.get_header_map().get_all("x-seen-by"),
+            vec!["request".to_string()]
+        );
+        let mut body: String = String::new();
+        response_stream.read_to_string(&mut body).unwrap();
+        body
+    })
+    .await
+    .unwrap();
+    assert_eq!(body, "ok");
+    let request: String = String::from_utf8(handle.join().unwrap().remove(0)).unwrap();
+    assert!(request.contains("X-Middleware: request\r\n"), "{}", request);
+    assert_eq!(
+        events.lock().unwrap().drain(..).collect::<Vec<String>>(),
+        vec!["request request", "request response"]
+    );
+    let mut caching_middleware: RecordingMiddleware = recording_middleware("cache", &events);
+    caching_middleware.short_circuit = true;
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .middleware(recording_middleware("outer", &events))
+        .middleware(caching_middleware)
+        .http1_1_only()
+        .build_async();
+    let mut response_stream: AsyncResponseStream = request_builder.send_stream().await.unwrap();
+    assert_eq!(response_stream.get_status_code(), 203);
+    assert_eq!(response_stream.next().await.unwrap().unwrap(), b"cached");
+    assert!(response_stream.next().await.is_none());
+    assert_eq!(
+        events.lock().unwrap().drain(..).collect::<Vec<String>>(),
+        vec!["outer request", "cache request", "outer response"]
+    );
+}
+
+#[test]
+fn test_md5() {
+    assert_eq!(hex_encode(&md5(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+    assert_eq!(hex_encode(&md5(b"abc")), "900150983cd24fb0d6963f7d28e17f72");
+    assert_eq!(
+        hex_encode(&md5(b"message digest")),
+        "f96b697d7cb7938d525a2f31aaf161d0"
+    );
+    assert_eq!(
+        hex_encode(&md5(
+            b"12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+        )),
+        "57edf4a22be3c955ac49da2e2107b67a"
+    );
+}
+
+#[test]
+fn test_digest_auth() {
+    let header: String = "Basic realm=\"basic\", Digest realm=\"http-auth@example.org\", \
+        qop=\"auth, auth-int\", algorithm=MD5, \
+        nonce=\"7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v\", \
+        opaque=\"FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS\""
+        .to_string();
+    let sha256_header: String = header.replace("algorithm=MD5", "algorithm=SHA-256");
+    let challenge: DigestChallenge =
+        DigestChallenge::parse_all(&[header.clone(), sha256_header]).unwrap();
+    assert_eq!(challenge.algorithm, DigestAlgorithm::Sha256);
+    assert_eq!(challenge.realm, "http-auth@example.org");
+    assert!(challenge.qop);
+    assert_eq!(
+        challenge.opaque.as_deref(),
+        Some("FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS")
+    );
+    let digest_auth: DigestAuth = DigestAuth::new("Mufasa", "Circle of Life");
+    let cnonce: &str = "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ";
+    assert_eq!(
+        digest_auth.get_response(&challenge, "GET", "/dir/index.html", "00000001", cnonce),
+        "753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1"
+    );
+    let challenge: DigestChallenge = DigestChallenge::parse_all(&[header]).unwrap();
+    assert_eq!(challenge.algorithm, DigestAlgorithm::Md5);
+    assert_eq!(
+        digest_auth.get_response(&challenge, "GET", "/dir/index.html", "00000001", cnonce),
+        "8ca523f5e9506fed4657c9700eebdbec"
+    );
+    assert!(
+        DigestChallenge::parse_all(&["Digest realm=\"r\", nonce=\"n\", qop=\"auth-int\"".into()])
+            .is_none()
+    );
+    assert!(
+        DigestChallenge::parse_all(&["Digest realm=\"r\", nonce=\"n\", algorithm=SHA-512".into()])
+            .is_none()
+    );
+    assert!(DigestChallenge::parse_all(&["Basic realm=\"r\"".into()]).is_none());
+}
+
+#[cfg(test)]
+fn get_digest_params(request: &[u8], header_name: &str) -> Vec<(String, String)> {
+    let request: String = String::from_utf8_lossy(request).into_owned();
+    let authorization: &str = request
+        .lines()
+        .find_map(|line| {
+            line.split_once(':')
+                .filter(|(name, _)| name.eq_ignore_ascii_case(header_name))
+                .map(|(_, value)| value)
+        })
+        .unwrap_or_default();
+    DigestChallenge::split_challenges(authorization.trim())
+        .pop()
+        .map(|(_, params)| params)
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn test_local_digest_auth() {
+    let challenge: &str = "Digest realm=\"local\", qop=\"auth\", algorithm=SHA-256, \
+        nonce=\"abc123\", opaque=\"xyz\"";
+    let (url, handle) = spawn_local_server(vec![
+        format!(
+            "HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Basic realm=\"local\"\r\n\
+            WWW-Authenticate: {}\r\nContent-Length: 0\r\n\r\n",
+            challenge
+        )
+        .into_bytes(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .post(&format!("{}/private?x=1", url))
+        .digest_auth("user", "secret")
+        .http1_1_only()
+        .build_sync();
+    let request_builder: BoxRequestTrait = spawn_blocking(move || {
+        let response: BoxResponseTrait = request_builder.send().unwrap();
+        assert_eq!(response.binary().get_status_code(), 200);
+        let response: BoxResponseTrait = request_builder.send().unwrap();
+        assert_eq!(response.binary().get_status_code(), 200);
+        request_builder
+    })
+    .await
+    .unwrap();
+    drop(request_builder);
+    let requests: Vec<Vec<u8>> = handle.join().unwrap();
+    assert!(get_digest_params(&requests[0], AUTHORIZATION).is_empty());
+    let challenge: DigestChallenge = DigestChallenge::parse_all(&[challenge.to_string()]).unwrap();
+    let digest_auth: DigestAuth = DigestAuth::new("user", "secret");
+    for (request, nonce_count) in requests[1..].iter().zip(["00000001", "00000002"]) {
+        let params: Vec<(String, String)> = get_digest_params(request, AUTHORIZATION);
+        let get = |name: &str| -> String {
+            params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default()
+        };
+        assert_eq!(get("username"), "user");
+        assert_eq!(get("uri"), "/private?x=1");
+        assert_eq!(get("algorithm"), "SHA-256");
+        assert_eq!(get("opaque"), "xyz");
+        assert_eq!(get("qop"), "auth");
+        assert_eq!(get("nc"), nonce_count);
+        assert_eq!(
+            get("response"),
+            digest_auth.get_response(
+                &challenge,
+                "POST",
+                "/private?x=1",
+                nonce_count,
+                &get("cnonce")
+            )
+        );
+    }
+    let (url, handle) = spawn_local_server(vec![
+        b"HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Digest realm=\"r\", nonce=\"n\"\r\n\
+        Content-Length: 0\r\n\r\n"
+            .to_vec(),
+        b"HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Digest realm=\"r\", nonce=\"n\"\r\n\
+        Content-Length: 0\r\n\r\n"
+            .to_vec(),
+    ]);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .digest_auth("user", "wrong")
+        .http1_1_only()
+        .build_async();
+    let response: BoxResponseTrait = request_builder.send().await.unwrap();
+    assert_eq!(response.binary().get_status_code(), 401);
+    let requests: Vec<Vec<u8>> = handle.join().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(get_digest_params(&requests[0], AUTHORIZATION).is_empty());
+    assert!(!get_digest_params(&requests[1], AUTHORIZATION).is_empty());
+}
+
+#[test]
+fn test_local_proxy_digest_auth() {
+    let challenge: &str = "Digest realm=\"proxy\", qop=\"auth\", nonce=\"p123\"";
+    let (url, handle) = spawn_local_server(vec![
+        format!(
+            "HTTP/1.1 407 Proxy Authentication Required\r\nProxy-Authenticate: {}\r\n\
+            Content-Length: 0\r\n\r\n",
+            challenge
+        )
+        .into_bytes(),
+        b"HTTP/1.1 200 Connection established\r\n\r\n\
+        HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok"
+            .to_vec(),
+    ]);
+    let port: u16 = url.rsplit(':').next().unwrap().parse().unwrap();
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get("http://example.test/")
+        .http_proxy_auth("127.0.0.1", port, "user", "secret")
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let response: BoxResponseTrait = request_builder.send().unwrap();
+    assert_eq!(response.binary().get_body(), b"ok");
+    drop(request_builder);
+    let requests: Vec<Vec<u8>> = handle.join().unwrap();
+    let first: String = String::from_utf8_lossy(&requests[0]).to_lowercase();
+    assert!(first.starts_with("connect example.test:80 http/1.1"));
+    assert!(first.contains("proxy-authorization: basic dxnlcjpzzwnyzxq="));
+    let params: Vec<(String, String)> = get_digest_params(&requests[1], PROXY_AUTHORIZATION);
+    let get = |name: &str| -> String {
+        params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default()
+    };
+    assert_eq!(get("uri"), "example.test:80");
+    let challenge: DigestChallenge = DigestChallenge::parse_all(&[challenge.to_string()]).unwrap();
+    assert_eq!(
+        get("response"),
+        DigestAuth::new("user", "secret").get_response(
+            &challenge,
+            "CONNECT",
+            "example.test:80",
+            "00000001",
+            &get("cnonce")
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_local_proxy_connect_error() {
+    let (url, handle) = spawn_local_server(vec![
+        b"HTTP/1.1 407 Proxy Authentication Required\r\n\
+        Proxy-Authenticate: Digest realm=\"proxy\", nonce=\"p123\"\r\nContent-Length: 0\r\n\r\n"
+            .to_vec(),
+        b"HTTP/1.1 403 Forbidden\r\nContent-Length: 18\r\n\r\nblocked by policy\n".to_vec(),
+    ]);
+    let port: u16 = url.rsplit(':').next().unwrap().parse().unwrap();
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get("http://example.test/")
+        .http_proxy_auth("127.0.0.1", port, "user", "secret")
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    let error: RequestError = request_builder.send().await.unwrap_err();
+    assert_eq!(
+        error,
+        RequestError::Request(format!(
+            "{}: 403 Forbidden: blocked by policy",
+            PROXY_CONNECT_FAILED
+        ))
+    );
+    drop(request_builder);
+    let requests: Vec<Vec<u8>> = handle.join().unwrap();
+    assert!(!get_digest_params(&requests[1], PROXY_AUTHORIZATION).is_empty());
+}
+
+#[tokio::test]
+async fn test_local_stream_redirect_body_limit() {
+    let url: String = format!("{}/redirect", spawn_endless_redirect_server());
+    let client: Client = Client::new();
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .client(&client)
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let body: String = spawn_blocking(move || {
+        let mut response_stream: ResponseStream = request_builder.send_stream().unwrap();
+        assert_eq!(response_stream.get_status_code(), 200);
+        let mut body: String = String::new();
+        response_stream.read_to_string(&mut body).unwrap();
+        body
+    })
+    .await
+    .unwrap();
+    assert_eq!(body, "ok");
+    assert_eq!(client.idle_connections(), 1);
+    let client: Client = Client::new();
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .client(&client)
+        .redirect()
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    let mut response_stream: AsyncResponseStream = request_builder.send_stream().await.unwrap();
+    assert_eq!(response_stream.get_status_code(), 200);
+    assert_eq!(response_stream.next().await.unwrap().unwrap(), b"ok");
+    assert!(response_stream.next().await.is_none());
+    assert_eq!(client.idle_connections(), 1);
+}
+
+#[tokio::test]
+async fn test_local_stream_digest_auth() {
+    let challenge: &[u8] = b"HTTP/1.1 401 Unauthorized\r\n\
+        WWW-Authenticate: Digest realm=\"r\", qop=\"auth\", nonce=\"n\"\r\n\
+        Content-Length: 6\r\n\r\ndenied";
+    let (url, handle) = spawn_local_server(vec![
+        challenge.to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .digest_auth("user", "secret")
+        .timeout(4000)
+        .http1_1_only()
+        .build_sync();
+    let body: String = spawn_blocking(move || {
+        let mut response_stream: ResponseStream = request_builder.send_stream().unwrap();
+        assert_eq!(response_stream.get_status_code(), 200);
+        let mut body: String = String::new();
+        response_stream.read_to_string(&mut body).unwrap();
+        body
+    })
+    .await
+    .unwrap();
+    assert_eq!(body, "ok");
+    let requests: Vec<Vec<u8>> = handle.join().unwrap();
+    assert!(get_digest_params(&requests[0], AUTHORIZATION).is_empty());
+    assert!(!get_digest_params(&requests[1], AUTHORIZATION).is_empty());
+    let (url, handle) = spawn_local_server(vec![
+        challenge.to_vec(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_vec(),
+    ]);
+    let mut request_builder: BoxAsyncRequestTrait = RequestBuilder::new()
+        .get(&url)
+        .digest_auth("user", "secret")
+        .timeout(4000)
+        .http1_1_only()
+        .build_async();
+    let mut response_stream: AsyncResponseStream = request_builder.send_stream().await.unwrap();
+    assert_eq!(response_stream.get_status_code(), 200);
+    assert_eq!(response_stream.next().await.unwrap().unwrap(), b"ok");
+    drop(response_stream);
+    drop(request_builder);
+    let requests: Vec<Vec<u8>> = handle.join().unwrap();
+    assert!(!get_digest_params(&requests[1], AUTHORIZATION).is_empty());
+}
@@ -0,0 +1,51 @@
+use crate::*;
+
+impl Default for Client {
+    fn default() -> Self {
+        ClientBuilder::new().build()
+    }
+}
+
+impl Debug for Client {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("config", &self.pool.config)
+            .field("idle_connections", &self.idle_connections())
+            .finish()
+    }
+}
+
+impl Client {
+    /// Creates a new client with the default pool limits.
+    ///
+    /// # Returns
+    /// Returns a new `Client` with an empty connection pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder for configuring the connection pool limits.
+    ///
+    /// # Returns
+    /// Returns a new `ClientBuilder`.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// Returns the number of idle connections currently kept alive by this client.
+    pub fn idle_connections(&self) -> usize {
+        self.pool.get_idle_count()
+    }
+
+    /// Returns the cookie jar shared by requests sent through this client, if any.
+    pub fn get_cookie_jar(&self) -> Option<CookieJar> {
+        self.cookie_jar.clone()
+    }
+
+    /// Closes every idle connection kept by this client.
+    ///
+    /// Connections currently in use are not affected.
+    pub fn clear_idle_connections(&self) {
+        self.pool.clear();
+    }
+}
@@ -0,0 +1,12 @@
+use crate::*;
+
+/// A reusable HTTP client that keeps idle HTTP/1.1 connections alive between requests.
+///
+/// Connections are pooled per scheme, host, port and proxy. Cloning a `Client` is cheap
+/// and every clone shares the same pool. Attach it to a request with `RequestBuilder::client`.
+#[derive(Clone)]
+pub struct Client {
+    pub(crate) pool: Arc<ConnectionPool>,
+    pub(crate) cookie_jar: Option<CookieJar>,
+    pub(crate) middlewares: Vec<ArcMiddleware>,
+}
@@ -0,0 +1,93 @@
+use crate::*;
+
+impl ClientBuilder {
+    /// Creates a new builder with the default pool limits.
+    ///
+    /// # Returns
+    /// Returns a new instance of `ClientBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of idle connections kept per scheme, host, port and proxy.
+    ///
+    /// A value of `0` disables keep-alive reuse entirely.
+    ///
+    /// # Parameters
+    /// - `max_idle_per_host`: The maximum number of idle connections per host.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `ClientBuilder` to allow method chaining.
+    pub fn max_idle_per_host(&mut self, max_idle_per_host: usize) -> &mut Self {
+        self.config.max_idle_per_host = max_idle_per_host;
+        self
+    }
+
+    /// Sets how long an idle connection is kept before it is closed, in milliseconds.
+    ///
+    /// # Parameters
+    /// - `idle_timeout`: The idle timeout in milliseconds.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `ClientBuilder` to allow method chaining.
+    pub fn idle_timeout(&mut self, idle_timeout: u64) -> &mut Self {
+        self.config.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Sets the maximum number of connections, idle or in use, open at the same time.
+    ///
+    /// When the limit is reached, the longest idle connection is closed to make room.
+    /// If every connection is in use, a request waits for one to become available
+    /// for up to its own timeout.
+    ///
+    /// # Parameters
+    /// - `max_connections`: The maximum number of open connections.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `ClientBuilder` to allow method chaining.
+    pub fn max_connections(&mut self, max_connections: usize) -> &mut Self {
+        self.config.max_connections = max_connections.max(1);
+        self
+    }
+
+    /// Shares a cookie jar with every request sent through the client.
+    ///
+    /// A jar attached to an individual request with `RequestBuilder::cookie_jar` takes precedence.
+    ///
+    /// # Parameters
+    /// - `cookie_jar`: The cookie jar to use.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `ClientBuilder` to allow method chaining.
+    pub fn cookie_jar(&mut self, cookie_jar: &CookieJar) -> &mut Self {
+        self.cookie_jar = Some(cookie_jar.clone());
+        self
+    }
+
+    /// Adds a middleware run around every request sent through the client.
+    ///
+    /// Client middleware runs before any middleware registered on the request itself.
+    ///
+    /// # Parameters
+    /// - `middleware`: The middleware to add.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `ClientBuilder` to allow method chaining.
+    pub fn middleware<M: Middleware + 'static>(&mut self, middleware: M) -> &mut Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Finalizes the builder and returns a `Client` with an empty connection pool.
+    ///
+    /// # Returns
+    /// Returns a new `Client` using the configured limits.
+    pub fn build(&self) -> Client {
+        Client {
+            pool: Arc::new(ConnectionPool::new(self.config)),
+            cookie_jar: self.cookie_jar.clone(),
+            middlewares: self.middlewares.clone(),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::*;
+
+/// Builder for configuring the connection pool of a `Client`.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    pub(crate) config: PoolConfig,
+    pub(crate) cookie_jar: Option<CookieJar>,
+    pub(crate) middlewares: Vec<ArcMiddleware>,
+}
@@ -0,0 +1,8 @@
+pub(crate) mod client;
+pub(crate) mod client_builder;
+pub(crate) mod pool;
+
+pub use client::*;
+pub use client_builder::*;
+
+pub(crate) use pool::*;
@@ -0,0 +1,3 @@
+pub(crate) const DEFAULT_MAX_IDLE_PER_HOST: usize = 32;
+pub(crate) const DEFAULT_IDLE_TIMEOUT: u64 = 90_000;
+pub(crate) const DEFAULT_MAX_CONNECTIONS: usize = usize::MAX;
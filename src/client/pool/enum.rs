@@ -0,0 +1,9 @@
+use crate::*;
+
+/// The result of checking a connection out of the pool.
+pub(crate) enum Checkout<S> {
+    /// An idle keep-alive connection that can be reused as is.
+    Reused(IdleConnection<S>),
+    /// Permission to open a new connection.
+    New(ConnectionPermit),
+}
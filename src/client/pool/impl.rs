@@ -0,0 +1,427 @@
+use crate::*;
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_idle_per_host: DEFAULT_MAX_IDLE_PER_HOST,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+        }
+    }
+}
+
+impl ConnectionPermit {
+    pub(crate) fn new(pool: &Arc<ConnectionPool>) -> Self {
+        Self {
+            pool: Arc::downgrade(pool),
+        }
+    }
+}
+
+/// Releases the connection slot and wakes up requests waiting for one.
+///
+/// A permit must never be dropped while the pool state lock is held.
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.upgrade() {
+            if let Ok(mut state) = pool.state.lock() {
+                state.total = state.total.saturating_sub(1);
+            }
+            pool.notify_available();
+        }
+    }
+}
+
+impl<S> PooledConnection<S> {
+    pub(crate) fn new(stream: S, key: Option<PoolKey>, permit: Option<ConnectionPermit>) -> Self {
+        Self {
+            stream,
+            key,
+            permit,
+            reused: false,
+            written: false,
+            received: false,
+            keep_alive: false,
+            tls_info: None,
+        }
+    }
+
+    pub(crate) fn reused(key: PoolKey, idle_connection: IdleConnection<S>) -> Self {
+        let mut connection: Self = Self::new(
+            idle_connection.stream,
+            Some(key),
+            Some(idle_connection.permit),
+        );
+        connection.reused = true;
+        connection.tls_info = idle_connection.tls_info;
+        connection
+    }
+}
+
+impl ConnectionPool {
+    pub(crate) fn new(config: PoolConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(PoolState {
+                idle: HashMap::new(),
+                idle_async: HashMap::new(),
+                http2: HashMap::new(),
+                http2_connecting: HashMap::new(),
+                total: 0,
+            }),
+            available: Condvar::new(),
+            available_async: Notify::new(),
+            runtime: Mutex::new(None),
+        }
+    }
+
+    /// Returns the runtime driving the HTTP/2 connections of synchronous requests.
+    ///
+    /// It is created on first use, while holding the lock, so that clients used only for
+    /// HTTP/1.1 never start one and concurrent first requests start only one.
+    pub(crate) fn get_runtime(&self) -> Result<RuntimeHandle, RequestError> {
+        let mut runtime: MutexGuard<'_, Option<Runtime>> = self
+            .runtime
+            .lock()
+            .map_err(|err| RequestError::Unknown(err.to_string()))?;
+        if let Some(runtime) = runtime.as_ref() {
+            return Ok(runtime.handle().clone());
+        }
+        let new_runtime: Runtime = RuntimeBuilder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .map_err(|err| RequestError::Unknown(err.to_string()))?;
+        Ok(runtime.insert(new_runtime).handle().clone())
+    }
+
+    fn notify_available(&self) {
+        self.available.notify_all();
+        self.available_async.notify_one();
+    }
+
+    fn get_idle_timeout(&self) -> Duration {
+        Duration::from_millis(self.config.idle_timeout)
+    }
+
+    fn get_exhausted_error(timeout: Duration) -> RequestError {
+        RequestError::Request(format!(
+            "connection pool exhausted: no connection available within {}ms",
+            timeout.as_millis()
+        ))
+    }
+
+    /// Moves every connection that has been idle for longer than the idle timeout into `expired`.
+    fn take_expired<S>(
+        idle: &mut IdleConnections<S>,
+        idle_timeout: Duration,
+        expired: &mut Vec<IdleConnection<S>>,
+    ) {
+        for queue in idle.values_mut() {
+            while queue
+                .front()
+                .is_some_and(|connection| connection.idle_since.elapsed() >= idle_timeout)
+            {
+                if let Some(connection) = queue.pop_front() {
+                    expired.push(connection);
+                }
+            }
+        }
+        idle.retain(|_, queue| !queue.is_empty());
+    }
+
+    /// Closes the longest idle connection of `idle` and returns its permit.
+    fn take_oldest_permit<S>(idle: &mut IdleConnections<S>) -> Option<ConnectionPermit> {
+        let key: PoolKey = idle
+            .iter()
+            .filter_map(|(key, queue)| queue.front().map(|connection| (key, connection.idle_since)))
+            .min_by_key(|(_, idle_since)| *idle_since)
+            .map(|(key, _)| key.clone())?;
+        let queue: &mut VecDeque<IdleConnection<S>> = idle.get_mut(&key)?;
+        let connection: IdleConnection<S> = queue.pop_front()?;
+        if queue.is_empty() {
+            idle.remove(&key);
+        }
+        let IdleConnection { stream, permit, .. } = connection;
+        drop(stream);
+        Some(permit)
+    }
+
+    /// Reuses an idle connection for `key` or reserves a slot for a new one.
+    ///
+    /// When the pool is full, the longest idle connection of any host is closed to make room.
+    /// Returns `None` if every connection is in use.
+    fn try_checkout<S>(
+        self: &Arc<Self>,
+        state: &mut PoolState,
+        idle: fn(&mut PoolState) -> &mut IdleConnections<S>,
+        key: &PoolKey,
+        reuse: bool,
+        expired: &mut Vec<IdleConnection<S>>,
+    ) -> Option<Checkout<S>> {
+        Self::take_expired(idle(state), self.get_idle_timeout(), expired);
+        if reuse
+            && let Some(queue) = idle(state).get_mut(key)
+            && let Some(connection) = queue.pop_back()
+        {
+            if queue.is_empty() {
+                idle(state).remove(key);
+            }
+            return Some(Checkout::Reused(connection));
+        }
+        if state.total < self.config.max_connections {
+            state.total += 1;
+            return Some(Checkout::New(ConnectionPermit::new(self)));
+        }
+        Self::take_oldest_permit(idle(state))
+            .or_else(|| Self::take_oldest_permit(&mut state.idle))
+            .or_else(|| Self::take_oldest_permit(&mut state.idle_async))
+            .map(Checkout::New)
+    }
+
+    /// Stores an idle connection, returning whichever connection no longer fits in the pool.
+    fn put_idle<S>(
+        &self,
+        idle: &mut IdleConnections<S>,
+        key: PoolKey,
+        stream: S,
+        permit: ConnectionPermit,
+        tls_info: Option<TlsInfo>,
+    ) -> Option<IdleConnection<S>> {
+        let connection: IdleConnection<S> = IdleConnection {
+            stream,
+            permit,
+            idle_since: Instant::now(),
+            tls_info,
+        };
+        if self.config.max_idle_per_host == 0 {
+            return Some(connection);
+        }
+        let queue: &mut VecDeque<IdleConnection<S>> = idle.entry(key).or_default();
+        let evicted: Option<IdleConnection<S>> = if queue.len() >= self.config.max_idle_per_host {
+            queue.pop_front()
+        } else {
+            None
+        };
+        queue.push_back(connection);
+        evicted
+    }
+
+    /// Checks out a connection for a synchronous request, waiting up to `timeout` for a free slot.
+    pub(crate) fn checkout(
+        self: &Arc<Self>,
+        key: &PoolKey,
+        reuse: bool,
+        timeout: Duration,
+    ) -> Result<Checkout<BoxReadWrite>, RequestError> {
+        let deadline: Instant = Instant::now() + timeout;
+        let mut expired: Vec<IdleConnection<BoxReadWrite>> = Vec::new();
+        let mut state: MutexGuard<'_, PoolState> = self
+            .state
+            .lock()
+            .map_err(|err| RequestError::Unknown(err.to_string()))?;
+        loop {
+            if let Some(checkout) = self.try_checkout(
+                &mut state,
+                |state| &mut state.idle,
+                key,
+                reuse,
+                &mut expired,
+            ) {
+                drop(state);
+                return Ok(checkout);
+            }
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                drop(state);
+                return Err(Self::get_exhausted_error(timeout));
+            }
+            state = self
+                .available
+                .wait_timeout(state, remaining)
+                .map_err(|err| RequestError::Unknown(err.to_string()))?
+                .0;
+        }
+    }
+
+    /// Checks out a connection for an asynchronous request, waiting up to `timeout` for a free slot.
+    pub(crate) async fn checkout_async(
+        self: &Arc<Self>,
+        key: &PoolKey,
+        reuse: bool,
+        timeout_duration: Duration,
+    ) -> Result<Checkout<BoxAsyncReadWrite>, RequestError> {
+        let deadline: Instant = Instant::now() + timeout_duration;
+        let mut expired: Vec<IdleConnection<BoxAsyncReadWrite>> = Vec::new();
+        loop {
+            let mut notified: Pin<&mut Notified<'_>> = pin!(self.available_async.notified());
+            notified.as_mut().enable();
+            {
+                let mut state: MutexGuard<'_, PoolState> = self
+                    .state
+                    .lock()
+                    .map_err(|err| RequestError::Unknown(err.to_string()))?;
+                if let Some(checkout) = self.try_checkout(
+                    &mut state,
+                    |state| &mut state.idle_async,
+                    key,
+                    reuse,
+                    &mut expired,
+                ) {
+                    drop(state);
+                    return Ok(checkout);
+                }
+            }
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() || timeout(remaining, notified).await.is_err() {
+                return Err(Self::get_exhausted_error(timeout_duration));
+            }
+        }
+    }
+
+    /// Returns a synchronous keep-alive connection to the pool.
+    pub(crate) fn release(
+        &self,
+        key: PoolKey,
+        stream: BoxReadWrite,
+        permit: ConnectionPermit,
+        tls_info: Option<TlsInfo>,
+    ) {
+        let evicted: Option<IdleConnection<BoxReadWrite>> = match self.state.lock() {
+            Ok(mut state) => self.put_idle(&mut state.idle, key, stream, permit, tls_info),
+            Err(_) => return,
+        };
+        drop(evicted);
+        self.notify_available();
+    }
+
+    /// Returns an asynchronous keep-alive connection to the pool.
+    pub(crate) fn release_async(
+        &self,
+        key: PoolKey,
+        stream: BoxAsyncReadWrite,
+        permit: ConnectionPermit,
+        tls_info: Option<TlsInfo>,
+    ) {
+        let evicted: Option<IdleConnection<BoxAsyncReadWrite>> = match self.state.lock() {
+            Ok(mut state) => self.put_idle(&mut state.idle_async, key, stream, permit, tls_info),
+            Err(_) => return,
+        };
+        drop(evicted);
+        self.notify_available();
+    }
+
+    /// Reserves a slot for a new connection, waiting up to `timeout` for one to become free.
+    pub(crate) async fn reserve_async(
+        self: &Arc<Self>,
+        key: &PoolKey,
+        timeout_duration: Duration,
+    ) -> Result<ConnectionPermit, RequestError> {
+        match self.checkout_async(key, false, timeout_duration).await? {
+            Checkout::New(permit) => Ok(permit),
+            Checkout::Reused(idle_connection) => Ok(idle_connection.permit),
+        }
+    }
+
+    /// Returns a handle to the shared HTTP/2 connection for `key`, unless it has been idle for too long.
+    pub(crate) fn get_http2(&self, key: &PoolKey) -> Option<(SendRequest<Bytes>, Option<TlsInfo>)> {
+        let idle_timeout: Duration = self.get_idle_timeout();
+        let expired: Option<Http2Connection> = {
+            let mut state: MutexGuard<'_, PoolState> = self.state.lock().ok()?;
+            match state.http2.get_mut(key) {
+                Some(connection) if connection.idle_since.elapsed() < idle_timeout => {
+                    connection.idle_since = Instant::now();
+                    return Some((connection.send_request.clone(), connection.tls_info.clone()));
+                }
+                Some(_) => state.http2.remove(key),
+                None => None,
+            }
+        };
+        drop(expired);
+        None
+    }
+
+    /// Returns the lock serializing the opening of HTTP/2 connections for `key`.
+    ///
+    /// Concurrent requests to the same origin wait for the first connection instead of opening their own.
+    pub(crate) fn get_http2_lock(&self, key: &PoolKey) -> Arc<AsyncMutex<()>> {
+        match self.state.lock() {
+            Ok(mut state) => state
+                .http2_connecting
+                .entry(key.clone())
+                .or_default()
+                .clone(),
+            Err(_) => Arc::new(AsyncMutex::new(())),
+        }
+    }
+
+    /// Shares a new HTTP/2 connection with later requests for `key`.
+    pub(crate) fn put_http2(
+        &self,
+        key: PoolKey,
+        send_request: SendRequest<Bytes>,
+        permit: ConnectionPermit,
+        tls_info: Option<TlsInfo>,
+    ) {
+        let connection: Http2Connection = Http2Connection {
+            send_request,
+            _permit: permit,
+            idle_since: Instant::now(),
+            tls_info,
+        };
+        if self.config.max_idle_per_host == 0 {
+            return;
+        }
+        let replaced: Option<Http2Connection> = match self.state.lock() {
+            Ok(mut state) => state.http2.insert(key, connection),
+            Err(_) => return,
+        };
+        drop(replaced);
+    }
+
+    /// Forgets the HTTP/2 connection for `key` after it has been closed.
+    pub(crate) fn remove_http2(&self, key: &PoolKey) {
+        let removed: Option<Http2Connection> = match self.state.lock() {
+            Ok(mut state) => state.http2.remove(key),
+            Err(_) => return,
+        };
+        drop(removed);
+    }
+
+    /// Returns the number of idle connections currently kept alive.
+    ///
+    /// Shared HTTP/2 connections are counted once each.
+    pub(crate) fn get_idle_count(&self) -> usize {
+        self.state.lock().map_or(0, |state| {
+            state.idle.values().map(VecDeque::len).sum::<usize>()
+                + state.idle_async.values().map(VecDeque::len).sum::<usize>()
+                + state.http2.len()
+        })
+    }
+
+    /// Closes every idle connection.
+    pub(crate) fn clear(&self) {
+        let (idle, idle_async, http2) = match self.state.lock() {
+            Ok(mut state) => (
+                std::mem::take(&mut state.idle),
+                std::mem::take(&mut state.idle_async),
+                std::mem::take(&mut state.http2),
+            ),
+            Err(_) => return,
+        };
+        drop(idle);
+        drop(idle_async);
+        drop(http2);
+    }
+}
+
+/// Shuts the HTTP/2 runtime down without blocking, so a client may be dropped inside async code.
+impl Drop for ConnectionPool {
+    fn drop(&mut self) {
+        if let Ok(runtime) = self.runtime.get_mut()
+            && let Some(runtime) = runtime.take()
+        {
+            runtime.shutdown_background();
+        }
+    }
+}
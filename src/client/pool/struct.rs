@@ -0,0 +1,75 @@
+use crate::*;
+
+/// Limits applied to the connections kept by a `Client`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PoolConfig {
+    pub(crate) max_idle_per_host: usize,
+    pub(crate) idle_timeout: u64,
+    pub(crate) max_connections: usize,
+}
+
+/// Identifies the connections that may be shared between requests.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct PoolKey {
+    pub(crate) https: bool,
+    pub(crate) host: String,
+    pub(crate) port: u16,
+    pub(crate) proxy: Option<ProxyConfig>,
+    /// Fingerprint of the TLS trust and client certificate settings.
+    pub(crate) tls: u64,
+    /// Fingerprint of the TLS settings of the connection to an HTTPS proxy, or `0` without one.
+    pub(crate) proxy_tls: u64,
+}
+
+/// Counts one open connection against `PoolConfig::max_connections` until it is dropped.
+pub(crate) struct ConnectionPermit {
+    pub(crate) pool: Weak<ConnectionPool>,
+}
+
+/// A keep-alive connection waiting in the pool for its next request.
+pub(crate) struct IdleConnection<S> {
+    pub(crate) stream: S,
+    pub(crate) permit: ConnectionPermit,
+    pub(crate) idle_since: Instant,
+    pub(crate) tls_info: Option<TlsInfo>,
+}
+
+/// A multiplexed HTTP/2 connection shared by concurrent requests.
+pub(crate) struct Http2Connection {
+    pub(crate) send_request: SendRequest<Bytes>,
+    /// Held so that the shared connection counts towards `max_connections`.
+    pub(crate) _permit: ConnectionPermit,
+    pub(crate) idle_since: Instant,
+    pub(crate) tls_info: Option<TlsInfo>,
+}
+
+pub(crate) struct PoolState {
+    pub(crate) idle: IdleConnections<BoxReadWrite>,
+    pub(crate) idle_async: IdleConnections<BoxAsyncReadWrite>,
+    pub(crate) http2: HashMap<PoolKey, Http2Connection>,
+    pub(crate) http2_connecting: HashMap<PoolKey, Arc<AsyncMutex<()>>>,
+    pub(crate) total: usize,
+}
+
+/// Idle HTTP/1.1 connections shared by every request sent through a `Client`.
+pub(crate) struct ConnectionPool {
+    pub(crate) config: PoolConfig,
+    pub(crate) state: Mutex<PoolState>,
+    pub(crate) available: Condvar,
+    pub(crate) available_async: Notify,
+    pub(crate) runtime: Mutex<Option<Runtime>>,
+}
+
+/// A connection used for a single request and response exchange.
+///
+/// Connections opened without a `Client` carry no key or permit and are closed once dropped.
+pub(crate) struct PooledConnection<S> {
+    pub(crate) stream: S,
+    pub(crate) key: Option<PoolKey>,
+    pub(crate) permit: Option<ConnectionPermit>,
+    pub(crate) reused: bool,
+    pub(crate) written: bool,
+    pub(crate) received: bool,
+    pub(crate) keep_alive: bool,
+    pub(crate) tls_info: Option<TlsInfo>,
+}
@@ -0,0 +1,3 @@
+use crate::*;
+
+pub(crate) type IdleConnections<S> = HashMap<PoolKey, VecDeque<IdleConnection<S>>>;
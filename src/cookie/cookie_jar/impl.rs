@@ -0,0 +1,175 @@
+use crate::*;
+
+impl CookieJar {
+    /// Creates an empty cookie jar.
+    ///
+    /// # Returns
+    /// Returns a new instance of `CookieJar`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores the cookie of a `Set-Cookie` header value as if it had been received from `url`.
+    ///
+    /// # Parameters
+    /// - `set_cookie`: The `Set-Cookie` header value, such as `session=1; Path=/; HttpOnly`.
+    /// - `url`: The URL the cookie was received from.
+    pub fn add_cookie_str(&self, set_cookie: &str, url: &str) {
+        if let Ok(url_obj) = HttpUrlComponents::parse(url) {
+            self.store(&url_obj, [set_cookie]);
+        }
+    }
+
+    /// Builds the `Cookie` header value that would be sent with a request to `url`.
+    ///
+    /// # Parameters
+    /// - `url`: The request URL.
+    ///
+    /// # Returns
+    /// Returns the matching cookies as `name=value` pairs joined by `; `, or `None` if no cookie matches.
+    pub fn get_cookie_header(&self, url: &str) -> Option<String> {
+        let url_obj: HttpUrlComponents = HttpUrlComponents::parse(url).ok()?;
+        self.get_request_cookie(&url_obj)
+    }
+
+    /// Retrieves every cookie that has not expired.
+    ///
+    /// # Returns
+    /// - `Vec<StoredCookie>`: The stored cookies in the order they were first set.
+    pub fn get_cookies(&self) -> Vec<StoredCookie> {
+        let now: u64 = get_unix_time();
+        self.cookies.read().map_or(Vec::new(), |cookies| {
+            cookies
+                .iter()
+                .filter(|cookie: &&StoredCookie| !cookie.is_expired(now))
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Returns the number of cookies that have not expired.
+    pub fn len(&self) -> usize {
+        self.get_cookies().len()
+    }
+
+    /// Returns whether the jar holds no cookie that has not expired.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every cookie.
+    pub fn clear(&self) {
+        if let Ok(mut cookies) = self.cookies.write() {
+            cookies.clear();
+        }
+    }
+
+    /// Writes every cookie that has not expired, including session cookies, as JSON.
+    ///
+    /// # Parameters
+    /// - `writer`: The destination, such as a file.
+    ///
+    /// # Returns
+    /// Returns `Err` if writing fails.
+    pub fn save<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let cookies: Vec<JsonValue> = self
+            .get_cookies()
+            .iter()
+            .map(StoredCookie::to_json)
+            .collect();
+        writer.write_all(
+            json_to_string(&cookies)
+                .map_err(std::io::Error::other)?
+                .as_bytes(),
+        )?;
+        writer.flush()
+    }
+
+    /// Reads cookies written by `save` and adds them to the jar.
+    ///
+    /// Loaded cookies replace stored cookies with the same name, domain and path, and cookies
+    /// that have expired since they were saved are skipped.
+    ///
+    /// # Parameters
+    /// - `reader`: The source, such as a file.
+    ///
+    /// # Returns
+    /// Returns `Err` if reading fails or the content is not a saved cookie jar.
+    pub fn load<R: Read>(&self, reader: &mut R) -> std::io::Result<()> {
+        let mut content: String = String::new();
+        reader.read_to_string(&mut content)?;
+        let values: Vec<JsonValue> = json_from_str(&content)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+        let now: u64 = get_unix_time();
+        for value in &values {
+            let cookie: StoredCookie = StoredCookie::from_json(value).ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid saved cookie")
+            })?;
+            self.insert(cookie, now);
+        }
+        Ok(())
+    }
+
+    /// Stores a cookie, replacing the cookie with the same name, domain and path.
+    ///
+    /// An expired cookie only removes the cookie it replaces.
+    fn insert(&self, cookie: StoredCookie, now: u64) {
+        if let Ok(mut cookies) = self.cookies.write() {
+            cookies.retain(|stored: &StoredCookie| !stored.is_expired(now));
+            let position: Option<usize> = cookies
+                .iter()
+                .position(|stored: &StoredCookie| stored.is_same(&cookie));
+            match (position, cookie.is_expired(now)) {
+                (Some(index), true) => {
+                    cookies.remove(index);
+                }
+                (Some(index), false) => cookies[index] = cookie,
+                (None, false) => cookies.push(cookie),
+                (None, true) => {}
+            }
+        }
+    }
+
+    /// Stores the cookies of the `Set-Cookie` header values of a response.
+    ///
+    /// # Parameters
+    /// - `url_obj`: The URL of the request the response answered.
+    /// - `set_cookies`: The `Set-Cookie` header values.
+    pub(crate) fn store<I, S>(&self, url_obj: &HttpUrlComponents, set_cookies: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let now: u64 = get_unix_time();
+        for set_cookie in set_cookies {
+            if let Some(cookie) = StoredCookie::parse(set_cookie.as_ref(), url_obj, now) {
+                self.insert(cookie, now);
+            }
+        }
+    }
+
+    /// Builds the `Cookie` header value for a request to the given URL.
+    ///
+    /// Cookies with longer paths are listed first, as recommended by RFC 6265, Section 5.4.
+    pub(crate) fn get_request_cookie(&self, url_obj: &HttpUrlComponents) -> Option<String> {
+        let now: u64 = get_unix_time();
+        let mut cookies: Vec<StoredCookie> = self.cookies.read().ok().map(|cookies| {
+            cookies
+                .iter()
+                .filter(|cookie: &&StoredCookie| cookie.matches(url_obj, now))
+                .cloned()
+                .collect()
+        })?;
+        if cookies.is_empty() {
+            return None;
+        }
+        cookies.sort_by_key(|cookie: &StoredCookie| Reverse(cookie.path.len()));
+        Some(
+            cookies
+                .iter()
+                .map(|cookie: &StoredCookie| format!("{}={}", cookie.name, cookie.value))
+                .collect::<Vec<String>>()
+                .join("; "),
+        )
+    }
+}
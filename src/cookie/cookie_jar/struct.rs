@@ -0,0 +1,12 @@
+use crate::*;
+
+/// A cookie store shared by every request it is attached to.
+///
+/// Cookies set by responses, including intermediate redirect responses, are stored and sent
+/// back with later requests whose URL matches their domain, path and `Secure` attribute.
+/// Cloning a `CookieJar` is cheap and every clone shares the same cookies. Attach it with
+/// `RequestBuilder::cookie_jar` or `ClientBuilder::cookie_jar`.
+#[derive(Debug, Clone, Default)]
+pub struct CookieJar {
+    pub(crate) cookies: ArcRwLock<Vec<StoredCookie>>,
+}
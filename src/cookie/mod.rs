@@ -0,0 +1,5 @@
+pub(crate) mod cookie_jar;
+pub(crate) mod stored_cookie;
+
+pub use cookie_jar::*;
+pub use stored_cookie::*;
@@ -0,0 +1,222 @@
+use crate::*;
+
+impl StoredCookie {
+    /// Retrieves the cookie name.
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Retrieves the cookie value.
+    pub fn get_value(&self) -> String {
+        self.value.clone()
+    }
+
+    /// Retrieves the domain the cookie belongs to.
+    pub fn get_domain(&self) -> String {
+        self.domain.clone()
+    }
+
+    /// Returns whether the cookie is only sent to its exact domain.
+    pub fn is_host_only(&self) -> bool {
+        self.host_only
+    }
+
+    /// Retrieves the path prefix the cookie is sent for.
+    pub fn get_path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// Retrieves the expiry time in seconds since the Unix epoch, or `None` for a session cookie.
+    pub fn get_expires(&self) -> Option<u64> {
+        self.expires
+    }
+
+    /// Returns whether the cookie is only sent over HTTPS.
+    pub fn is_secure(&self) -> bool {
+        self.secure
+    }
+
+    /// Returns whether the cookie was marked `HttpOnly`.
+    pub fn is_http_only(&self) -> bool {
+        self.http_only
+    }
+
+    /// Retrieves the `SameSite` attribute, if any.
+    pub fn get_same_site(&self) -> Option<String> {
+        self.same_site.clone()
+    }
+
+    /// Parses a `Set-Cookie` header value received for the given request URL.
+    ///
+    /// Follows RFC 6265, Section 5.2 and 5.3. Cookies whose `Domain` does not cover the request
+    /// host, and `Secure` cookies received over plain HTTP, are rejected.
+    ///
+    /// # Parameters
+    /// - `set_cookie`: The `Set-Cookie` header value.
+    /// - `url_obj`: The URL of the request the response answered.
+    /// - `now`: The current time in seconds since the Unix epoch.
+    ///
+    /// # Returns
+    /// Returns the cookie to store, or `None` if it must be ignored.
+    pub(crate) fn parse(set_cookie: &str, url_obj: &HttpUrlComponents, now: u64) -> Option<Self> {
+        let host: String = url_obj.host.clone().unwrap_or_default().to_lowercase();
+        let https: bool = url_obj.protocol.is_https();
+        let mut parts = set_cookie.split(';');
+        let (name, value) = parts.next()?.split_once('=')?;
+        let name: &str = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+        let mut cookie: Self = Self {
+            name: name.to_string(),
+            value: value.trim().to_string(),
+            domain: host.clone(),
+            host_only: true,
+            path: Self::get_default_path(url_obj.path.as_deref().unwrap_or_default()),
+            expires: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+        };
+        let mut max_age: Option<i64> = None;
+        for attribute in parts {
+            let (key, value) = attribute.split_once('=').unwrap_or((attribute, ""));
+            let value: &str = value.trim();
+            match key.trim().to_ascii_lowercase().as_str() {
+                "expires" => {
+                    cookie.expires = parse_http_date(value).or(cookie.expires);
+                }
+                "max-age" => {
+                    max_age = value.parse::<i64>().ok().or(max_age);
+                }
+                "domain" => {
+                    let domain: String = value.trim_start_matches('.').to_lowercase();
+                    if !domain.is_empty() {
+                        if !Self::domain_matches(&host, &domain) {
+                            return None;
+                        }
+                        cookie.host_only = host == domain;
+                        cookie.domain = domain;
+                    }
+                }
+                "path" if value.starts_with('/') => cookie.path = value.to_string(),
+                "secure" => cookie.secure = true,
+                "httponly" => cookie.http_only = true,
+                "samesite" => cookie.same_site = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        if let Some(seconds) = max_age {
+            cookie.expires = Some(if seconds <= 0 {
+                0
+            } else {
+                now.saturating_add(seconds as u64)
+            });
+        }
+        if cookie.secure && !https {
+            return None;
+        }
+        Some(cookie)
+    }
+
+    /// Returns the default cookie path for a request path, as described in RFC 6265, Section 5.1.4.
+    fn get_default_path(request_path: &str) -> String {
+        if !request_path.starts_with('/') {
+            return DEFAULT_HTTP_PATH.to_string();
+        }
+        match request_path.rfind('/') {
+            Some(0) | None => DEFAULT_HTTP_PATH.to_string(),
+            Some(pos) => request_path[..pos].to_string(),
+        }
+    }
+
+    /// Returns whether `host` is `domain` or one of its subdomains.
+    ///
+    /// IP addresses only match themselves.
+    fn domain_matches(host: &str, domain: &str) -> bool {
+        if host == domain {
+            return true;
+        }
+        host.parse::<IpAddr>().is_err()
+            && !host.starts_with('[')
+            && host.len() > domain.len()
+            && host.ends_with(domain)
+            && host.as_bytes()[host.len() - domain.len() - 1] == b'.'
+    }
+
+    /// Returns whether `request_path` falls under `cookie_path`.
+    fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+        if request_path == cookie_path {
+            return true;
+        }
+        request_path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/')
+                || request_path.as_bytes().get(cookie_path.len()) == Some(&b'/'))
+    }
+
+    /// Returns whether the cookie has expired.
+    pub(crate) fn is_expired(&self, now: u64) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    /// Returns whether the cookie replaces `other` when stored.
+    pub(crate) fn is_same(&self, other: &Self) -> bool {
+        self.name == other.name && self.domain == other.domain && self.path == other.path
+    }
+
+    /// Returns whether the cookie must be sent with a request to the given URL.
+    pub(crate) fn matches(&self, url_obj: &HttpUrlComponents, now: u64) -> bool {
+        let host: String = url_obj.host.clone().unwrap_or_default().to_lowercase();
+        let request_path: &str = url_obj
+            .path
+            .as_deref()
+            .filter(|path: &&str| !path.is_empty())
+            .unwrap_or(DEFAULT_HTTP_PATH);
+        let domain_matches: bool = if self.host_only {
+            host == self.domain
+        } else {
+            Self::domain_matches(&host, &self.domain)
+        };
+        domain_matches
+            && Self::path_matches(request_path, &self.path)
+            && (!self.secure || url_obj.protocol.is_https())
+            && !self.is_expired(now)
+    }
+
+    /// Converts the cookie into the JSON object used by `CookieJar::save`.
+    pub(crate) fn to_json(&self) -> JsonValue {
+        json_value!({
+            "name": self.name,
+            "value": self.value,
+            "domain": self.domain,
+            "host_only": self.host_only,
+            "path": self.path,
+            "expires": self.expires,
+            "secure": self.secure,
+            "http_only": self.http_only,
+            "same_site": self.same_site,
+        })
+    }
+
+    /// Reads a cookie from the JSON object written by `CookieJar::save`.
+    pub(crate) fn from_json(value: &JsonValue) -> Option<Self> {
+        let get_str = |key: &str| {
+            value
+                .get(key)
+                .and_then(JsonValue::as_str)
+                .map(str::to_string)
+        };
+        let get_bool = |key: &str| value.get(key).and_then(JsonValue::as_bool).unwrap_or(false);
+        Some(Self {
+            name: get_str("name")?,
+            value: get_str("value")?,
+            domain: get_str("domain")?,
+            host_only: get_bool("host_only"),
+            path: get_str("path").unwrap_or(DEFAULT_HTTP_PATH.to_string()),
+            expires: value.get("expires").and_then(JsonValue::as_u64),
+            secure: get_bool("secure"),
+            http_only: get_bool("http_only"),
+            same_site: get_str("same_site"),
+        })
+    }
+}
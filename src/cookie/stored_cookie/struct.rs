@@ -0,0 +1,24 @@
+/// A cookie kept by a `CookieJar`, together with the attributes that decide where it is sent.
+///
+/// # Fields
+/// - `name`: The cookie name.
+/// - `value`: The cookie value.
+/// - `domain`: The lowercase domain the cookie belongs to, without a leading dot.
+/// - `host_only`: Whether the cookie is only sent to `domain` itself and not to its subdomains.
+/// - `path`: The path prefix the cookie is sent for.
+/// - `expires`: When the cookie expires, in seconds since the Unix epoch; `None` for session cookies.
+/// - `secure`: Whether the cookie is only sent over HTTPS.
+/// - `http_only`: Whether the cookie was marked `HttpOnly`.
+/// - `same_site`: The `SameSite` attribute, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredCookie {
+    pub(crate) name: String,
+    pub(crate) value: String,
+    pub(crate) domain: String,
+    pub(crate) host_only: bool,
+    pub(crate) path: String,
+    pub(crate) expires: Option<u64>,
+    pub(crate) secure: bool,
+    pub(crate) http_only: bool,
+    pub(crate) same_site: Option<String>,
+}
@@ -1,14 +1,20 @@
 pub(crate) mod body;
 pub(crate) mod cfg;
+pub(crate) mod client;
 pub(crate) mod common;
 pub(crate) mod r#const;
+pub(crate) mod cookie;
 pub(crate) mod request;
 pub(crate) mod response;
+pub(crate) mod tls;
 pub(crate) mod utils;
 pub(crate) mod websocket;
 
+pub use client::*;
+pub use cookie::*;
 pub use request::*;
 pub use response::*;
+pub use tls::{TlsInfo, is_certificate_pin_mismatch};
 pub use websocket::*;
 
 pub use http_type::{
@@ -22,53 +28,98 @@ pub use http_type::{
 pub(crate) use body::*;
 pub(crate) use common::*;
 pub(crate) use r#const::*;
+pub(crate) use tls::*;
 pub(crate) use utils::*;
 
+pub(crate) use brotli::DecompressorWriter;
+pub(crate) use bytes::Bytes;
+pub(crate) use flate2::write::{DeflateDecoder, GzDecoder};
 pub(crate) use futures::{Future, Sink, SinkExt, Stream, StreamExt};
+pub(crate) use h2::{
+    RecvStream, SendStream,
+    client::{ResponseFuture, SendRequest},
+};
+pub(crate) use http::{HeaderMap, Response, Version, response::Parts as ResponseParts};
 pub(crate) use http_type::{
-    ACCEPT, ACCEPT_ANY, BR_BYTES, CONNECTION, CONTENT_LENGTH, CONTENT_TYPE, Compress, ContentType,
-    DEFAULT_BUFFER_SIZE, DEFAULT_HTTP_PATH, DEFAULT_MAX_REDIRECT_TIMES, DEFAULT_TIMEOUT, EMPTY_STR,
-    HOST, HTTP_BR_BYTES, HttpStatus, HttpUrlComponents, HttpVersion, LOCATION, Method, Protocol,
-    QUERY_SYMBOL, RequestBody, RequestBodyString, RequestError, RequestHeaders, ResponseHeaders,
-    ResponseStatusCode, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_VERSION, SPACE_U8, TAB_U8, UPGRADE,
-    USER_AGENT,
+    ACCEPT, ACCEPT_ANY, AUTHORIZATION, BR_BYTES, CONNECTION, CONTENT_ENCODING, CONTENT_LENGTH,
+    CONTENT_TYPE, COOKIE, Compress, ContentType, DEFAULT_BUFFER_SIZE, DEFAULT_HTTP_PATH,
+    DEFAULT_MAX_REDIRECT_TIMES, DEFAULT_TIMEOUT, EMPTY_STR, FORM_URLENCODED, HOST, HTTP_BR_BYTES,
+    HTTP_DOUBLE_BR_BYTES, HttpStatus, HttpUrlComponents, HttpVersion, LOCATION, Method,
+    PROXY_AUTHENTICATE, PROXY_AUTHORIZATION, Protocol, QUERY_SYMBOL, RETRY_AFTER, RequestBody,
+    RequestBodyString, RequestError, RequestHeaders, ResponseHeaders, ResponseStatusCode,
+    SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_VERSION, SET_COOKIE, SPACE_U8, TAB_U8, TRANSFER_ENCODING,
+    UPGRADE, USER_AGENT, WWW_AUTHENTICATE,
 };
 pub(crate) use rustls::{
-    ClientConfig, ClientConnection, RootCertStore, StreamOwned, pki_types::ServerName,
+    CertificateError, ClientConfig, ClientConnection, CommonState, ConfigBuilder,
+    DigitallySignedStruct, Error as TlsError, RootCertStore, SignatureScheme, StreamOwned,
+    client::{
+        WantsClientCert, WebPkiServerVerifier,
+        danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    },
+    crypto::{CryptoProvider, verify_tls12_signature, verify_tls13_signature},
+    pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime, pem::PemObject},
 };
 pub(crate) use serde::{Serialize, Serializer};
 pub(crate) use std::{
     borrow::Cow,
-    collections::HashSet,
+    cmp::Reverse,
+    collections::{
+        HashMap, HashSet, VecDeque,
+        hash_map::{DefaultHasher, RandomState},
+    },
     fmt::{self, Debug, Display, Formatter},
-    io::{Read, Write},
-    net::{Ipv4Addr, Ipv6Addr, TcpStream},
-    pin::Pin,
+    future::poll_fn,
+    hash::{BuildHasher, Hash, Hasher},
+    io::{ErrorKind, Read, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream, ToSocketAddrs},
+    panic::resume_unwind,
+    path::{Path, PathBuf},
+    pin::{Pin, pin},
     str::from_utf8,
     sync::{
-        Arc, RwLock,
+        Arc, Condvar, Mutex, MutexGuard, RwLock, Weak,
         atomic::{AtomicBool, Ordering},
     },
     task::{Context, Poll},
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    thread::{scope as thread_scope, sleep as thread_sleep},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
     vec::IntoIter,
 };
 pub(crate) use tokio::{
     io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
     net::TcpStream as AsyncTcpStream,
-    sync::{Mutex as AsyncMutex, MutexGuard as AsyncMutexGuard},
-    time::timeout,
+    runtime::{Builder as RuntimeBuilder, Handle as RuntimeHandle, Runtime},
+    sync::{Mutex as AsyncMutex, MutexGuard as AsyncMutexGuard, Notify, futures::Notified},
+    time::{Sleep, sleep, timeout},
 };
 pub(crate) use tokio_rustls::{TlsConnector, client::TlsStream};
 pub(crate) use tokio_tungstenite::{
     MaybeTlsStream, WebSocketStream, client_async_with_config, connect_async_with_config,
     tungstenite::Message, tungstenite::handshake::client::Request,
 };
+pub(crate) use webpki::EndEntityCert;
 pub(crate) use webpki_roots::TLS_SERVER_ROOTS;
 
+#[cfg(test)]
+use flate2::{Compression, write::GzEncoder};
+#[cfg(test)]
+use h2::server::SendResponse;
+#[cfg(test)]
+use http::Request as Http2Request;
+#[cfg(test)]
+use rcgen::{Certificate, CertifiedKey, generate_simple_self_signed};
+#[cfg(test)]
+use rustls::{ServerConfig, pki_types::PrivatePkcs8KeyDer, server::WebPkiClientVerifier};
 #[cfg(test)]
 use std::{
-    sync::{Mutex, MutexGuard},
+    net::TcpListener,
+    sync::atomic::AtomicUsize,
     thread::{JoinHandle, spawn},
-    time::Instant,
 };
+#[cfg(test)]
+use tokio::{io::copy_bidirectional, net::TcpListener as AsyncTcpListener, task::spawn_blocking};
+#[cfg(test)]
+use tokio_rustls::TlsAcceptor;
+#[cfg(test)]
+use tokio_tungstenite::accept_async;
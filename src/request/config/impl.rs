@@ -4,14 +4,34 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             timeout: DEFAULT_TIMEOUT,
+            connect_timeout: None,
+            read_timeout: None,
+            total_timeout: None,
             url_obj: HttpUrlComponents::default(),
             redirect: false,
             max_redirect_times: DEFAULT_MAX_REDIRECT_TIMES,
             redirect_times: 0,
+            redirect_policy: RedirectPolicy::default(),
+            retry_policy: None,
             http_version: HttpVersion::default(),
             buffer: DEFAULT_BUFFER_SIZE,
             decode: true,
             proxy: None,
+            authorization: None,
+            digest_auth: None,
+            proxy_digest_auth: None,
         }
     }
 }
+
+impl Debug for ProxyConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("proxy_type", &self.proxy_type)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .finish()
+    }
+}
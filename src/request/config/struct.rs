@@ -1,19 +1,27 @@
 use crate::*;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub(crate) struct Config {
     pub(crate) timeout: u64,
+    pub(crate) connect_timeout: Option<u64>,
+    pub(crate) read_timeout: Option<u64>,
+    pub(crate) total_timeout: Option<u64>,
     pub(crate) url_obj: HttpUrlComponents,
     pub(crate) redirect: bool,
     pub(crate) max_redirect_times: usize,
     pub(crate) redirect_times: usize,
+    pub(crate) redirect_policy: RedirectPolicy,
+    pub(crate) retry_policy: Option<RetryPolicy>,
     pub(crate) http_version: HttpVersion,
     pub(crate) buffer: usize,
     pub(crate) decode: bool,
     pub(crate) proxy: Option<ProxyConfig>,
+    pub(crate) authorization: Option<Secret>,
+    pub(crate) digest_auth: Option<DigestAuth>,
+    pub(crate) proxy_digest_auth: Option<DigestAuth>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub(crate) struct ProxyConfig {
     pub(crate) proxy_type: ProxyType,
     pub(crate) host: String,
@@ -22,7 +30,7 @@ pub(crate) struct ProxyConfig {
     pub(crate) password: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) enum ProxyType {
     Http,
     Https,
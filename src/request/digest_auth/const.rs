@@ -0,0 +1,5 @@
+/// The authentication scheme of a Digest challenge.
+pub(crate) const DIGEST_SCHEME: &str = "Digest";
+
+/// The only quality of protection supported, which authenticates the request without its body.
+pub(crate) const DIGEST_QOP_AUTH: &str = "auth";
@@ -0,0 +1,8 @@
+/// The hash algorithm a Digest challenge asks for (RFC 7616).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DigestAlgorithm {
+    Md5,
+    Md5Sess,
+    Sha256,
+    Sha256Sess,
+}
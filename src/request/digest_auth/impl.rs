@@ -0,0 +1,331 @@
+use crate::*;
+
+impl DigestAlgorithm {
+    /// Parses the `algorithm` parameter of a challenge, matching case-insensitively.
+    ///
+    /// # Returns
+    /// Returns the algorithm, or `None` if it is not supported.
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_uppercase().as_str() {
+            "MD5" => Some(Self::Md5),
+            "MD5-SESS" => Some(Self::Md5Sess),
+            "SHA-256" => Some(Self::Sha256),
+            "SHA-256-SESS" => Some(Self::Sha256Sess),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the algorithm as sent in the `Authorization` header.
+    pub(crate) fn get_name(&self) -> &'static str {
+        match self {
+            Self::Md5 => "MD5",
+            Self::Md5Sess => "MD5-sess",
+            Self::Sha256 => "SHA-256",
+            Self::Sha256Sess => "SHA-256-sess",
+        }
+    }
+
+    /// Returns whether the session variant is used, which hashes the nonces into `A1`.
+    pub(crate) fn is_session(&self) -> bool {
+        matches!(self, Self::Md5Sess | Self::Sha256Sess)
+    }
+
+    /// Returns how strong the algorithm is, to prefer SHA-256 when several challenges are offered.
+    pub(crate) fn get_strength(&self) -> u8 {
+        match self {
+            Self::Md5 | Self::Md5Sess => 0,
+            Self::Sha256 | Self::Sha256Sess => 1,
+        }
+    }
+
+    /// Hashes `data` and returns the digest as lowercase hexadecimal.
+    pub(crate) fn hash(&self, data: &str) -> String {
+        match self {
+            Self::Md5 | Self::Md5Sess => hex_encode(&md5(data.as_bytes())),
+            Self::Sha256 | Self::Sha256Sess => hex_encode(&sha256(data.as_bytes())),
+        }
+    }
+}
+
+impl DigestChallenge {
+    /// Parses every Digest challenge of a set of authenticate headers and returns the strongest
+    /// one that is supported.
+    ///
+    /// # Parameters
+    /// - `values`: The values of every `WWW-Authenticate` or `Proxy-Authenticate` header.
+    ///
+    /// # Returns
+    /// Returns the challenge to answer, or `None` if no supported Digest challenge was offered.
+    pub(crate) fn parse_all(values: &[String]) -> Option<Self> {
+        values
+            .iter()
+            .flat_map(|value| Self::split_challenges(value))
+            .filter(|(scheme, _)| scheme.eq_ignore_ascii_case(DIGEST_SCHEME))
+            .filter_map(|(_, params)| Self::from_params(&params))
+            .rev()
+            .max_by_key(|challenge| challenge.algorithm.get_strength())
+    }
+
+    /// Builds a challenge from its parameters.
+    ///
+    /// Returns `None` when the realm or nonce is missing, the algorithm is unknown, or a
+    /// `qop` is required that does not include `auth`.
+    fn from_params(params: &[(String, String)]) -> Option<Self> {
+        let get = |name: &str| -> Option<String> {
+            params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+        };
+        let algorithm: DigestAlgorithm = match get("algorithm") {
+            Some(algorithm) => DigestAlgorithm::parse(&algorithm)?,
+            None => DigestAlgorithm::Md5,
+        };
+        let qop: bool = match get("qop") {
+            Some(qop) => {
+                if !qop
+                    .split(',')
+                    .any(|value| value.trim().eq_ignore_ascii_case(DIGEST_QOP_AUTH))
+                {
+                    return None;
+                }
+                true
+            }
+            None => false,
+        };
+        Some(Self {
+            realm: get("realm")?,
+            nonce: get("nonce")?,
+            opaque: get("opaque"),
+            algorithm,
+            qop,
+            stale: get("stale").is_some_and(|stale| stale.eq_ignore_ascii_case("true")),
+        })
+    }
+
+    /// Splits an authenticate header into its challenges, each with its scheme and its
+    /// parameters, whose names are lowercased and whose quoted values are unescaped.
+    pub(crate) fn split_challenges(value: &str) -> Vec<(String, Vec<(String, String)>)> {
+        let mut challenges: Vec<(String, Vec<(String, String)>)> = Vec::new();
+        for item in Self::split_list(value) {
+            let item: &str = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+            let space: Option<usize> = item.find(char::is_whitespace);
+            let equals: Option<usize> = item.find('=');
+            let param: &str = match (space, equals) {
+                (Some(space), Some(equals)) if space < equals => {
+                    challenges.push((item[..space].to_string(), Vec::new()));
+                    item[space..].trim()
+                }
+                (Some(space), None) => {
+                    challenges.push((item[..space].to_string(), Vec::new()));
+                    continue;
+                }
+                (None, None) => {
+                    challenges.push((item.to_string(), Vec::new()));
+                    continue;
+                }
+                _ => item,
+            };
+            if let (Some((_, params)), Some((name, value))) =
+                (challenges.last_mut(), param.split_once('='))
+            {
+                params.push((
+                    name.trim().to_ascii_lowercase(),
+                    Self::unquote(value.trim()),
+                ));
+            }
+        }
+        challenges
+    }
+
+    /// Splits a header value on the commas that are outside quoted strings.
+    fn split_list(value: &str) -> Vec<String> {
+        let mut items: Vec<String> = Vec::new();
+        let mut current: String = String::new();
+        let mut quoted: bool = false;
+        let mut escaped: bool = false;
+        for character in value.chars() {
+            match character {
+                _ if escaped => escaped = false,
+                '\\' if quoted => escaped = true,
+                '"' => quoted = !quoted,
+                ',' if !quoted => {
+                    items.push(std::mem::take(&mut current));
+                    continue;
+                }
+                _ => {}
+            }
+            current.push(character);
+        }
+        items.push(current);
+        items
+    }
+
+    /// Removes the quotes and escapes of a quoted-string, returning tokens unchanged.
+    fn unquote(value: &str) -> String {
+        let Some(inner) = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+        else {
+            return value.to_string();
+        };
+        let mut result: String = String::with_capacity(inner.len());
+        let mut escaped: bool = false;
+        for character in inner.chars() {
+            if character == '\\' && !escaped {
+                escaped = true;
+                continue;
+            }
+            escaped = false;
+            result.push(character);
+        }
+        result
+    }
+}
+
+impl DigestAuth {
+    /// Creates Digest credentials that wait for a challenge before they are sent.
+    ///
+    /// # Parameters
+    /// - `username`: The user name.
+    /// - `password`: The password.
+    pub(crate) fn new(username: &str, password: &str) -> Self {
+        Self {
+            username: username.to_string(),
+            password: password.to_string(),
+            challenge: None,
+            origin: None,
+            nonce_count: 0,
+        }
+    }
+
+    /// Stores a new challenge, restarting the nonce count when the nonce changes.
+    ///
+    /// # Parameters
+    /// - `challenge`: The challenge to answer from now on.
+    /// - `origin`: The scheme, host and port the challenge was received from.
+    pub(crate) fn set_challenge(
+        &mut self,
+        challenge: DigestChallenge,
+        origin: (bool, String, u16),
+    ) {
+        if self
+            .challenge
+            .as_ref()
+            .is_none_or(|current| current.nonce != challenge.nonce)
+        {
+            self.nonce_count = 0;
+        }
+        self.challenge = Some(challenge);
+        self.origin = Some(origin);
+    }
+
+    /// Computes the credentials for one request, counting it against the current nonce.
+    ///
+    /// # Parameters
+    /// - `method`: The request method.
+    /// - `uri`: The request target, as sent in the request line.
+    ///
+    /// # Returns
+    /// Returns the value of the `Authorization` header, or `None` if no challenge has been
+    /// received yet.
+    pub(crate) fn get_authorization(&mut self, method: &str, uri: &str) -> Option<String> {
+        self.challenge.as_ref()?;
+        self.nonce_count = self.nonce_count.wrapping_add(1);
+        let challenge: &DigestChallenge = self.challenge.as_ref()?;
+        let algorithm: DigestAlgorithm = challenge.algorithm;
+        let nonce_count: String = format!("{:08x}", self.nonce_count);
+        let cnonce: String = Self::get_cnonce(self.nonce_count);
+        let response: String = self.get_response(challenge, method, uri, &nonce_count, &cnonce);
+        let mut authorization: String = format!(
+            "{} username={}, realm={}, nonce={}, uri={}, algorithm={}, response={}",
+            DIGEST_SCHEME,
+            Self::quote(&self.username),
+            Self::quote(&challenge.realm),
+            Self::quote(&challenge.nonce),
+            Self::quote(uri),
+            algorithm.get_name(),
+            Self::quote(&response)
+        );
+        if let Some(opaque) = &challenge.opaque {
+            authorization.push_str(&format!(", opaque={}", Self::quote(opaque)));
+        }
+        if challenge.qop {
+            authorization.push_str(&format!(
+                ", qop={}, nc={}, cnonce={}",
+                DIGEST_QOP_AUTH,
+                nonce_count,
+                Self::quote(&cnonce)
+            ));
+        } else if algorithm.is_session() {
+            authorization.push_str(&format!(", cnonce={}", Self::quote(&cnonce)));
+        }
+        Some(authorization)
+    }
+
+    /// Computes the `response` parameter proving knowledge of the password.
+    ///
+    /// # Parameters
+    /// - `challenge`: The challenge being answered.
+    /// - `method`: The request method.
+    /// - `uri`: The request target.
+    /// - `nonce_count`: The nonce count as eight hexadecimal digits.
+    /// - `cnonce`: The client nonce.
+    pub(crate) fn get_response(
+        &self,
+        challenge: &DigestChallenge,
+        method: &str,
+        uri: &str,
+        nonce_count: &str,
+        cnonce: &str,
+    ) -> String {
+        let algorithm: DigestAlgorithm = challenge.algorithm;
+        let mut ha1: String = algorithm.hash(&format!(
+            "{}:{}:{}",
+            self.username, challenge.realm, self.password
+        ));
+        if algorithm.is_session() {
+            ha1 = algorithm.hash(&format!("{}:{}:{}", ha1, challenge.nonce, cnonce));
+        }
+        let ha2: String = algorithm.hash(&format!("{}:{}", method, uri));
+        if challenge.qop {
+            algorithm.hash(&format!(
+                "{}:{}:{}:{}:{}:{}",
+                ha1, challenge.nonce, nonce_count, cnonce, DIGEST_QOP_AUTH, ha2
+            ))
+        } else {
+            algorithm.hash(&format!("{}:{}:{}", ha1, challenge.nonce, ha2))
+        }
+    }
+
+    /// Returns a random client nonce.
+    fn get_cnonce(nonce_count: u32) -> String {
+        let nanos: u128 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos());
+        let mut hasher: DefaultHasher = RandomState::new().build_hasher();
+        hasher.write_u128(nanos);
+        hasher.write_u32(nonce_count);
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// Returns `value` as a quoted-string.
+    fn quote(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+impl Debug for DigestAuth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DigestAuth")
+            .field("username", &self.username)
+            .field("password", &REDACTED)
+            .field("challenge", &self.challenge)
+            .field("origin", &self.origin)
+            .field("nonce_count", &self.nonce_count)
+            .finish()
+    }
+}
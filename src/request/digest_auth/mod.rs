@@ -0,0 +1,8 @@
+pub(crate) mod r#const;
+pub(crate) mod r#enum;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+
+pub(crate) use r#const::*;
+pub(crate) use r#enum::*;
+pub(crate) use r#struct::*;
@@ -0,0 +1,37 @@
+use crate::*;
+
+/// A Digest challenge received in a `WWW-Authenticate` or `Proxy-Authenticate` header.
+///
+/// # Fields
+/// - `realm`: The protection space the credentials apply to.
+/// - `nonce`: The server nonce to compute responses with.
+/// - `opaque`: A value the server wants returned unchanged, if any.
+/// - `algorithm`: The hash algorithm to use.
+/// - `qop`: Whether the server offered `qop=auth`; legacy RFC 2069 responses are used otherwise.
+/// - `stale`: Whether the challenge only replaces an expired nonce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DigestChallenge {
+    pub(crate) realm: String,
+    pub(crate) nonce: String,
+    pub(crate) opaque: Option<String>,
+    pub(crate) algorithm: DigestAlgorithm,
+    pub(crate) qop: bool,
+    pub(crate) stale: bool,
+}
+
+/// Digest credentials together with the challenge they answer.
+///
+/// # Fields
+/// - `username`: The user name.
+/// - `password`: The password.
+/// - `challenge`: The last challenge received, reused for later requests to the same origin.
+/// - `origin`: The scheme, host and port the challenge was received from.
+/// - `nonce_count`: How many requests have been authenticated with the current nonce.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct DigestAuth {
+    pub(crate) username: String,
+    pub(crate) password: String,
+    pub(crate) challenge: Option<DigestChallenge>,
+    pub(crate) origin: Option<(bool, String, u16)>,
+    pub(crate) nonce_count: u32,
+}
@@ -0,0 +1,7 @@
+use crate::*;
+
+impl Debug for dyn Middleware {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Middleware")
+    }
+}
@@ -0,0 +1,7 @@
+pub(crate) mod r#impl;
+pub(crate) mod r#trait;
+pub(crate) mod r#type;
+
+pub use r#trait::*;
+
+pub(crate) use r#type::*;
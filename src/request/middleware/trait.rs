@@ -0,0 +1,64 @@
+use crate::*;
+
+/// Cross-cutting behaviour run around every `send` and `send_stream` of a request, on both the
+/// sync and the async path.
+///
+/// Middleware registered on a client runs before middleware registered on the request, and
+/// each kind runs in the order it was added. `on_request` is called on the way out; the
+/// response or error then travels back through `on_response` or `on_error` in reverse order,
+/// so a middleware can wrap everything that runs after it. Retries and redirects happen
+/// inside the chain.
+///
+/// For `send_stream`, `on_response` runs once the response head is received, before the body
+/// is read, and a response returned by `on_request` or `on_error` is streamed from memory.
+pub trait Middleware: Send + Sync {
+    /// Inspects or modifies the request before it is sent.
+    ///
+    /// # Parameters
+    /// - `request`: The outgoing request. Changes apply to this send only.
+    ///
+    /// # Returns
+    /// - `Ok(None)`: Continues with the next middleware and eventually sends the request.
+    /// - `Ok(Some(response))`: Skips the rest of the chain and the network, returning a
+    ///   synthetic response to the middleware that already ran.
+    /// - `Err(RequestError)`: Fails the request without sending it.
+    fn on_request(
+        &self,
+        _request: &mut HttpRequest,
+    ) -> Result<Option<HttpResponseBinary>, RequestError> {
+        Ok(None)
+    }
+
+    /// Inspects or modifies the response on its way back to the caller.
+    ///
+    /// # Parameters
+    /// - `request`: The request as it was sent.
+    /// - `response`: The response returned by the rest of the chain.
+    ///
+    /// # Returns
+    /// - `Err(RequestError)`: Turns the response into an error, passed on to `on_error` of
+    ///   the middleware that ran before this one.
+    fn on_response(
+        &self,
+        _request: &HttpRequest,
+        _response: &mut HttpResponseBinary,
+    ) -> Result<(), RequestError> {
+        Ok(())
+    }
+
+    /// Handles an error on its way back to the caller.
+    ///
+    /// # Parameters
+    /// - `request`: The request as it was sent.
+    /// - `error`: The error returned by the rest of the chain.
+    ///
+    /// # Returns
+    /// Returns the error, possibly replaced, or a response to recover with.
+    fn on_error(
+        &self,
+        _request: &HttpRequest,
+        error: RequestError,
+    ) -> Result<HttpResponseBinary, RequestError> {
+        Err(error)
+    }
+}
@@ -0,0 +1,4 @@
+use crate::*;
+
+/// A shared middleware registered on a request or client.
+pub(crate) type ArcMiddleware = Arc<dyn Middleware>;
@@ -1,14 +1,38 @@
 pub(crate) mod config;
+pub(crate) mod digest_auth;
+pub(crate) mod middleware;
+pub(crate) mod multipart_form;
+pub(crate) mod multipart_part;
 pub(crate) mod proxy;
+pub(crate) mod redirect_policy;
 pub(crate) mod request;
 pub(crate) mod request_builder;
+pub(crate) mod request_timeouts;
+pub(crate) mod retry_attempt;
+pub(crate) mod retry_policy;
 pub(crate) mod shared;
 pub(crate) mod tmp;
 
+pub use middleware::*;
+pub use multipart_form::*;
+pub use multipart_part::*;
+pub use redirect_policy::*;
 pub use request::*;
+pub use request_timeouts::{RequestTimeoutKind, get_timeout_kind, is_timeout};
+pub use retry_attempt::*;
+pub use retry_policy::*;
 
 pub(crate) use config::*;
+pub(crate) use digest_auth::*;
+pub(crate) use middleware::ArcMiddleware;
+pub(crate) use multipart_form::MULTIPART_FORM_DATA;
+pub(crate) use multipart_part::MultipartData;
 pub(crate) use proxy::*;
+pub(crate) use request_timeouts::*;
+pub(crate) use retry_policy::{
+    DEFAULT_RETRY_INITIAL_BACKOFF, DEFAULT_RETRY_MAX_ATTEMPTS, DEFAULT_RETRY_MAX_BACKOFF,
+    DEFAULT_RETRY_STATUSES, RetryHook,
+};
 pub(crate) use shared::*;
 pub(crate) use tmp::*;
 
@@ -0,0 +1,3 @@
+pub(crate) const MULTIPART_FORM_DATA: &str = "multipart/form-data";
+pub(crate) const MULTIPART_BOUNDARY_PREFIX: &str = "http-request-boundary-";
+pub(crate) const MULTIPART_DEFAULT_FILE_CONTENT_TYPE: &str = "application/octet-stream";
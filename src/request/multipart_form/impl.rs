@@ -0,0 +1,178 @@
+use crate::*;
+
+impl Default for MultipartForm {
+    fn default() -> Self {
+        let random_state: RandomState = RandomState::new();
+        let nanos: u128 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos());
+        let mut hasher: DefaultHasher = random_state.build_hasher();
+        hasher.write_u128(nanos);
+        let high: u64 = hasher.finish();
+        hasher.write_u64(high);
+        Self {
+            boundary: format!(
+                "{}{:016x}{:016x}",
+                MULTIPART_BOUNDARY_PREFIX,
+                high,
+                hasher.finish()
+            ),
+            parts: Vec::new(),
+        }
+    }
+}
+
+impl MultipartForm {
+    /// Creates an empty form with a newly generated boundary.
+    ///
+    /// # Returns
+    /// Returns the new form.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a text field to the form.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the field.
+    /// - `value`: The text value of the field.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the form to allow method chaining.
+    pub fn text<N: ToString, V: ToString>(&mut self, name: N, value: V) -> &mut Self {
+        self.parts
+            .push((name.to_string(), MultipartPart::text(value)));
+        self
+    }
+
+    /// Adds a part, such as a file, to the form.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the field.
+    /// - `part`: The part to add.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the form to allow method chaining.
+    pub fn part<N: ToString>(&mut self, name: N, part: &MultipartPart) -> &mut Self {
+        self.parts.push((name.to_string(), part.clone()));
+        self
+    }
+
+    /// Retrieves the boundary delimiting the parts of the form.
+    ///
+    /// # Returns
+    /// - `String`: The boundary.
+    pub fn get_boundary(&self) -> String {
+        self.boundary.clone()
+    }
+
+    /// Retrieves the `Content-Type` header value of the form, including its boundary.
+    ///
+    /// # Returns
+    /// - `String`: The content type, such as `multipart/form-data; boundary=...`.
+    pub fn get_content_type(&self) -> String {
+        format!("{}; boundary={}", MULTIPART_FORM_DATA, self.boundary)
+    }
+
+    /// Escapes a name or file name for a quoted `Content-Disposition` parameter.
+    fn escape_quoted(value: &str) -> String {
+        value
+            .replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    }
+
+    /// Builds the delimiter and headers sent before the content of a part.
+    fn get_part_head(&self, index: usize, name: &str, part: &MultipartPart) -> Vec<u8> {
+        let mut head: String = String::new();
+        if index > 0 {
+            head.push_str("\r\n");
+        }
+        head.push_str(&format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"",
+            self.boundary,
+            Self::escape_quoted(name)
+        ));
+        if let Some(file_name) = &part.file_name {
+            head.push_str(&format!(
+                "; filename=\"{}\"",
+                Self::escape_quoted(file_name)
+            ));
+        }
+        head.push_str("\r\n");
+        let content_type: Option<&str> = part.content_type.as_deref().or(part
+            .file_name
+            .as_ref()
+            .map(|_| MULTIPART_DEFAULT_FILE_CONTENT_TYPE));
+        if let Some(content_type) = content_type {
+            head.push_str(&format!("Content-Type: {}\r\n", content_type));
+        }
+        head.push_str("\r\n");
+        head.into_bytes()
+    }
+
+    /// Builds the delimiter closing the form.
+    fn get_tail(&self) -> Vec<u8> {
+        let separator: &str = if self.parts.is_empty() { "" } else { "\r\n" };
+        format!("{}--{}--\r\n", separator, self.boundary).into_bytes()
+    }
+
+    /// Returns the length of the encoded form, if the length of every part is known.
+    pub(crate) fn get_length(&self) -> Option<u64> {
+        let mut length: u64 = self.get_tail().len() as u64;
+        for (index, (name, part)) in self.parts.iter().enumerate() {
+            length += self.get_part_head(index, name, part).len() as u64 + part.get_length()?;
+        }
+        Some(length)
+    }
+
+    /// Returns whether the form can be sent again, which is the case without reader parts.
+    pub(crate) fn is_replayable(&self) -> bool {
+        self.parts.iter().all(|(_, part)| match &part.data {
+            MultipartData::Bytes(_) => true,
+            MultipartData::Stream(body_stream) => body_stream.is_replayable(),
+        })
+    }
+
+    /// Opens the encoded form for a blocking request.
+    pub(crate) fn open(&self) -> Result<BodySource, RequestError> {
+        let mut sources: VecDeque<BodySource> = VecDeque::new();
+        for (index, (name, part)) in self.parts.iter().enumerate() {
+            let head: Vec<u8> = self.get_part_head(index, name, part);
+            sources.push_back(BodySource::Reader(Box::new(std::io::Cursor::new(head))));
+            sources.push_back(match &part.data {
+                MultipartData::Bytes(bytes) => {
+                    BodySource::Reader(Box::new(std::io::Cursor::new(bytes.clone())))
+                }
+                MultipartData::Stream(body_stream) => body_stream.open()?.source,
+            });
+        }
+        sources.push_back(BodySource::Reader(Box::new(std::io::Cursor::new(
+            self.get_tail(),
+        ))));
+        Ok(BodySource::Chain(sources))
+    }
+
+    /// Opens the encoded form for an async request.
+    pub(crate) async fn open_async(&self) -> Result<BodySource, RequestError> {
+        let mut sources: VecDeque<BodySource> = VecDeque::new();
+        for (index, (name, part)) in self.parts.iter().enumerate() {
+            let head: Vec<u8> = self.get_part_head(index, name, part);
+            sources.push_back(BodySource::AsyncReader(Box::new(std::io::Cursor::new(
+                head,
+            ))));
+            sources.push_back(match &part.data {
+                MultipartData::Bytes(bytes) => {
+                    BodySource::AsyncReader(Box::new(std::io::Cursor::new(bytes.clone())))
+                }
+                MultipartData::Stream(body_stream) => {
+                    Box::pin(body_stream.open_async()).await?.source
+                }
+            });
+        }
+        sources.push_back(BodySource::AsyncReader(Box::new(std::io::Cursor::new(
+            self.get_tail(),
+        ))));
+        Ok(BodySource::Chain(sources))
+    }
+}
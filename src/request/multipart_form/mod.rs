@@ -0,0 +1,7 @@
+pub(crate) mod r#const;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+
+pub use r#struct::*;
+
+pub(crate) use r#const::*;
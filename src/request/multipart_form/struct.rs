@@ -0,0 +1,12 @@
+use crate::*;
+
+/// A `multipart/form-data` request body made of named text fields and file parts.
+///
+/// # Fields
+/// - `boundary`: The delimiter separating the parts, generated when the form is created.
+/// - `parts`: The named parts of the form, in the order they are sent.
+#[derive(Debug, Clone)]
+pub struct MultipartForm {
+    pub(crate) boundary: String,
+    pub(crate) parts: Vec<(String, MultipartPart)>,
+}
@@ -0,0 +1,10 @@
+use crate::*;
+
+/// The content of a multipart form part.
+#[derive(Debug, Clone)]
+pub(crate) enum MultipartData {
+    /// Content held in memory.
+    Bytes(Vec<u8>),
+    /// Content read from its source while the request is sent.
+    Stream(BodyStream),
+}
@@ -0,0 +1,124 @@
+use crate::*;
+
+impl MultipartPart {
+    fn new(data: MultipartData) -> Self {
+        Self {
+            data,
+            file_name: None,
+            content_type: None,
+        }
+    }
+
+    /// Creates a text part.
+    ///
+    /// # Parameters
+    /// - `value`: The text value of the part.
+    ///
+    /// # Returns
+    /// Returns the new part.
+    pub fn text<T: ToString>(value: T) -> Self {
+        Self::new(MultipartData::Bytes(value.to_string().into_bytes()))
+    }
+
+    /// Creates a part holding the given bytes.
+    ///
+    /// # Parameters
+    /// - `data`: The content of the part.
+    ///
+    /// # Returns
+    /// Returns the new part.
+    pub fn bytes<T: Into<Vec<u8>>>(data: T) -> Self {
+        Self::new(MultipartData::Bytes(data.into()))
+    }
+
+    /// Creates a file part whose content is streamed from the file when the request is sent.
+    ///
+    /// The file name defaults to the last component of `path` and the content type to
+    /// `application/octet-stream`.
+    ///
+    /// # Parameters
+    /// - `path`: The path of the file to upload.
+    ///
+    /// # Returns
+    /// Returns the new part.
+    pub fn file<P: AsRef<Path>>(path: P) -> Self {
+        let path: &Path = path.as_ref();
+        let mut part: Self = Self::new(MultipartData::Stream(BodyStream::new(
+            BodySource::File(path.to_path_buf()),
+            None,
+        )));
+        part.file_name = path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().into_owned());
+        part
+    }
+
+    /// Creates a part whose content is read from a blocking reader when the request is sent.
+    ///
+    /// The reader is consumed by the first request that sends the form.
+    ///
+    /// # Parameters
+    /// - `reader`: The reader the content is read from.
+    /// - `length`: The number of bytes the reader provides, if known.
+    ///
+    /// # Returns
+    /// Returns the new part.
+    pub fn reader<R: Read + Send + 'static>(reader: R, length: Option<u64>) -> Self {
+        Self::new(MultipartData::Stream(BodyStream::new(
+            BodySource::Reader(Box::new(reader)),
+            length,
+        )))
+    }
+
+    /// Creates a part whose content is read from an async reader when the request is sent.
+    ///
+    /// The reader is consumed by the first request that sends the form.
+    ///
+    /// # Parameters
+    /// - `reader`: The async reader the content is read from.
+    /// - `length`: The number of bytes the reader provides, if known.
+    ///
+    /// # Returns
+    /// Returns the new part.
+    pub fn async_reader<R: AsyncRead + Unpin + Send + 'static>(
+        reader: R,
+        length: Option<u64>,
+    ) -> Self {
+        Self::new(MultipartData::Stream(BodyStream::new(
+            BodySource::AsyncReader(Box::new(reader)),
+            length,
+        )))
+    }
+
+    /// Sets the file name sent with the part.
+    ///
+    /// # Parameters
+    /// - `file_name`: The file name, such as `avatar.png`.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the part to allow method chaining.
+    pub fn file_name<T: ToString>(&mut self, file_name: T) -> &mut Self {
+        self.file_name = Some(file_name.to_string());
+        self
+    }
+
+    /// Sets the content type of the part.
+    ///
+    /// # Parameters
+    /// - `content_type`: The content type, such as `image/png`.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the part to allow method chaining.
+    pub fn content_type<T: ToString>(&mut self, content_type: T) -> &mut Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Returns the length of the part content, if known.
+    pub(crate) fn get_length(&self) -> Option<u64> {
+        match &self.data {
+            MultipartData::Bytes(bytes) => Some(bytes.len() as u64),
+            MultipartData::Stream(body_stream) => body_stream.get_length(),
+        }
+    }
+}
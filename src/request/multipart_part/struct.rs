@@ -0,0 +1,14 @@
+use crate::*;
+
+/// A single part of a `MultipartForm`, such as a text field or an uploaded file.
+///
+/// # Fields
+/// - `data`: The content of the part.
+/// - `file_name`: The file name sent in the `Content-Disposition` header, if any.
+/// - `content_type`: The `Content-Type` of the part, if any.
+#[derive(Debug, Clone)]
+pub struct MultipartPart {
+    pub(crate) data: MultipartData,
+    pub(crate) file_name: Option<String>,
+    pub(crate) content_type: Option<String>,
+}
@@ -0,0 +1,76 @@
+use crate::*;
+
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        Self {
+            preserve_method: false,
+            strip_credentials: true,
+            allow_https_downgrade: false,
+        }
+    }
+}
+
+impl RedirectPolicy {
+    /// Creates a redirect policy with browser-compatible defaults.
+    ///
+    /// # Returns
+    /// Returns a new instance of `RedirectPolicy`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps the method and body of the request on `301` and `302` redirects.
+    ///
+    /// A `303` redirect always switches to `GET`, as required by RFC 9110.
+    ///
+    /// # Parameters
+    /// - `preserve_method`: Whether `301` and `302` redirects resend the original method and body.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RedirectPolicy` to allow method chaining.
+    pub fn preserve_method(&mut self, preserve_method: bool) -> &mut Self {
+        self.preserve_method = preserve_method;
+        self
+    }
+
+    /// Drops the `Authorization`, `Cookie` and `Proxy-Authorization` headers on cross-origin redirects.
+    ///
+    /// # Parameters
+    /// - `strip_credentials`: Whether credentials are removed when the scheme, host or port changes.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RedirectPolicy` to allow method chaining.
+    pub fn strip_credentials(&mut self, strip_credentials: bool) -> &mut Self {
+        self.strip_credentials = strip_credentials;
+        self
+    }
+
+    /// Allows following redirects from HTTPS to plain HTTP.
+    ///
+    /// # Parameters
+    /// - `allow_https_downgrade`: Whether an HTTPS request may be redirected to an HTTP URL.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RedirectPolicy` to allow method chaining.
+    pub fn allow_https_downgrade(&mut self, allow_https_downgrade: bool) -> &mut Self {
+        self.allow_https_downgrade = allow_https_downgrade;
+        self
+    }
+
+    /// Returns whether a redirect with the given status turns the request into a body-less `GET`.
+    ///
+    /// # Parameters
+    /// - `status_code`: The status code of the redirect response.
+    /// - `methods`: The method of the request being redirected.
+    pub(crate) fn is_get_redirect(
+        &self,
+        status_code: ResponseStatusCode,
+        methods: &Method,
+    ) -> bool {
+        match status_code {
+            301 | 302 => !self.preserve_method && *methods == Method::POST,
+            303 => !methods.is_head(),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+/// Controls how methods, bodies and credentials are carried over when a redirect is followed.
+///
+/// The default mirrors browsers: `301`, `302` and `303` turn the request into a `GET` without a
+/// body, `307` and `308` resend it unchanged, credentials are dropped when the redirect leaves
+/// the origin, and redirects from HTTPS to HTTP are refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RedirectPolicy {
+    pub(crate) preserve_method: bool,
+    pub(crate) strip_credentials: bool,
+    pub(crate) allow_https_downgrade: bool,
+}
@@ -1 +1,53 @@
-pub(crate) const CONTENT_LENGTH_PATTERN: &[u8] = b"content-length:";
+use crate::*;
+
+pub(crate) const CONTENT_LENGTH_PATTERN: &[u8] = b"\r\ncontent-length:";
+pub(crate) const TRANSFER_ENCODING_PATTERN: &[u8] = b"\r\ntransfer-encoding:";
+pub(crate) const CONTENT_ENCODING_PATTERN: &[u8] = b"\r\ncontent-encoding:";
+pub(crate) const CHUNKED_CODING: &[u8] = b"chunked";
+pub(crate) const CHUNKED_TRANSFER_ENCODING: &str = "chunked";
+pub(crate) const CONNECTION_PATTERN: &[u8] = b"\r\nconnection:";
+pub(crate) const CONNECTION_CLOSE: &[u8] = b"close";
+pub(crate) const HTTP2_ALPN: &[u8] = b"h2";
+pub(crate) const CONNECTING: &str = "connecting";
+pub(crate) const TLS_HANDSHAKE: &str = "performing the TLS handshake";
+pub(crate) const PROXY_NEGOTIATION: &str = "negotiating with the proxy";
+pub(crate) const HTTP2_HANDSHAKE: &str = "performing the HTTP/2 handshake";
+pub(crate) const WRITING_REQUEST: &str = "writing the request";
+pub(crate) const READING_RESPONSE: &str = "reading the response";
+pub(crate) const HTTP_SCHEME: &str = "http";
+pub(crate) const HTTPS_SCHEME: &str = "https";
+pub(crate) const HTTP2_CONNECTION_HEADERS: [&str; 7] = [
+    "host",
+    "connection",
+    "keep-alive",
+    "proxy-connection",
+    "transfer-encoding",
+    "upgrade",
+    "te",
+];
+pub(crate) const REDIRECT_BODY_HEADERS: [&str; 4] = [
+    CONTENT_TYPE,
+    CONTENT_LENGTH,
+    CONTENT_ENCODING,
+    TRANSFER_ENCODING,
+];
+pub(crate) const PROXY_RESPONSE_HEAD_LIMIT: usize = 16384;
+pub(crate) const PROXY_ERROR_BODY_LIMIT: usize = 4096;
+pub(crate) const DRAINED_BODY_LIMIT: usize = 65536;
+pub(crate) const REDIRECT_CREDENTIAL_HEADERS: [&str; 3] =
+    [AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION];
+
+/// The start of the `RequestError::Request` message reported when a step of a request, such as
+/// connecting, the TLS handshake, proxy negotiation or reading the response, does not complete
+/// within its timeout. Every timeout error starts with it; `get_timeout_kind` tells them apart.
+pub(crate) const REQUEST_TIMEOUT: &str = "request timed out";
+/// The start of the `RequestError::Request` message reported when an HTTP or HTTPS proxy refuses
+/// to open a tunnel. The status code, reason phrase and body of the proxy's reply follow it.
+pub(crate) const PROXY_CONNECT_FAILED: &str = "Proxy connection failed";
+/// The start of the message reported when establishing a connection exceeds the connect timeout.
+pub(crate) const CONNECT_TIMEOUT: &str = "request timed out: connect";
+/// The start of the message reported when no response data arrives within the read timeout.
+pub(crate) const READ_TIMEOUT: &str = "request timed out: read";
+/// The start of the message reported when a request, including its redirects, does not complete
+/// before its overall deadline.
+pub(crate) const REQUEST_DEADLINE_EXCEEDED: &str = "request timed out: deadline exceeded";
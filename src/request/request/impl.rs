@@ -1,20 +1,51 @@
 use crate::*;
 
 impl<T: AsyncRead + AsyncWrite + Unpin + Send> AsyncReadWrite for T {}
-impl<T: Read + Write> ReadWrite for T {}
+impl<T: Read + Write + Send> ReadWrite for T {}
+
+impl Debug for HttpRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpRequest")
+            .field("methods", &self.methods)
+            .field("url", &self.url)
+            .field("header", &redact_headers(&self.header))
+            .field("body", &self.body)
+            .field("config", &self.config)
+            .field("tmp", &self.tmp)
+            .field("response", &self.response)
+            .field("client", &self.client)
+            .field("cookie_jar", &self.cookie_jar)
+            .field("middlewares", &self.middlewares)
+            .finish()
+    }
+}
 
 impl AsyncRequestTrait for HttpRequest {
     type RequestResult = RequestResult;
 
     fn send(&mut self) -> Pin<Box<dyn Future<Output = Self::RequestResult> + Send + '_>> {
-        Box::pin(self.send_async())
+        self.begin_send();
+        Box::pin(self.send_with_middleware_async())
+    }
+
+    fn send_stream(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = AsyncResponseStreamResult> + Send + '_>> {
+        self.begin_send();
+        Box::pin(self.send_stream_with_middleware_async())
     }
 }
 
 impl RequestTrait for HttpRequest {
     type RequestResult = RequestResult;
     fn send(&mut self) -> Self::RequestResult {
-        self.send_sync()
+        self.begin_send();
+        self.send_with_middleware()
+    }
+
+    fn send_stream(&mut self) -> ResponseStreamResult {
+        self.begin_send();
+        self.send_stream_with_middleware()
     }
 }
 
@@ -28,6 +59,9 @@ impl Default for HttpRequest {
             config: Arc::new(RwLock::new(Config::default())),
             tmp: Arc::new(RwLock::new(Tmp::default())),
             response: Arc::new(RwLock::new(HttpResponseBinary::default())),
+            client: None,
+            cookie_jar: None,
+            middlewares: Vec::new(),
         }
     }
 }
@@ -36,7 +70,7 @@ impl Default for HttpRequest {
 ///
 /// These methods provide functionality for managing HTTP requests, including:
 /// - Retrieving or setting HTTP attributes (e.g., URL, headers, protocol).
-/// - Constructing and sending HTTP GET or POST requests.
+/// - Constructing and sending HTTP requests for every supported method.
 /// - Parsing responses and handling redirects.
 impl HttpRequest {
     /// Returns the protocol of the HTTP request.
@@ -45,20 +79,85 @@ impl HttpRequest {
     }
 
     /// Returns the HTTP method used for the request.
-    pub(crate) fn get_methods(&self) -> Method {
+    pub fn get_methods(&self) -> Method {
         self.methods.as_ref().clone()
     }
 
     /// Returns the URL of the HTTP request.
-    fn get_url(&self) -> String {
+    pub fn get_url(&self) -> String {
         self.url.as_ref().clone()
     }
 
     /// Returns the headers of the HTTP request.
-    fn get_header(&self) -> RequestHeaders {
+    pub fn get_headers(&self) -> RequestHeaders {
         self.header.as_ref().clone()
     }
 
+    /// Sets the HTTP method of the request.
+    ///
+    /// # Parameters
+    ///
+    /// - `methods`: The new HTTP method.
+    ///
+    /// Returns a mutable reference to the `HttpRequest` to allow method chaining.
+    pub fn set_methods(&mut self, methods: Method) -> &mut Self {
+        self.methods = Arc::new(methods);
+        self
+    }
+
+    /// Sets the URL of the request, including any query string.
+    ///
+    /// # Parameters
+    ///
+    /// - `url`: The new URL.
+    ///
+    /// Returns a mutable reference to the `HttpRequest` to allow method chaining.
+    pub fn set_url(&mut self, url: &str) -> &mut Self {
+        self.url(url.to_string());
+        self
+    }
+
+    /// Sets a header of the request, replacing any header with the same name regardless of case.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: The header name.
+    /// - `value`: The header value.
+    ///
+    /// Returns a mutable reference to the `HttpRequest` to allow method chaining.
+    pub fn set_header<K: ToString, V: ToString>(&mut self, key: K, value: V) -> &mut Self {
+        let key: String = key.to_string();
+        let header: &mut RequestHeaders = Arc::make_mut(&mut self.header);
+        header.retain(|existing_key, _| !existing_key.eq_ignore_ascii_case(&key));
+        header.insert(key, value.to_string());
+        self
+    }
+
+    /// Removes a header from the request, matching the name case-insensitively.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: The header name.
+    ///
+    /// Returns a mutable reference to the `HttpRequest` to allow method chaining.
+    pub fn remove_header(&mut self, key: &str) -> &mut Self {
+        Arc::make_mut(&mut self.header)
+            .retain(|existing_key, _| !existing_key.eq_ignore_ascii_case(key));
+        self
+    }
+
+    /// Replaces the body of the request with binary content.
+    ///
+    /// # Parameters
+    ///
+    /// - `body`: The new body.
+    ///
+    /// Returns a mutable reference to the `HttpRequest` to allow method chaining.
+    pub fn set_body<T: Into<Vec<u8>>>(&mut self, body: T) -> &mut Self {
+        self.body = Arc::new(Body::Binary(body.into()));
+        self
+    }
+
     /// Returns the body of the HTTP request.
     fn get_body(&self) -> Body {
         self.body.as_ref().clone()
@@ -98,7 +197,8 @@ impl HttpRequest {
     ///
     /// - The `Host` header is derived from the URL's host in the configuration.
     /// - The `Content-Length` header is calculated based on the request method:
-    ///   - For `GET` requests, it is set to `0`.
+    ///   - For `GET`, `HEAD` and `TRACE` requests, no body is sent and the header is omitted.
+    ///   - For `DELETE` and `OPTIONS` requests, it is only sent when a body is present.
     ///   - For other methods, it is determined by the length of the body.
     /// - If any required header is missing, it is automatically added with its default value.
     /// - Headers are concatenated into a string with each header ending in a line break specified by `HTTP_BR`.
@@ -113,21 +213,270 @@ impl HttpRequest {
             .any(|key| key.eq_ignore_ascii_case(target_key))
     }
 
-    pub(crate) fn get_header_bytes(&self) -> Vec<u8> {
-        let mut header: RequestHeaders = self.get_header();
-        let body_length: usize = if self.get_methods().is_get() {
-            0usize
-        } else {
-            self.get_body_bytes().len()
+    /// Returns the Basic or Bearer credentials of the request, if they may be sent to the
+    /// current origin.
+    ///
+    /// They are tied to the origin of the first request of each send, and are only sent to
+    /// other origins when the redirect policy keeps credentials.
+    fn get_authorization(&self) -> Option<String> {
+        let (authorization, origin, strip_credentials): (Secret, (bool, String, u16), bool) =
+            self.config.read().ok().and_then(|config| {
+                config.authorization.clone().map(|authorization| {
+                    (
+                        authorization,
+                        Self::get_origin(&config.url_obj),
+                        config.redirect_policy.strip_credentials,
+                    )
+                })
+            })?;
+        self.tmp.write().ok().and_then(|mut tmp| {
+            let auth_origin: &mut (bool, String, u16) =
+                tmp.auth_origin.get_or_insert_with(|| origin.clone());
+            (!strip_credentials || *auth_origin == origin).then_some(authorization.0)
+        })
+    }
+
+    /// Returns the Digest credentials for the next request, if a challenge has been received
+    /// from the origin the request is sent to.
+    fn get_digest_authorization(&self) -> Option<String> {
+        let methods: String = self.get_methods().to_string();
+        let path: String = self.get_path();
+        self.config.write().ok().and_then(|mut config| {
+            let origin: (bool, String, u16) = Self::get_origin(&config.url_obj);
+            let digest_auth: &mut DigestAuth = config.digest_auth.as_mut()?;
+            if digest_auth.origin.as_ref() != Some(&origin) {
+                return None;
+            }
+            digest_auth.get_authorization(&methods, &path)
+        })
+    }
+
+    /// Stores the Digest challenge of a `401` response, if the request should be sent again to
+    /// answer it.
+    ///
+    /// The request is sent again at most once per redirect hop, and only when Digest
+    /// credentials are set and the body can be resent.
+    ///
+    /// Returns `true` if the request should be sent again.
+    fn prepare_digest_retry(&self) -> bool {
+        let (status_code, challenges) = self.response.read().map_or((0, Vec::new()), |response| {
+            (
+                response.get_status_code(),
+                response.get_header_all(WWW_AUTHENTICATE),
+            )
+        });
+        if status_code != 401 || !self.can_resend_body() {
+            return false;
+        }
+        let Some(challenge) = DigestChallenge::parse_all(&challenges) else {
+            return false;
+        };
+        let Ok(mut config) = self.config.write() else {
+            return false;
+        };
+        let origin: (bool, String, u16) = Self::get_origin(&config.url_obj);
+        let Some(digest_auth) = config.digest_auth.as_mut() else {
+            return false;
+        };
+        let Ok(mut tmp) = self.tmp.write() else {
+            return false;
+        };
+        if tmp.digest_retried {
+            return false;
+        }
+        tmp.digest_retried = true;
+        digest_auth.set_challenge(challenge, origin);
+        true
+    }
+
+    /// Returns the scheme, host and port of a proxy, as recorded with its Digest challenge.
+    fn get_proxy_origin(proxy_config: &ProxyConfig) -> (bool, String, u16) {
+        (
+            proxy_config.proxy_type == ProxyType::Https,
+            proxy_config.host.clone(),
+            proxy_config.port,
+        )
+    }
+
+    /// Returns the `Proxy-Authorization` value for a CONNECT to `authority`.
+    ///
+    /// Digest credentials are sent once the proxy has challenged for them, Basic credentials
+    /// otherwise.
+    fn get_proxy_authorization(
+        &self,
+        proxy_config: &ProxyConfig,
+        authority: &str,
+    ) -> Option<String> {
+        let (Some(username), Some(password)) = (&proxy_config.username, &proxy_config.password)
+        else {
+            return None;
+        };
+        let origin: (bool, String, u16) = Self::get_proxy_origin(proxy_config);
+        self.config
+            .write()
+            .ok()
+            .and_then(|mut config| {
+                config
+                    .proxy_digest_auth
+                    .as_mut()
+                    .filter(|digest_auth| {
+                        digest_auth.origin.as_ref() == Some(&origin)
+                            && digest_auth.username == *username
+                            && digest_auth.password == *password
+                    })
+                    .and_then(|digest_auth| digest_auth.get_authorization("CONNECT", authority))
+            })
+            .or_else(|| Some(basic_authorization(username, password)))
+    }
+
+    /// Builds the CONNECT request opening a tunnel to `authority`.
+    fn get_proxy_connect_request(&self, authority: &str, proxy_config: &ProxyConfig) -> String {
+        match self.get_proxy_authorization(proxy_config, authority) {
+            Some(authorization) => format!(
+                "CONNECT {} HTTP/1.1\r\nHost: {}\r\nProxy-Authorization: {}\r\n\r\n",
+                authority, authority, authorization
+            ),
+            None => format!(
+                "CONNECT {} HTTP/1.1\r\nHost: {}\r\n\r\n",
+                authority, authority
+            ),
+        }
+    }
+
+    /// Parses the status line and headers of a proxy's reply to a CONNECT request.
+    fn parse_proxy_response_head(head: &[u8]) -> Result<HttpResponseBinary, RequestError> {
+        if !head.starts_with(b"HTTP/") || !head.ends_with(HTTP_DOUBLE_BR_BYTES) {
+            let status_line: Cow<'_, str> = String::from_utf8_lossy(head);
+            return Err(RequestError::Request(format!(
+                "{}: invalid response {:?}",
+                PROXY_CONNECT_FAILED,
+                status_line.lines().next().unwrap_or_default()
+            )));
+        }
+        Ok(HttpResponseBinary::from_parts(head, Vec::new()))
+    }
+
+    /// Returns whether a proxy accepted a CONNECT request.
+    fn is_proxy_tunnel_established(response: &HttpResponseBinary) -> bool {
+        (200..300).contains(&response.get_status_code())
+    }
+
+    /// Returns how many bytes of the body of a proxy's failure reply to read.
+    ///
+    /// The body is read up to its `Content-Length`, or until the proxy closes the connection when
+    /// it announces `Connection: close` without a length, and never beyond
+    /// `PROXY_ERROR_BODY_LIMIT` bytes.
+    fn get_proxy_response_body_length(response: &HttpResponseBinary) -> u64 {
+        let limit: usize = match response
+            .get_header_all(CONTENT_LENGTH)
+            .first()
+            .and_then(|length| length.trim().parse::<usize>().ok())
+        {
+            Some(length) => length,
+            None if response
+                .get_header_all(TRANSFER_ENCODING)
+                .iter()
+                .all(|coding| {
+                    !coding
+                        .to_ascii_lowercase()
+                        .contains(CHUNKED_TRANSFER_ENCODING)
+                })
+                && response
+                    .get_header_all(CONNECTION)
+                    .iter()
+                    .any(|connection| connection.trim().eq_ignore_ascii_case("close")) =>
+            {
+                PROXY_ERROR_BODY_LIMIT
+            }
+            None => 0,
+        };
+        limit.min(PROXY_ERROR_BODY_LIMIT) as u64
+    }
+
+    /// Stores the Digest challenge of a `407` reply to a CONNECT request.
+    ///
+    /// # Returns
+    /// Returns `true` if the proxy credentials can answer the challenge and the CONNECT
+    /// request should be sent again.
+    fn prepare_proxy_digest_retry(
+        &self,
+        proxy_config: &ProxyConfig,
+        response: &HttpResponseBinary,
+    ) -> bool {
+        if response.get_status_code() != 407 {
+            return false;
+        }
+        let (Some(username), Some(password)) = (&proxy_config.username, &proxy_config.password)
+        else {
+            return false;
+        };
+        let Some(challenge) =
+            DigestChallenge::parse_all(&response.get_header_all(PROXY_AUTHENTICATE))
+        else {
+            return false;
+        };
+        let Ok(mut config) = self.config.write() else {
+            return false;
+        };
+        let digest_auth: &mut DigestAuth = config
+            .proxy_digest_auth
+            .get_or_insert_with(|| DigestAuth::new(username, password));
+        if digest_auth.username != *username || digest_auth.password != *password {
+            *digest_auth = DigestAuth::new(username, password);
+        }
+        digest_auth.set_challenge(challenge, Self::get_proxy_origin(proxy_config));
+        true
+    }
+
+    /// Builds the error reported when a proxy refuses a CONNECT request.
+    fn get_proxy_connect_error(response: &HttpResponseBinary) -> RequestError {
+        let body: Vec<u8> = response.get_body();
+        let body: Cow<'_, str> = String::from_utf8_lossy(&body);
+        let mut message: String = format!(
+            "{}: {} {}",
+            PROXY_CONNECT_FAILED,
+            response.get_status_code(),
+            response.get_status_text()
+        );
+        if !body.trim().is_empty() {
+            message.push_str(&format!(": {}", body.trim()));
+        }
+        RequestError::Request(message)
+    }
+
+    /// Returns the request headers completed with the default `Host`, `Content-Length`,
+    /// `Accept` and `User-Agent` values.
+    pub(crate) fn get_request_headers(&self) -> RequestHeaders {
+        let mut header: RequestHeaders = self.get_headers();
+        if !Self::header_contains_key_case_insensitive(&header, AUTHORIZATION)
+            && let Some(authorization) = self
+                .get_authorization()
+                .or_else(|| self.get_digest_authorization())
+        {
+            header.insert(AUTHORIZATION.to_owned(), authorization);
+        }
+        let body_stream: Option<BodyStream> = self.get_body_stream();
+        let body_length: Option<u64> = match &body_stream {
+            Some(body_stream) => body_stream.get_length(),
+            None => self.get_request_body_bytes().map(|body| body.len() as u64),
         };
         if let Ok(config) = self.config.read() {
             let host_value: String = config.url_obj.host.clone().unwrap_or_default();
-            let content_length_value: String = body_length.to_string();
             if !Self::header_contains_key_case_insensitive(&header, HOST) {
                 header.insert(HOST.to_owned(), host_value);
             }
-            if !Self::header_contains_key_case_insensitive(&header, CONTENT_LENGTH) {
-                header.insert(CONTENT_LENGTH.to_owned(), content_length_value);
+            if let Some(body_length) = body_length
+                && !Self::header_contains_key_case_insensitive(&header, CONTENT_LENGTH)
+            {
+                header.insert(CONTENT_LENGTH.to_owned(), body_length.to_string());
+            }
+            if body_stream.is_some_and(|body_stream| self.is_chunked_body(&body_stream))
+                && !Self::header_contains_key_case_insensitive(&header, TRANSFER_ENCODING)
+            {
+                header.insert(
+                    TRANSFER_ENCODING.to_owned(),
+                    CHUNKED_TRANSFER_ENCODING.to_owned(),
+                );
             }
             if !Self::header_contains_key_case_insensitive(&header, ACCEPT) {
                 header.insert(ACCEPT.to_owned(), ACCEPT_ANY.to_owned());
@@ -135,7 +484,53 @@ impl HttpRequest {
             if !Self::header_contains_key_case_insensitive(&header, USER_AGENT) {
                 header.insert(USER_AGENT.to_owned(), APP_NAME.to_owned());
             }
+            if let Some(cookie_value) = self
+                .get_cookie_jar()
+                .and_then(|cookie_jar| cookie_jar.get_request_cookie(&config.url_obj))
+            {
+                let cookie_key: String = header
+                    .keys()
+                    .find(|key| key.eq_ignore_ascii_case(COOKIE))
+                    .cloned()
+                    .unwrap_or(COOKIE.to_owned());
+                let cookie_value: String = match header.get(&cookie_key) {
+                    Some(value) if !value.is_empty() => format!("{}; {}", value, cookie_value),
+                    _ => cookie_value,
+                };
+                header.insert(cookie_key, cookie_value);
+            }
+        }
+        header
+    }
+
+    /// Returns the cookie jar of the request, or else the one shared by its client.
+    fn get_cookie_jar(&self) -> Option<CookieJar> {
+        self.cookie_jar.clone().or_else(|| {
+            self.client
+                .as_ref()
+                .and_then(|client| client.cookie_jar.clone())
+        })
+    }
+
+    /// Stores the cookies set by the current response in the cookie jar.
+    fn store_response_cookies(&self) {
+        let Some(cookie_jar) = self.get_cookie_jar() else {
+            return;
+        };
+        let set_cookies: Vec<String> = self
+            .response
+            .read()
+            .map_or(Vec::new(), |response| response.get_header_all(SET_COOKIE));
+        if set_cookies.is_empty() {
+            return;
         }
+        if let Ok(config) = self.config.read() {
+            cookie_jar.store(&config.url_obj, set_cookies);
+        }
+    }
+
+    pub(crate) fn get_header_bytes(&self) -> Vec<u8> {
+        let header: RequestHeaders = self.get_request_headers();
         let estimated_size: usize = header.iter().map(|(k, v)| k.len() + v.len() + 4).sum();
         let mut header_bytes: Vec<u8> = Vec::with_capacity(estimated_size);
         for (key, value) in &header {
@@ -164,9 +559,13 @@ impl HttpRequest {
     /// The `Content-Type` header is matched case-insensitively. If no matching `Content-Type`
     /// is found or the parsing fails, the method defaults to returning an empty byte vector.
     /// The body processing relies on the implementation of the `ContentType` parsing logic.
-    pub(crate) fn get_body_bytes(&self) -> Vec<u8> {
-        let header: RequestHeaders = self.get_header();
+    /// Form bodies are encoded when they are set and are returned as they are.
+    pub fn get_body_bytes(&self) -> Vec<u8> {
+        let header: RequestHeaders = self.get_headers();
         let body: Body = self.get_body();
+        if let Body::Form(form) = body {
+            return form.into_bytes();
+        }
         if let Some(content_type_value) = header.get(CONTENT_TYPE) {
             let res: String = content_type_value
                 .to_lowercase()
@@ -188,6 +587,214 @@ impl HttpRequest {
         String::new().into_bytes()
     }
 
+    /// Returns the body bytes that are written on the wire for the current method.
+    ///
+    /// `GET`, `HEAD` and `TRACE` never carry a body. `DELETE` and `OPTIONS` only carry one
+    /// when it is non-empty, while `POST`, `PUT` and `PATCH` always send theirs, even if empty.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(Vec<u8>)` - The body to send.
+    /// - `None` - The request is sent without a body.
+    pub(crate) fn get_request_body_bytes(&self) -> Option<Vec<u8>> {
+        let methods: Method = self.get_methods();
+        if methods.is_get() || methods.is_head() || methods.is_trace() {
+            return None;
+        }
+        if let Body::Stream(_) = self.get_body() {
+            return None;
+        }
+        let body: Vec<u8> = self.get_body_bytes();
+        if (methods.is_delete() || methods.is_options()) && body.is_empty() {
+            return None;
+        }
+        Some(body)
+    }
+
+    /// Returns the streamed body to send after the request head, if any.
+    ///
+    /// Like other bodies, streamed bodies are never sent with `GET`, `HEAD` and `TRACE`.
+    pub(crate) fn get_body_stream(&self) -> Option<BodyStream> {
+        let methods: Method = self.get_methods();
+        if methods.is_get() || methods.is_head() || methods.is_trace() {
+            return None;
+        }
+        match self.get_body() {
+            Body::Stream(body_stream) => Some(body_stream),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the streamed body is sent with chunked transfer coding.
+    ///
+    /// Chunked coding is used when neither the body nor the request headers give its length.
+    fn is_chunked_body(&self, body_stream: &BodyStream) -> bool {
+        body_stream.get_length().is_none()
+            && !Self::header_contains_key_case_insensitive(&self.get_headers(), CONTENT_LENGTH)
+    }
+
+    /// Returns whether the request body can be sent again on another connection.
+    fn can_resend_body(&self) -> bool {
+        self.get_body_stream()
+            .is_none_or(|body_stream| body_stream.is_replayable())
+    }
+
+    /// Returns whether a request that failed on a reused pooled connection is sent again on a
+    /// new connection.
+    ///
+    /// An idle connection closed by the server shows up as a failed write, or as the connection
+    /// closing before any response byte arrives. Timeouts are never resent, and a fully written
+    /// request is resent only when its method is idempotent, since the server may have acted on it.
+    fn is_stale_connection<S, T>(
+        &self,
+        connection: &PooledConnection<S>,
+        result: &Result<T, RequestError>,
+    ) -> bool {
+        connection.reused
+            && !connection.received
+            && !result.as_ref().err().is_some_and(is_timeout)
+            && (!connection.written || RetryPolicy::is_idempotent_method(&self.get_methods()))
+    }
+
+    /// Writes the complete request, streaming the body when it is read from a source.
+    fn write_request(&self, stream: &mut BoxReadWrite) -> Result<(), RequestError> {
+        let request: Vec<u8> = self.get_request_bytes();
+        stream
+            .write_all(&request)
+            .map_err(|err| self.get_io_error(err, TimeoutKind::Write, WRITING_REQUEST))?;
+        self.write_body_stream(stream)?;
+        stream
+            .flush()
+            .map_err(|err| self.get_io_error(err, TimeoutKind::Write, WRITING_REQUEST))
+    }
+
+    /// Writes the complete request to the async connection, streaming the body when it is read
+    /// from a source.
+    async fn write_request_async(
+        &self,
+        stream: &mut BoxAsyncReadWrite,
+    ) -> Result<(), RequestError> {
+        let request: Vec<u8> = self.get_request_bytes();
+        self.with_timeout(TimeoutKind::Write, WRITING_REQUEST, async {
+            stream
+                .write_all(&request)
+                .await
+                .map_err(|err| RequestError::Request(err.to_string()))
+        })
+        .await?;
+        self.write_body_stream_async(stream).await?;
+        self.with_timeout(TimeoutKind::Write, WRITING_REQUEST, async {
+            stream
+                .flush()
+                .await
+                .map_err(|err| RequestError::Request(err.to_string()))
+        })
+        .await
+    }
+
+    /// Writes the streamed body, if any, to the connection after the request head.
+    fn write_body_stream(&self, stream: &mut BoxReadWrite) -> Result<(), RequestError> {
+        let Some(body_stream) = self.get_body_stream() else {
+            return Ok(());
+        };
+        let chunked: bool = self.is_chunked_body(&body_stream);
+        let mut body_reader: BodyReader = body_stream.open()?;
+        let mut buffer: Vec<u8> = vec![0; self.get_buffer_size()];
+        loop {
+            let n: usize = body_reader.read(&mut buffer)?;
+            let data: Vec<u8> = Self::frame_body_chunk(&buffer[..n], chunked);
+            stream
+                .write_all(&data)
+                .map_err(|err| self.get_io_error(err, TimeoutKind::Write, WRITING_REQUEST))?;
+            if n == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Writes the streamed body, if any, to the async connection after the request head.
+    async fn write_body_stream_async(
+        &self,
+        stream: &mut BoxAsyncReadWrite,
+    ) -> Result<(), RequestError> {
+        let Some(body_stream) = self.get_body_stream() else {
+            return Ok(());
+        };
+        let chunked: bool = self.is_chunked_body(&body_stream);
+        let mut body_reader: BodyReader = body_stream.open_async().await?;
+        let mut buffer: Vec<u8> = vec![0; self.get_buffer_size()];
+        loop {
+            let n: usize = body_reader.read_async(&mut buffer).await?;
+            let data: Vec<u8> = Self::frame_body_chunk(&buffer[..n], chunked);
+            self.with_timeout(TimeoutKind::Write, WRITING_REQUEST, async {
+                stream
+                    .write_all(&data)
+                    .await
+                    .map_err(|err| RequestError::Request(err.to_string()))
+            })
+            .await?;
+            if n == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Frames a chunk of a streamed body for the wire.
+    ///
+    /// With chunked transfer coding an empty chunk becomes the terminating last-chunk.
+    fn frame_body_chunk(data: &[u8], chunked: bool) -> Vec<u8> {
+        if !chunked {
+            return data.to_vec();
+        }
+        let mut frame: Vec<u8> = format!("{:X}", data.len()).into_bytes();
+        frame.extend_from_slice(HTTP_BR_BYTES);
+        frame.extend_from_slice(data);
+        frame.extend_from_slice(HTTP_BR_BYTES);
+        frame
+    }
+
+    /// Checks whether the given method can be sent by this client.
+    ///
+    /// # Parameters
+    ///
+    /// - `methods`: The HTTP method to check.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` for `GET`, `POST`, `PUT`, `PATCH`, `DELETE`, `HEAD`, `OPTIONS` and `TRACE`.
+    pub(crate) fn is_supported_method(methods: &Method) -> bool {
+        methods.is_get()
+            || methods.is_post()
+            || methods.is_put()
+            || methods.is_patch()
+            || methods.is_delete()
+            || methods.is_head()
+            || methods.is_options()
+            || methods.is_trace()
+    }
+
+    /// Serializes the complete request (request line, headers and body) into bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The raw request ready to be written to the connection.
+    pub(crate) fn get_request_bytes(&self) -> Vec<u8> {
+        let path: String = self.get_path();
+        let header_bytes: Vec<u8> = self.get_header_bytes();
+        let body_bytes: Option<Vec<u8>> = self.get_request_body_bytes();
+        let http_version_str: String =
+            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
+                config.http_version.to_string()
+            });
+        SharedRequestBuilder::build_http_request(
+            &self.get_methods().to_string(),
+            path,
+            header_bytes,
+            body_bytes,
+            http_version_str,
+        )
+    }
+
     /// Retrieves the full path of the HTTP request, including the query string if present.
     ///
     /// This function constructs and returns the complete path of the HTTP request, which
@@ -200,6 +807,9 @@ impl HttpRequest {
     /// not set in the `url_obj` configuration. If the query string is empty, the function
     /// simply returns the path.
     ///
+    /// Characters that may not appear in a request target, such as spaces and non-ASCII
+    /// characters, are percent-encoded; existing escapes are sent as they are.
+    ///
     /// # Returns
     ///
     /// - `String` - The full path, including the query string if available, or just the
@@ -222,104 +832,50 @@ impl HttpRequest {
                 )
             }
         });
-        path
-    }
-
-    /// Sends a GET request over the provided stream and returns the HTTP response.
-    ///
-    /// This method constructs and sends an HTTP GET request to the server. It formats the URL path
-    /// and query parameters based on the current configuration and sends the request to the server
-    /// via the provided `stream`. After sending the request, it waits for the response and reads
-    /// the result.
-    ///
-    /// # Parameters
-    /// - `stream`: A mutable reference to a `Box<dyn ReadWrite>`, representing the stream used
-    ///   for sending and receiving data.
-    ///
-    /// # Returns
-    /// Returns a `Result<HttpResponseBinary, RequestError>`, where:
-    /// - `Ok(HttpResponseBinary)` contains the HTTP response received from the server.
-    /// - `Err(RequestError)` indicates that an error occurred while sending the request or reading the response.
-    fn send_get_request(
-        &mut self,
-        stream: &mut Box<dyn ReadWrite>,
-    ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
-                config.http_version.to_string()
-            });
-
-        let request: Vec<u8> =
-            SharedRequestBuilder::build_get_request(path, header_bytes, http_version_str);
-
-        stream
-            .write_all(&request)
-            .and_then(|_| stream.flush())
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        self.read_response(stream)
+        escape_request_target(&path)
     }
 
-    /// Sends a POST request over the provided stream and returns the HTTP response.
+    /// Sends the request over the provided stream and returns the HTTP response.
     ///
-    /// This method constructs and sends an HTTP POST request to the server. It formats the URL path
-    /// and sends the body content along with the headers to the server via the provided `stream`. After
-    /// sending the request, it waits for the response and reads the result.
+    /// This method constructs the request line, headers and (when the method carries one) the
+    /// body through `SharedRequestBuilder::build_http_request`, writes everything to the
+    /// provided `stream`, and then waits for the response.
     ///
     /// # Parameters
     /// - `stream`: A mutable reference to a `Box<dyn ReadWrite>`, representing the stream used
     ///   for sending and receiving data.
     ///
     /// # Returns
-    /// Returns a `Result<HttpResponseBinary, RequestError>`, where:
-    /// - `Ok(HttpResponseBinary)` contains the HTTP response received from the server.
+    /// Returns a `Result<Option<Vec<u8>>, RequestError>`, where:
+    /// - `Ok(Option<Vec<u8>>)` contains the redirect location, if any, once the response is stored.
     /// - `Err(RequestError)` indicates that an error occurred while sending the request or reading the response.
-    fn send_post_request(
+    fn send_request(
         &mut self,
-        stream: &mut Box<dyn ReadWrite>,
-    ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let body_bytes: Vec<u8> = self.get_body_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
-                config.http_version.to_string()
-            });
-
-        let request: Vec<u8> = SharedRequestBuilder::build_post_request(
-            path,
-            header_bytes,
-            body_bytes,
-            http_version_str,
-        );
-
-        stream
-            .write_all(&request)
-            .and_then(|_| stream.flush())
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        self.read_response(stream)
+        connection: &mut PooledConnection<BoxReadWrite>,
+    ) -> Result<Option<Vec<u8>>, RequestError> {
+        self.write_request(&mut connection.stream)?;
+        connection.written = true;
+        self.read_response(connection)
     }
 
     /// Reads the HTTP response from the provided stream.
     ///
     /// This method reads the response from the server after sending an HTTP request. It processes the
-    /// headers, checks for redirects, and retrieves the response body based on the content length.
-    /// If a redirect is detected, it follows the redirection URL. The method ensures that the entire
-    /// response is read before returning.
+    /// headers, checks for redirects, and retrieves the response body based on its framing.
+    /// The method ensures that the entire response is read before returning, and records on the
+    /// connection whether it can be kept alive for another request.
     ///
     /// # Parameters
-    /// - `stream`: A mutable reference to a `Box<dyn ReadWrite>`, representing the stream used
-    ///   for receiving the response.
+    /// - `connection`: The connection used for receiving the response.
     ///
     /// # Returns
-    /// Returns a `Result<HttpResponseBinary, RequestError>`, where:
-    /// - `Ok(HttpResponseBinary)` contains the complete HTTP response after processing headers and body.
+    /// Returns a `Result<Option<Vec<u8>>, RequestError>`, where:
+    /// - `Ok(Option<Vec<u8>>)` contains the redirect location, if any, once the response is stored.
     /// - `Err(RequestError)` indicates that an error occurred while reading the response.
     fn read_response(
         &mut self,
-        stream: &mut Box<dyn ReadWrite>,
-    ) -> Result<BoxResponseTrait, RequestError> {
+        connection: &mut PooledConnection<BoxReadWrite>,
+    ) -> Result<Option<Vec<u8>>, RequestError> {
         let buffer_size: usize = self
             .config
             .read()
@@ -328,22 +884,42 @@ impl HttpRequest {
         let initial_capacity: usize = buffer_size.max(8192);
         let mut response_bytes: Vec<u8> = Vec::with_capacity(initial_capacity);
         let mut headers_done: bool = false;
-        let mut content_length: usize = 0;
+        let mut body_framing: BodyFraming = BodyFraming::UntilClose;
+        let mut chunked_decoder: ChunkedDecoder = ChunkedDecoder::default();
+        let mut body: Vec<u8> = Vec::new();
         let mut redirect_url: Option<Vec<u8>> = None;
-        let mut headers_end_pos: usize = 0;
-        let http_version: String = self
-            .config
-            .read()
-            .map_or(HttpVersion::default().to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let http_version_bytes: Vec<u8> = http_version.to_lowercase().into_bytes();
         let location_sign_key: Vec<u8> = format!("{}:", LOCATION.to_lowercase()).into_bytes();
-        'read_loop: while let Ok(n) = stream.read(&mut buffer) {
+        let is_head: bool = self.get_methods().is_head();
+        let mut completed: bool = false;
+        let timeouts: RequestTimeouts = self.get_timeouts();
+        'read_loop: loop {
+            if timeouts.is_expired() {
+                return Err(timeouts.get_error(TimeoutKind::Read, READING_RESPONSE));
+            }
+            let n: usize = match connection.stream.read(&mut buffer) {
+                Ok(n) => n,
+                Err(err) if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    return Err(timeouts.get_error(TimeoutKind::Read, READING_RESPONSE));
+                }
+                Err(_) => break,
+            };
             if n == 0 {
                 break;
             }
-            let new_capacity: usize = SharedResponseHandler::calculate_buffer_capacity(
+            connection.received = true;
+            if headers_done {
+                if SharedResponseHandler::read_body_bytes(
+                    &body_framing,
+                    &mut chunked_decoder,
+                    &mut body,
+                    &buffer[..n],
+                )? {
+                    completed = true;
+                    break 'read_loop;
+                }
+                continue;
+            }
+            let new_capacity: usize = SharedResponseHandler::calculate_buffer_capacity(
                 &response_bytes,
                 n,
                 response_bytes.capacity(),
@@ -353,36 +929,63 @@ impl HttpRequest {
             }
             let old_len: usize = response_bytes.len();
             response_bytes.extend_from_slice(&buffer[..n]);
-            if !headers_done {
-                let search_start: usize = old_len.saturating_sub(3);
-                if let Some(pos) =
-                    SharedResponseHandler::find_double_crlf(&response_bytes, search_start)
-                {
-                    headers_done = true;
-                    headers_end_pos = pos + 4;
-
-                    SharedResponseHandler::parse_response_headers(
-                        &response_bytes[..headers_end_pos],
-                        &http_version_bytes,
-                        &location_sign_key,
-                        &mut content_length,
-                        &mut redirect_url,
-                    )?;
-                }
-            }
-            if headers_done {
-                let total_expected_length: usize = headers_end_pos + content_length;
-                if response_bytes.len() >= total_expected_length {
-                    response_bytes.truncate(total_expected_length);
+            let search_start: usize = old_len.saturating_sub(3);
+            if let Some(pos) =
+                SharedResponseHandler::find_double_crlf(&response_bytes, search_start)
+            {
+                headers_done = true;
+                let headers_end_pos: usize = pos + 4;
+                SharedResponseHandler::parse_response_headers(
+                    &response_bytes[..headers_end_pos],
+                    &location_sign_key,
+                    is_head,
+                    &mut body_framing,
+                    &mut redirect_url,
+                )?;
+                let received_body: Vec<u8> = response_bytes.split_off(headers_end_pos);
+                if SharedResponseHandler::read_body_bytes(
+                    &body_framing,
+                    &mut chunked_decoder,
+                    &mut body,
+                    &received_body,
+                )? {
+                    completed = true;
                     break 'read_loop;
                 }
             }
         }
-        self.response = Arc::new(RwLock::new(<HttpResponseBinary as ResponseTrait>::from(
-            &response_bytes,
-        )));
+        let response: HttpResponseBinary = if headers_done {
+            SharedResponseHandler::build_response(&response_bytes, &chunked_decoder, body)
+        } else {
+            <HttpResponseBinary as ResponseTrait>::from(&response_bytes)
+        };
+        connection.keep_alive = completed
+            && body_framing != BodyFraming::UntilClose
+            && SharedResponseHandler::is_keep_alive(&response_bytes)
+            && self
+                .config
+                .read()
+                .is_ok_and(|config| config.http_version == HttpVersion::HTTP1_1);
+        self.response = Arc::new(RwLock::new(response));
+        Ok(redirect_url)
+    }
+
+    /// Decodes the stored response or follows its redirect location.
+    ///
+    /// # Parameters
+    /// - `redirect_url`: The redirect location returned by `read_response`.
+    ///
+    /// # Returns
+    /// Returns the final response, or `Err(RequestError)` if following the redirect fails.
+    fn handle_response(&mut self, redirect_url: Option<Vec<u8>>) -> RequestResult {
+        self.store_response_cookies();
+        if self.prepare_digest_retry() {
+            return self.send_sync();
+        }
         if let Ok(config) = self.config.read() {
             if !config.redirect || redirect_url.is_none() {
+                self.attach_redirect_history();
+                self.attach_tls_info();
                 if config.decode {
                     if let Ok(mut response) = self.response.write() {
                         *response = response.decode(config.buffer);
@@ -404,10 +1007,26 @@ impl HttpRequest {
     ///
     /// # Parameters
     ///
-    /// - `url`: The redirection URL to follow.
+    /// - `url`: The redirection URL to follow, resolved against the current request URL when relative.
     ///
     /// Returns `Ok(HttpResponseBinary)` if the redirection is successful, or `Err(RequestError)` otherwise.
     fn handle_redirect(&mut self, url: String) -> Result<BoxResponseTrait, RequestError> {
+        self.prepare_redirect(url)?;
+        self.send_sync()
+    }
+
+    /// Points the request at the redirection URL after checking that it may be followed.
+    ///
+    /// Detects redirect loops, enforces the maximum number of redirects and applies the
+    /// redirect policy, recording the redirect response in the redirect history.
+    ///
+    /// # Parameters
+    ///
+    /// - `url`: The redirection URL to follow, resolved against the current request URL when relative.
+    ///
+    /// Returns `Ok(())` if the redirect may be followed, or `Err(RequestError)` otherwise.
+    fn prepare_redirect(&mut self, url: String) -> Result<(), RequestError> {
+        let url: String = resolve_url(&self.get_url(), &url);
         if let Ok(mut config) = self.config.write() {
             if !config.redirect {
                 return Err(RequestError::NeedOpenRedirect);
@@ -417,14 +1036,317 @@ impl HttpRequest {
                     return Err(RequestError::RedirectUrlDeadLoop);
                 }
                 tmp.visit_url.insert(url.clone());
+                tmp.digest_retried = false;
                 if config.redirect_times >= config.max_redirect_times {
                     return Err(RequestError::MaxRedirectTimes);
                 }
                 config.redirect_times += 1;
             }
         }
-        self.url(url.clone());
-        self.send_sync()
+        self.apply_redirect_policy(&url)?;
+        self.record_redirect_hop();
+        self.url(url);
+        Ok(())
+    }
+
+    /// Appends the redirect response about to be followed to the redirect history.
+    fn record_redirect_hop(&self) {
+        let redirect_hop: RedirectHop = match self.response.read() {
+            Ok(response) => RedirectHop {
+                url: self.get_url(),
+                status_code: response.get_status_code(),
+                headers: response.get_headers(),
+            },
+            Err(_) => return,
+        };
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.redirect_history.push(redirect_hop);
+        }
+    }
+
+    /// Attaches the redirects followed so far to the final response.
+    fn attach_redirect_history(&self) {
+        let redirect_history: Vec<RedirectHop> = self
+            .tmp
+            .read()
+            .map_or(Vec::new(), |tmp| tmp.redirect_history.clone());
+        if let Ok(response) = self.response.read()
+            && let Ok(mut response_redirect_history) = response.redirect_history.write()
+        {
+            *response_redirect_history = redirect_history;
+        }
+    }
+
+    /// Starts a new redirect history and overall deadline for a request sent through the
+    /// request trait.
+    fn begin_send(&self) {
+        let total_timeout: Option<u64> = self
+            .config
+            .read()
+            .ok()
+            .and_then(|config| config.total_timeout);
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.redirect_history.clear();
+            tmp.digest_retried = false;
+            tmp.auth_origin = None;
+            tmp.deadline = total_timeout
+                .map(|total_timeout| Instant::now() + Duration::from_millis(total_timeout));
+        }
+    }
+
+    /// Returns the middleware of the client followed by the middleware of the request.
+    fn get_middlewares(&self) -> Vec<ArcMiddleware> {
+        let mut middlewares: Vec<ArcMiddleware> = self
+            .client
+            .as_ref()
+            .map_or(Vec::new(), |client| client.middlewares.clone());
+        middlewares.extend(self.middlewares.iter().cloned());
+        middlewares
+    }
+
+    /// Runs `on_request` of each middleware in order until one ends the chain early.
+    ///
+    /// # Parameters
+    ///
+    /// - `middlewares`: The middleware chain.
+    ///
+    /// Returns how many middleware ran, together with the response or error of the one that
+    /// ended the chain early, or `None` if the request should be sent.
+    fn run_request_middlewares(
+        &mut self,
+        middlewares: &[ArcMiddleware],
+    ) -> (usize, Option<Result<HttpResponseBinary, RequestError>>) {
+        for (index, middleware) in middlewares.iter().enumerate() {
+            match middleware.on_request(self) {
+                Ok(None) => {}
+                Ok(Some(response)) => return (index, Some(Ok(response))),
+                Err(err) => return (index, Some(Err(err))),
+            }
+        }
+        (middlewares.len(), None)
+    }
+
+    /// Passes the outcome of a request back through `on_response` or `on_error` of each
+    /// middleware that ran, in reverse order.
+    ///
+    /// # Parameters
+    ///
+    /// - `middlewares`: The middleware whose `on_request` ran.
+    /// - `result`: The response or error returned by the rest of the chain.
+    fn run_response_middlewares(
+        &self,
+        middlewares: &[ArcMiddleware],
+        mut result: Result<HttpResponseBinary, RequestError>,
+    ) -> RequestResult {
+        for middleware in middlewares.iter().rev() {
+            result = match result {
+                Ok(mut response) => middleware
+                    .on_response(self, &mut response)
+                    .map(|_| response),
+                Err(err) => middleware.on_error(self, err),
+            };
+        }
+        result.map(|response| Box::new(response) as BoxResponseTrait)
+    }
+
+    /// Passes the outcome of a streamed request back through `on_response` or `on_error` of each
+    /// middleware that ran, in reverse order.
+    ///
+    /// `on_response` sees the response head before the body is read; a response returned by
+    /// `on_error` is streamed from memory.
+    ///
+    /// # Parameters
+    ///
+    /// - `middlewares`: The middleware whose `on_request` ran.
+    /// - `result`: The response stream or error returned by the rest of the chain.
+    /// - `get_response`: Returns the response head of a stream.
+    /// - `from_response`: Streams a complete response.
+    fn run_stream_response_middlewares<S>(
+        &self,
+        middlewares: &[ArcMiddleware],
+        mut result: Result<S, RequestError>,
+        get_response: fn(&mut S) -> &mut HttpResponseBinary,
+        from_response: impl Fn(HttpResponseBinary) -> S,
+    ) -> Result<S, RequestError> {
+        for middleware in middlewares.iter().rev() {
+            result = match result {
+                Ok(mut stream) => middleware
+                    .on_response(self, get_response(&mut stream))
+                    .map(|_| stream),
+                Err(err) => middleware.on_error(self, err).map(&from_response),
+            };
+        }
+        result
+    }
+
+    /// Returns the retry policy of the request, if one is set.
+    fn get_retry_policy(&self) -> Option<RetryPolicy> {
+        self.config
+            .read()
+            .ok()
+            .and_then(|config| config.retry_policy.clone())
+    }
+
+    /// Decides whether an attempt is retried and reports its outcome to the retry hook.
+    ///
+    /// A retryable response is retried after its `Retry-After` delay when it has one, and after
+    /// the policy's backoff otherwise. No retry is made if the next attempt would start after
+    /// the overall deadline.
+    ///
+    /// # Parameters
+    ///
+    /// - `retry_policy`: The retry policy of the request.
+    /// - `original`: The request as it was before its first attempt.
+    /// - `attempt`: The number of the attempt, starting at 1.
+    /// - `error`: The error the attempt failed with, or `None` if a response was received.
+    ///
+    /// Returns the delay before the next attempt, or `None` if the outcome is final.
+    fn get_retry_delay(
+        &self,
+        retry_policy: &RetryPolicy,
+        original: &HttpRequest,
+        attempt: usize,
+        error: Option<&RequestError>,
+    ) -> Option<Duration> {
+        let mut retry_attempt: RetryAttempt = RetryAttempt {
+            attempt,
+            status_code: None,
+            error: None,
+            delay: None,
+        };
+        let mut retry_after: Option<Duration> = None;
+        let retryable: bool = match error {
+            None => {
+                let (status_code, retry_after_values) =
+                    self.response.read().map_or((0, Vec::new()), |response| {
+                        (
+                            response.get_status_code(),
+                            response.get_header_all(RETRY_AFTER),
+                        )
+                    });
+                retry_attempt.status_code = Some(status_code);
+                retry_after = retry_after_values
+                    .first()
+                    .and_then(|value| RetryPolicy::parse_retry_after(value));
+                retry_policy.is_retryable_status(status_code)
+            }
+            Some(err) => {
+                retry_attempt.error = Some(err.clone());
+                retry_policy.is_retryable_error(err)
+            }
+        };
+        if retryable
+            && attempt < retry_policy.max_attempts
+            && retry_policy.is_retryable_method(&original.get_methods())
+            && self.can_resend_body()
+        {
+            let delay: Option<Duration> = match retry_after {
+                Some(retry_after)
+                    if retry_after > Duration::from_millis(retry_policy.max_backoff) =>
+                {
+                    None
+                }
+                Some(retry_after) => Some(retry_after),
+                None => Some(retry_policy.get_backoff(attempt)),
+            };
+            let deadline: Option<Instant> = self.tmp.read().ok().and_then(|tmp| tmp.deadline);
+            retry_attempt.delay = delay
+                .filter(|delay| deadline.is_none_or(|deadline| Instant::now() + *delay < deadline));
+        }
+        retry_policy.notify(&retry_attempt);
+        retry_attempt.delay
+    }
+
+    /// Restores the method, URL, headers and body the request had before its first attempt and
+    /// forgets the redirects followed, before it is sent again under the retry policy.
+    ///
+    /// Redirects may have switched the request to a body-less `GET` or stripped its
+    /// credentials; the retry starts over from the request as the caller built it.
+    ///
+    /// # Parameters
+    ///
+    /// - `original`: The request as it was before its first attempt.
+    fn begin_retry(&mut self, original: &HttpRequest) {
+        if let Ok(mut config) = self.config.write() {
+            config.redirect_times = 0;
+        }
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.visit_url.clear();
+            tmp.redirect_history.clear();
+            tmp.digest_retried = false;
+        }
+        self.methods = original.methods.clone();
+        self.url = original.url.clone();
+        self.header = original.header.clone();
+        self.body = original.body.clone();
+    }
+
+    /// Returns the scheme, host and port identifying the origin of a parsed URL.
+    fn get_origin(url_obj: &HttpUrlComponents) -> (bool, String, u16) {
+        let https: bool = url_obj.protocol.is_https();
+        let port: u16 = match url_obj.port {
+            Some(port) if port != 0 => port,
+            _ => url_obj.protocol.get_port(),
+        };
+        (
+            https,
+            url_obj.host.clone().unwrap_or_default().to_lowercase(),
+            port,
+        )
+    }
+
+    /// Adapts the request to the redirect about to be followed, according to the redirect policy.
+    ///
+    /// Switches to a body-less `GET` when the status code calls for it, strips credentials when
+    /// the redirect leaves the current origin, and refuses HTTPS to HTTP downgrades.
+    ///
+    /// # Parameters
+    /// - `url`: The resolved redirection URL.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the redirect may be followed, or `Err(RequestError)` otherwise.
+    pub(crate) fn apply_redirect_policy(&mut self, url: &str) -> Result<(), RequestError> {
+        let target: HttpUrlComponents = HttpUrlComponents::parse(url)
+            .map_err(|err| RequestError::InvalidUrl(err.to_string()))?;
+        let status_code: ResponseStatusCode = self
+            .response
+            .read()
+            .map_or(HttpStatus::Unknown.code(), |response| {
+                response.get_status_code()
+            });
+        let (policy, current_origin, target_origin) = match self.config.read() {
+            Ok(config) => (
+                config.redirect_policy,
+                Self::get_origin(&config.url_obj),
+                Self::get_origin(&target),
+            ),
+            Err(_) => return Ok(()),
+        };
+        if current_origin.0 && !target_origin.0 && !policy.allow_https_downgrade {
+            return Err(RequestError::Request(format!(
+                "refusing to follow redirect from HTTPS to HTTP: {}",
+                url
+            )));
+        }
+        let mut header: RequestHeaders = self.get_headers();
+        if policy.is_get_redirect(status_code, &self.get_methods()) {
+            self.methods = Arc::new(Method::GET);
+            self.body = Arc::new(Body::default());
+            header.retain(|key, _| {
+                !REDIRECT_BODY_HEADERS
+                    .iter()
+                    .any(|name| key.eq_ignore_ascii_case(name))
+            });
+        }
+        if policy.strip_credentials && current_origin != target_origin {
+            header.retain(|key, _| {
+                !REDIRECT_CREDENTIAL_HEADERS
+                    .iter()
+                    .any(|name| key.eq_ignore_ascii_case(name))
+            });
+        }
+        self.header = Arc::new(header);
+        Ok(())
     }
 
     /// Determines the appropriate port for the HTTP request.
@@ -443,6 +1365,318 @@ impl HttpRequest {
         protocol.get_port()
     }
 
+    /// Returns whether the request is sent over HTTP/2.
+    pub(crate) fn is_http2(&self) -> bool {
+        self.config
+            .read()
+            .is_ok_and(|config| config.http_version == HttpVersion::HTTP2)
+    }
+
+    /// Builds the rustls client configuration used for TLS connections of this request.
+    ///
+    /// # Parameters
+    ///
+    /// - `http2`: Whether to offer HTTP/2 through ALPN.
+    /// - `origin`: Whether the connection is to the origin server; connections to an HTTPS
+    ///   proxy are not checked against certificate pins.
+    pub(crate) fn get_tls_config(
+        &self,
+        http2: bool,
+        origin: bool,
+    ) -> Result<Arc<ClientConfig>, RequestError> {
+        let tls: TlsConfig = match self.tmp.read() {
+            Ok(tmp) if origin => tmp.tls.clone(),
+            Ok(tmp) => tmp.tls.get_proxy_config(),
+            Err(err) => {
+                return Err(RequestError::Unknown(format!(
+                    "error reading temporary configuration: {}",
+                    err
+                )));
+            }
+        };
+        let alpn_protocols: Vec<Vec<u8>> = if http2 {
+            vec![HTTP2_ALPN.to_vec()]
+        } else {
+            Vec::new()
+        };
+        tls.build_client_config(alpn_protocols)
+            .map(Arc::new)
+            .map_err(RequestError::TlsConnectorBuild)
+    }
+
+    /// Maps an error raised during the TLS handshake to a `RequestError`.
+    ///
+    /// Pin mismatches are reported as `TlsConnectorBuild` with a message starting with
+    /// `CERTIFICATE_PIN_MISMATCH`, which `is_certificate_pin_mismatch` recognises.
+    fn get_tls_handshake_error(err: std::io::Error) -> RequestError {
+        let message: String = err.to_string();
+        match message.find(CERTIFICATE_PIN_MISMATCH) {
+            Some(index) => RequestError::TlsConnectorBuild(message[index..].to_string()),
+            None => RequestError::TlsConnectorBuild(message),
+        }
+    }
+
+    /// Performs the TLS handshake with `host` over a blocking stream.
+    ///
+    /// The handshake completes before the stream is returned, so certificate and pinning
+    /// failures are reported here instead of on the first write.
+    ///
+    /// # Parameters
+    ///
+    /// - `stream`: The connection to the origin server or to an HTTPS proxy.
+    /// - `host`: The name the certificate is verified against.
+    /// - `origin`: Whether `host` is the origin server, whose session details are recorded.
+    fn connect_tls<S: Read + Write>(
+        &self,
+        mut stream: S,
+        host: &str,
+        origin: bool,
+    ) -> Result<StreamOwned<ClientConnection, S>, RequestError> {
+        let client_config: Arc<ClientConfig> = self.get_tls_config(false, origin)?;
+        let dns_name: ServerName<'_> = ServerName::try_from(host.to_string())
+            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
+        let mut session: ClientConnection = ClientConnection::new(client_config, dns_name)
+            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
+        while session.is_handshaking() {
+            session
+                .complete_io(&mut stream)
+                .map_err(Self::get_tls_handshake_error)?;
+        }
+        if origin {
+            self.set_tls_info(Some(TlsInfo::from_connection(&session)));
+        }
+        Ok(StreamOwned::new(session, stream))
+    }
+
+    /// Performs the TLS handshake with `host` over an async stream.
+    ///
+    /// # Parameters
+    ///
+    /// - `stream`: The connection to the origin server or to an HTTPS proxy.
+    /// - `host`: The name the certificate is verified against.
+    /// - `origin`: Whether `host` is the origin server, which may negotiate HTTP/2 through ALPN
+    ///   and whose session details are recorded.
+    async fn connect_tls_async<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        stream: S,
+        host: &str,
+        origin: bool,
+    ) -> Result<TlsStream<S>, RequestError> {
+        let connector: TlsConnector =
+            TlsConnector::from(self.get_tls_config(origin && self.is_http2(), origin)?);
+        let dns_name: ServerName<'_> = ServerName::try_from(host.to_string())
+            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
+        let tls_stream: TlsStream<S> = self
+            .with_timeout(TimeoutKind::Connect, TLS_HANDSHAKE, async {
+                connector
+                    .connect(dns_name, stream)
+                    .await
+                    .map_err(Self::get_tls_handshake_error)
+            })
+            .await?;
+        if origin {
+            self.check_alpn_protocol(tls_stream.get_ref().1.alpn_protocol())?;
+            self.set_tls_info(Some(TlsInfo::from_connection(tls_stream.get_ref().1)));
+        }
+        Ok(tls_stream)
+    }
+
+    /// Records the TLS session of the connection the current request is sent over.
+    ///
+    /// `None` marks a plaintext connection.
+    fn set_tls_info(&self, tls_info: Option<TlsInfo>) {
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.tls_info = tls_info;
+        }
+    }
+
+    /// Returns the TLS session of the connection the current request is sent over.
+    fn get_tls_info(&self) -> Option<TlsInfo> {
+        self.tmp.read().ok().and_then(|tmp| tmp.tls_info.clone())
+    }
+
+    /// Attaches the TLS session the response was received over to the final response.
+    fn attach_tls_info(&self) {
+        let tls_info: Option<TlsInfo> = self.get_tls_info();
+        if let Ok(response) = self.response.read()
+            && let Ok(mut response_tls_info) = response.tls_info.write()
+        {
+            *response_tls_info = tls_info;
+        }
+    }
+
+    /// Ensures the origin server agreed to HTTP/2 during the TLS handshake when it is required.
+    ///
+    /// # Parameters
+    ///
+    /// - `alpn_protocol`: The protocol selected through ALPN, if any.
+    fn check_alpn_protocol(&self, alpn_protocol: Option<&[u8]>) -> Result<(), RequestError> {
+        if self.is_http2() && alpn_protocol != Some(HTTP2_ALPN) {
+            return Err(RequestError::TlsConnectorBuild(
+                "server did not negotiate HTTP/2 through ALPN".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the pool key identifying connections that can serve this request.
+    fn get_pool_key(&self, host: String, port: u16) -> PoolKey {
+        let (https, proxy) = self.config.read().map_or((false, None), |config| {
+            (Self::get_protocol(&config).is_https(), config.proxy.clone())
+        });
+        let (tls, proxy_tls): (u64, u64) = self.tmp.read().map_or((0, 0), |tmp| {
+            let proxy_tls: u64 = match &proxy {
+                Some(proxy_config) if proxy_config.proxy_type == ProxyType::Https => {
+                    tmp.tls.get_proxy_config().get_fingerprint()
+                }
+                _ => 0,
+            };
+            (tmp.tls.get_fingerprint(), proxy_tls)
+        });
+        PoolKey {
+            https,
+            host,
+            port,
+            proxy,
+            tls,
+            proxy_tls,
+        }
+    }
+
+    /// Returns the time limits of the current send.
+    pub(crate) fn get_timeouts(&self) -> RequestTimeouts {
+        let deadline: Option<Instant> = self.tmp.read().ok().and_then(|tmp| tmp.deadline);
+        self.config.read().map_or(
+            RequestTimeouts::new(&Config::default(), deadline),
+            |config| RequestTimeouts::new(&config, deadline),
+        )
+    }
+
+    /// Returns the request timeout, also used as the maximum wait for a pooled connection.
+    fn get_timeout(&self) -> Duration {
+        Duration::from_millis(
+            self.config
+                .read()
+                .map_or(DEFAULT_TIMEOUT, |config| config.timeout),
+        )
+    }
+
+    /// Checks out an idle connection from the client pool, or opens a new one.
+    ///
+    /// Without a client every request opens its own connection.
+    ///
+    /// # Parameters
+    ///
+    /// - `host`: The hostname or IP address to connect to.
+    /// - `port`: The port number to connect to.
+    /// - `reuse`: Whether an idle connection may be reused.
+    fn get_pooled_connection(
+        &self,
+        host: String,
+        port: u16,
+        reuse: bool,
+    ) -> Result<PooledConnection<BoxReadWrite>, RequestError> {
+        let Some(client) = &self.client else {
+            let stream: BoxReadWrite = self.get_connection_stream(host, port)?;
+            return Ok(PooledConnection::new(stream, None, None));
+        };
+        let key: PoolKey = self.get_pool_key(host.clone(), port);
+        match client.pool.checkout(&key, reuse, self.get_timeout())? {
+            Checkout::Reused(idle_connection) => {
+                self.set_tls_info(idle_connection.tls_info.clone());
+                Ok(PooledConnection::reused(key, idle_connection))
+            }
+            Checkout::New(permit) => {
+                let stream: BoxReadWrite = self.get_connection_stream(host, port)?;
+                let mut connection: PooledConnection<BoxReadWrite> =
+                    PooledConnection::new(stream, Some(key), Some(permit));
+                connection.tls_info = self.get_tls_info();
+                Ok(connection)
+            }
+        }
+    }
+
+    /// Returns a kept-alive connection to the client pool, closing it otherwise.
+    fn release_connection(&self, connection: PooledConnection<BoxReadWrite>) {
+        if !connection.keep_alive {
+            return;
+        }
+        if let (Some(client), Some(key), Some(permit)) =
+            (&self.client, connection.key, connection.permit)
+        {
+            client
+                .pool
+                .release(key, connection.stream, permit, connection.tls_info);
+        }
+    }
+
+    /// Opens a TCP connection within the connect timeout and applies the read and write
+    /// timeouts to the socket.
+    ///
+    /// Every address the host resolves to is tried in turn.
+    ///
+    /// # Parameters
+    ///
+    /// - `host`: The hostname or IP address to connect to.
+    /// - `port`: The port number to connect to.
+    fn connect_tcp(&self, host: &str, port: u16) -> Result<TcpStream, RequestError> {
+        let timeouts: RequestTimeouts = self.get_timeouts();
+        let addrs: Vec<SocketAddr> = (host, port)
+            .to_socket_addrs()
+            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?
+            .collect();
+        let mut error: RequestError =
+            RequestError::TcpStreamConnect(format!("no address found for {}:{}", host, port));
+        for addr in addrs {
+            let connect_timeout: Duration = timeouts.get_duration(TimeoutKind::Connect);
+            if connect_timeout.is_zero() {
+                return Err(timeouts.get_error(TimeoutKind::Connect, CONNECTING));
+            }
+            match TcpStream::connect_timeout(&addr, connect_timeout) {
+                Ok(tcp_stream) => {
+                    tcp_stream
+                        .set_read_timeout(Some(Self::get_socket_timeout(
+                            timeouts.get_duration(TimeoutKind::Read),
+                        )))
+                        .map_err(|err| RequestError::SetReadTimeout(err.to_string()))?;
+                    tcp_stream
+                        .set_write_timeout(Some(Self::get_socket_timeout(
+                            timeouts.get_duration(TimeoutKind::Write),
+                        )))
+                        .map_err(|err| RequestError::SetWriteTimeout(err.to_string()))?;
+                    return Ok(tcp_stream);
+                }
+                Err(err) if err.kind() == ErrorKind::TimedOut => {
+                    error = timeouts.get_error(TimeoutKind::Connect, CONNECTING);
+                }
+                Err(err) => error = RequestError::TcpStreamConnect(err.to_string()),
+            }
+        }
+        Err(error)
+    }
+
+    /// Returns a socket timeout for `duration`, which sockets require to be non-zero.
+    fn get_socket_timeout(duration: Duration) -> Duration {
+        duration.max(Duration::from_millis(1))
+    }
+
+    /// Maps an I/O error of a blocking socket to a `RequestError`, reporting socket timeouts as
+    /// timeout errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `err`: The I/O error.
+    /// - `kind`: Which timeout bounds the step that failed.
+    /// - `step`: What the request was doing, reported on timeout.
+    fn get_io_error(&self, err: std::io::Error, kind: TimeoutKind, step: &str) -> RequestError {
+        match err.kind() {
+            ErrorKind::WouldBlock | ErrorKind::TimedOut => {
+                self.get_timeouts().get_error(kind, step)
+            }
+            _ => RequestError::Request(err.to_string()),
+        }
+    }
+
     /// Establishes a connection stream to the specified host and port.
     ///
     /// This method attempts to create a connection stream based on the protocol type
@@ -466,6 +1700,7 @@ impl HttpRequest {
         host: String,
         port: u16,
     ) -> Result<Box<dyn ReadWrite>, RequestError> {
+        self.set_tls_info(None);
         let config: Config = self
             .config
             .read()
@@ -473,39 +1708,12 @@ impl HttpRequest {
         if let Some(proxy_config) = &config.proxy {
             return self.get_proxy_connection_stream(host, port, proxy_config);
         }
-        let host_port: (String, u16) = (host.clone(), port);
-        let timeout: Duration = Duration::from_millis(config.timeout);
-        let tcp_stream: TcpStream = TcpStream::connect(host_port.clone())
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
-        tcp_stream
-            .set_read_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetReadTimeout(err.to_string()))?;
-        tcp_stream
-            .set_write_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetWriteTimeout(err.to_string()))?;
+        let tcp_stream: TcpStream = self.connect_tcp(&host, port)?;
         let stream: Result<Box<dyn ReadWrite>, RequestError> =
             if Self::get_protocol(&config).is_https() {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => {
-                        let roots: RootCertStore = tmp.root_cert.clone();
-                        let tls_config: ClientConfig = ClientConfig::builder()
-                            .with_root_certificates(roots)
-                            .with_no_client_auth();
-                        let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                        let dns_name: ServerName<'_> = ServerName::try_from(host.clone())
-                            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                        let session: ClientConnection =
-                            ClientConnection::new(Arc::clone(&client_config), dns_name)
-                                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                        let tls_stream: StreamOwned<ClientConnection, TcpStream> =
-                            StreamOwned::new(session, tcp_stream);
-                        return Ok(Box::new(tls_stream));
-                    }
-                    Err(err) => Err(RequestError::Unknown(format!(
-                        "error reading temporary configuration: {}",
-                        err
-                    ))),
-                }
+                let tls_stream: StreamOwned<ClientConnection, TcpStream> =
+                    self.connect_tls(tcp_stream, &host, true)?;
+                return Ok(Box::new(tls_stream));
             } else {
                 Ok(Box::new(tcp_stream))
             };
@@ -519,129 +1727,97 @@ impl HttpRequest {
         target_port: u16,
         proxy_config: &ProxyConfig,
     ) -> Result<Box<dyn ReadWrite>, RequestError> {
-        let timeout: Duration = Duration::from_millis(
-            self.config
-                .read()
-                .map_or(DEFAULT_TIMEOUT, |config| config.timeout),
-        );
         match proxy_config.proxy_type {
             ProxyType::Http | ProxyType::Https => {
-                self.get_http_proxy_connection(target_host, target_port, proxy_config, timeout)
+                self.get_http_proxy_connection(target_host, target_port, proxy_config)
             }
             ProxyType::Socks5 => {
-                self.get_socks5_proxy_connection(target_host, target_port, proxy_config, timeout)
+                self.get_socks5_proxy_connection(target_host, target_port, proxy_config)
             }
         }
     }
 
+    /// Opens the TCP or TLS connection to an HTTP/HTTPS proxy.
+    fn connect_http_proxy(
+        &self,
+        proxy_config: &ProxyConfig,
+    ) -> Result<Box<dyn ReadWrite>, RequestError> {
+        let tcp_stream: TcpStream = self.connect_tcp(&proxy_config.host, proxy_config.port)?;
+        if proxy_config.proxy_type == ProxyType::Https {
+            let tls_stream: StreamOwned<ClientConnection, TcpStream> =
+                self.connect_tls(tcp_stream, &proxy_config.host, false)?;
+            return Ok(Box::new(tls_stream));
+        }
+        Ok(Box::new(tcp_stream))
+    }
+
+    /// Reads the proxy's reply to a CONNECT request, including the body of a failure reply.
+    fn read_proxy_response(
+        &self,
+        proxy_stream: &mut Box<dyn ReadWrite>,
+    ) -> Result<HttpResponseBinary, RequestError> {
+        let mut head: Vec<u8> = Vec::new();
+        let mut byte: [u8; 1] = [0u8; 1];
+        while !head.ends_with(HTTP_DOUBLE_BR_BYTES) && head.len() < PROXY_RESPONSE_HEAD_LIMIT {
+            let bytes_read: usize = proxy_stream
+                .read(&mut byte)
+                .map_err(|err| self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION))?;
+            if bytes_read == 0 {
+                break;
+            }
+            head.push(byte[0]);
+        }
+        let mut response: HttpResponseBinary = Self::parse_proxy_response_head(&head)?;
+        if !Self::is_proxy_tunnel_established(&response) {
+            let mut body: Vec<u8> = Vec::new();
+            let body_length: u64 = Self::get_proxy_response_body_length(&response);
+            let _ = Read::by_ref(proxy_stream)
+                .take(body_length)
+                .read_to_end(&mut body);
+            response.set_body(body);
+        }
+        Ok(response)
+    }
+
     /// Establishes an HTTP/HTTPS proxy connection.
+    ///
+    /// A `407` reply carrying a Digest challenge is answered once on a new connection; the
+    /// challenge is kept so later tunnels through the same proxy authenticate up front.
     fn get_http_proxy_connection(
         &self,
         target_host: String,
         target_port: u16,
         proxy_config: &ProxyConfig,
-        timeout: Duration,
     ) -> Result<Box<dyn ReadWrite>, RequestError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let tcp_stream: TcpStream = TcpStream::connect(proxy_host_port)
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
-        tcp_stream
-            .set_read_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetReadTimeout(err.to_string()))?;
-        tcp_stream
-            .set_write_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetWriteTimeout(err.to_string()))?;
-        let mut proxy_stream: Box<dyn ReadWrite> = if proxy_config.proxy_type == ProxyType::Https {
-            match self.tmp.clone().read() {
-                Ok(tmp) => {
-                    let roots: RootCertStore = tmp.root_cert.clone();
-                    let tls_config: ClientConfig = ClientConfig::builder()
-                        .with_root_certificates(roots)
-                        .with_no_client_auth();
-                    let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                    let dns_name: ServerName<'_> = ServerName::try_from(proxy_config.host.clone())
-                        .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                    let session: ClientConnection =
-                        ClientConnection::new(Arc::clone(&client_config), dns_name)
-                            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                    let tls_stream: StreamOwned<ClientConnection, TcpStream> =
-                        StreamOwned::new(session, tcp_stream);
-                    Box::new(tls_stream)
-                }
-                Err(err) => {
-                    return Err(RequestError::Unknown(format!(
-                        "error reading temporary configuration: {}",
-                        err
-                    )));
-                }
+        let authority: String = format!("{}:{}", target_host, target_port);
+        let mut digest_retried: bool = false;
+        let proxy_stream: Box<dyn ReadWrite> = loop {
+            let mut proxy_stream: Box<dyn ReadWrite> = self.connect_http_proxy(proxy_config)?;
+            let connect_request: String = self.get_proxy_connect_request(&authority, proxy_config);
+            proxy_stream
+                .write_all(connect_request.as_bytes())
+                .map_err(|err| self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION))?;
+            proxy_stream
+                .flush()
+                .map_err(|err| self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION))?;
+            let response: HttpResponseBinary = self.read_proxy_response(&mut proxy_stream)?;
+            if Self::is_proxy_tunnel_established(&response) {
+                break proxy_stream;
             }
-        } else {
-            Box::new(tcp_stream)
-        };
-        let connect_request: String = if let (Some(username), Some(password)) =
-            (&proxy_config.username, &proxy_config.password)
-        {
-            let auth: String = format!("{}:{}", username, password);
-            let auth_encoded: String = base64_encode(auth.as_bytes());
-            format!(
-                "CONNECT {}:{} HTTP/1.1\r\nHost: {}:{}\r\nProxy-Authorization: Basic {}\r\n\r\n",
-                target_host, target_port, target_host, target_port, auth_encoded
-            )
-        } else {
-            format!(
-                "CONNECT {}:{} HTTP/1.1\r\nHost: {}:{}\r\n\r\n",
-                target_host, target_port, target_host, target_port
-            )
+            if digest_retried || !self.prepare_proxy_digest_retry(proxy_config, &response) {
+                return Err(Self::get_proxy_connect_error(&response));
+            }
+            digest_retried = true;
         };
-        proxy_stream
-            .write_all(connect_request.as_bytes())
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        proxy_stream
-            .flush()
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        let mut response_buffer = [0u8; 1024];
-        let bytes_read: usize = proxy_stream
-            .read(&mut response_buffer)
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        let response: Cow<'_, str> = String::from_utf8_lossy(&response_buffer[..bytes_read]);
-        if !response.starts_with("HTTP/1.1 200") && !response.starts_with("HTTP/1.0 200") {
-            return Err(RequestError::Request(format!(
-                "Proxy connection failed: {}",
-                response.lines().next().unwrap_or("Unknown error")
-            )));
-        }
         let config: Config = self
             .config
             .read()
             .map_or(Config::default(), |config| config.clone());
         if Self::get_protocol(&config).is_https() {
-            match self.tmp.clone().read() {
-                Ok(tmp) => {
-                    let roots: RootCertStore = tmp.root_cert.clone();
-                    let tls_config: ClientConfig = ClientConfig::builder()
-                        .with_root_certificates(roots)
-                        .with_no_client_auth();
-                    let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                    let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                        .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                    let session: ClientConnection =
-                        ClientConnection::new(Arc::clone(&client_config), dns_name)
-                            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-
-                    let tunnel_stream = crate::request::SyncProxyTunnelStream::new(proxy_stream);
-                    let tls_stream: StreamOwned<
-                        ClientConnection,
-                        crate::request::SyncProxyTunnelStream,
-                    > = StreamOwned::new(session, tunnel_stream);
-                    return Ok(Box::new(tls_stream));
-                }
-                Err(err) => {
-                    return Err(RequestError::Unknown(format!(
-                        "error reading temporary configuration: {}",
-                        err
-                    )));
-                }
-            }
+            let tunnel_stream: SyncProxyTunnelStream = SyncProxyTunnelStream::new(proxy_stream);
+            let tls_stream: StreamOwned<ClientConnection, SyncProxyTunnelStream> =
+                self.connect_tls(tunnel_stream, &target_host, true)?;
+            return Ok(Box::new(tls_stream));
         }
 
         Ok(proxy_stream)
@@ -653,17 +1829,8 @@ impl HttpRequest {
         target_host: String,
         target_port: u16,
         proxy_config: &ProxyConfig,
-        timeout: Duration,
     ) -> Result<Box<dyn ReadWrite>, RequestError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let mut tcp_stream: TcpStream = TcpStream::connect(proxy_host_port)
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
-        tcp_stream
-            .set_read_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetReadTimeout(err.to_string()))?;
-        tcp_stream
-            .set_write_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetWriteTimeout(err.to_string()))?;
+        let mut tcp_stream: TcpStream = self.connect_tcp(&proxy_config.host, proxy_config.port)?;
         let auth_methods: Vec<u8> =
             if proxy_config.username.is_some() && proxy_config.password.is_some() {
                 vec![0x05, 0x02, 0x00, 0x02]
@@ -672,11 +1839,11 @@ impl HttpRequest {
             };
         tcp_stream
             .write_all(&auth_methods)
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+            .map_err(|err| self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION))?;
         let mut response = [0u8; 2];
         tcp_stream
             .read_exact(&mut response)
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+            .map_err(|err| self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION))?;
         if response[0] != 0x05 {
             return Err(RequestError::Request("Invalid SOCKS5 response".to_string()));
         }
@@ -691,13 +1858,13 @@ impl HttpRequest {
                     auth_request.extend_from_slice(username.as_bytes());
                     auth_request.push(password.len() as u8);
                     auth_request.extend_from_slice(password.as_bytes());
-                    tcp_stream
-                        .write_all(&auth_request)
-                        .map_err(|err| RequestError::Request(err.to_string()))?;
+                    tcp_stream.write_all(&auth_request).map_err(|err| {
+                        self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION)
+                    })?;
                     let mut auth_response: [u8; 2] = [0u8; 2];
-                    tcp_stream
-                        .read_exact(&mut auth_response)
-                        .map_err(|err| RequestError::Request(err.to_string()))?;
+                    tcp_stream.read_exact(&mut auth_response).map_err(|err| {
+                        self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION)
+                    })?;
                     if auth_response[1] != 0x00 {
                         return Err(RequestError::Request(
                             "SOCKS5 authentication failed".to_string(),
@@ -737,11 +1904,11 @@ impl HttpRequest {
         connect_request.extend_from_slice(&target_port.to_be_bytes());
         tcp_stream
             .write_all(&connect_request)
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+            .map_err(|err| self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION))?;
         let mut connect_response: [u8; 4] = [0u8; 4];
         tcp_stream
             .read_exact(&mut connect_response)
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+            .map_err(|err| self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION))?;
         if connect_response[0] != 0x05 || connect_response[1] != 0x00 {
             return Err(RequestError::Request(format!(
                 "SOCKS5 connection failed with code: {}",
@@ -751,25 +1918,25 @@ impl HttpRequest {
         match connect_response[3] {
             0x01 => {
                 let mut skip = [0u8; 6];
-                tcp_stream
-                    .read_exact(&mut skip)
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                tcp_stream.read_exact(&mut skip).map_err(|err| {
+                    self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION)
+                })?;
             }
             0x03 => {
                 let mut len = [0u8; 1];
-                tcp_stream
-                    .read_exact(&mut len)
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                tcp_stream.read_exact(&mut len).map_err(|err| {
+                    self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION)
+                })?;
                 let mut skip = vec![0u8; len[0] as usize + 2];
-                tcp_stream
-                    .read_exact(&mut skip)
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                tcp_stream.read_exact(&mut skip).map_err(|err| {
+                    self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION)
+                })?;
             }
             0x04 => {
                 let mut skip = [0u8; 18];
-                tcp_stream
-                    .read_exact(&mut skip)
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                tcp_stream.read_exact(&mut skip).map_err(|err| {
+                    self.get_io_error(err, TimeoutKind::Connect, PROXY_NEGOTIATION)
+                })?;
             }
             _ => {
                 return Err(RequestError::Request(
@@ -783,41 +1950,20 @@ impl HttpRequest {
             .read()
             .map_or(Config::default(), |config| config.clone());
         if Self::get_protocol(&config).is_https() {
-            match self.tmp.clone().read() {
-                Ok(tmp) => {
-                    let roots: RootCertStore = tmp.root_cert.clone();
-                    let tls_config: ClientConfig = ClientConfig::builder()
-                        .with_root_certificates(roots)
-                        .with_no_client_auth();
-                    let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                    let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                        .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                    let session: ClientConnection =
-                        ClientConnection::new(Arc::clone(&client_config), dns_name)
-                            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-
-                    let tunnel_stream = crate::request::SyncProxyTunnelStream::new(proxy_stream);
-                    let tls_stream: StreamOwned<
-                        ClientConnection,
-                        crate::request::SyncProxyTunnelStream,
-                    > = StreamOwned::new(session, tunnel_stream);
-                    return Ok(Box::new(tls_stream));
-                }
-                Err(err) => {
-                    return Err(RequestError::Unknown(format!(
-                        "error reading temporary configuration: {}",
-                        err
-                    )));
-                }
-            }
+            let tunnel_stream: SyncProxyTunnelStream = SyncProxyTunnelStream::new(proxy_stream);
+            let tls_stream: StreamOwned<ClientConnection, SyncProxyTunnelStream> =
+                self.connect_tls(tunnel_stream, &target_host, true)?;
+            return Ok(Box::new(tls_stream));
         }
         Ok(proxy_stream)
     }
 }
 
 impl HttpRequest {
-    /// Sends the HTTP request synchronously.
-    pub(crate) fn send_sync(&mut self) -> RequestResult {
+    /// Parses the request URL and returns the host and port to connect to.
+    ///
+    /// Returns `Err(RequestError)` if the URL is invalid or the method is not supported.
+    fn resolve_target(&mut self) -> Result<(String, u16), RequestError> {
         let methods: Method = self.get_methods();
         let mut host: String = String::new();
         let mut port: u16 = u16::default();
@@ -828,79 +1974,160 @@ impl HttpRequest {
             host = config.url_obj.host.clone().unwrap_or_default();
             port = self.get_port(config.url_obj.port.clone().unwrap_or_default(), &config);
         }
-        let mut stream: BoxReadWrite = self.get_connection_stream(host, port)?;
-        let res: Result<BoxResponseTrait, RequestError> = match methods {
-            m if m.is_get() => self.send_get_request(&mut stream),
-            m if m.is_post() => self.send_post_request(&mut stream),
-            err => Err(RequestError::Request(format!(
+        if !Self::is_supported_method(&methods) {
+            return Err(RequestError::Request(format!(
                 "do not support {} method",
-                err
-            ))),
+                methods
+            )));
+        }
+        Ok((host, port))
+    }
+
+    /// Sends the HTTP request synchronously.
+    pub(crate) fn send_sync(&mut self) -> RequestResult {
+        let (host, port) = self.resolve_target()?;
+        if self.is_http2() {
+            return self.send_http2_sync();
+        }
+        let mut connection: PooledConnection<BoxReadWrite> =
+            self.get_pooled_connection(host.clone(), port, self.can_resend_body())?;
+        let mut result: Result<Option<Vec<u8>>, RequestError> = self.send_request(&mut connection);
+        if self.is_stale_connection(&connection, &result) {
+            connection = self.get_pooled_connection(host, port, false)?;
+            result = self.send_request(&mut connection);
+        }
+        self.release_connection(connection);
+        let redirect_url: Option<Vec<u8>> = result?;
+        self.handle_response(redirect_url)
+    }
+
+    /// Sends the HTTP request synchronously through its middleware chain.
+    ///
+    /// The chain works on a copy of the request, so changes made by middleware apply to this
+    /// send only.
+    fn send_with_middleware(&mut self) -> RequestResult {
+        let middlewares: Vec<ArcMiddleware> = self.get_middlewares();
+        if middlewares.is_empty() {
+            return self.send_with_retry();
+        }
+        let mut http_request: HttpRequest = self.clone();
+        let (ran, result) = http_request.run_request_middlewares(&middlewares);
+        let result: Result<HttpResponseBinary, RequestError> = match result {
+            Some(result) => result,
+            None => http_request
+                .send_with_retry()
+                .map(|response| response.binary()),
+        };
+        http_request.run_response_middlewares(&middlewares[..ran], result)
+    }
+
+    /// Sends the HTTP request synchronously through its middleware chain and returns as soon as
+    /// the response head is read.
+    ///
+    /// The chain works on a copy of the request, so changes made by middleware apply to this
+    /// send only.
+    fn send_stream_with_middleware(&mut self) -> ResponseStreamResult {
+        let middlewares: Vec<ArcMiddleware> = self.get_middlewares();
+        if middlewares.is_empty() {
+            return self.send_stream_with_retry();
+        }
+        let mut http_request: HttpRequest = self.clone();
+        let (ran, result) = http_request.run_request_middlewares(&middlewares);
+        let result: ResponseStreamResult = match result {
+            Some(result) => result.map(ResponseStream::from_response),
+            None => http_request.send_stream_with_retry(),
+        };
+        http_request.run_stream_response_middlewares(
+            &middlewares[..ran],
+            result,
+            |stream| &mut stream.response,
+            ResponseStream::from_response,
+        )
+    }
+
+    /// Sends the HTTP request synchronously and returns as soon as the response head is read,
+    /// sending it again as allowed by the retry policy.
+    ///
+    /// The response head decides whether the request is retried; the body of a response that
+    /// is retried is discarded unread.
+    fn send_stream_with_retry(&mut self) -> ResponseStreamResult {
+        let Some(retry_policy) = self.get_retry_policy() else {
+            return self.send_stream_sync();
+        };
+        let original: HttpRequest = self.clone();
+        let mut attempt: usize = 1;
+        loop {
+            let result: ResponseStreamResult = self.send_stream_sync();
+            let Some(delay) =
+                self.get_retry_delay(&retry_policy, &original, attempt, result.as_ref().err())
+            else {
+                return result;
+            };
+            drop(result);
+            thread_sleep(delay);
+            self.begin_retry(&original);
+            attempt += 1;
+        }
+    }
+
+    /// Sends the HTTP request synchronously, sending it again as allowed by the retry policy.
+    fn send_with_retry(&mut self) -> RequestResult {
+        let Some(retry_policy) = self.get_retry_policy() else {
+            return self.send_sync();
         };
-        res
+        let original: HttpRequest = self.clone();
+        let mut attempt: usize = 1;
+        loop {
+            let result: RequestResult = self.send_sync();
+            let Some(delay) =
+                self.get_retry_delay(&retry_policy, &original, attempt, result.as_ref().err())
+            else {
+                return result;
+            };
+            thread_sleep(delay);
+            self.begin_retry(&original);
+            attempt += 1;
+        }
     }
 }
 
 /// Async implementation for HttpRequest
 impl HttpRequest {
-    /// Sends an async GET request over the provided stream and returns the HTTP response.
-    async fn send_get_request_async(
-        &mut self,
-        stream: &mut BoxAsyncReadWrite,
-    ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let request: Vec<u8> =
-            SharedRequestBuilder::build_get_request(path, header_bytes, http_version_str);
-        stream
-            .write_all(&request)
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        stream
-            .flush()
+    /// Runs one step of an async request, failing once its timeout or the overall deadline
+    /// elapses.
+    ///
+    /// # Parameters
+    ///
+    /// - `kind`: Which timeout bounds the step.
+    /// - `step`: What the request is doing, such as `connecting`, reported on timeout.
+    /// - `future`: The step.
+    async fn with_timeout<T, F: Future<Output = Result<T, RequestError>>>(
+        &self,
+        kind: TimeoutKind,
+        step: &str,
+        future: F,
+    ) -> Result<T, RequestError> {
+        let timeouts: RequestTimeouts = self.get_timeouts();
+        timeout(timeouts.get_duration(kind), future)
             .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        self.read_response_async(stream).await
+            .unwrap_or_else(|_| Err(timeouts.get_error(kind, step)))
     }
 
-    /// Sends an async POST request over the provided stream and returns the HTTP response.
-    async fn send_post_request_async(
+    /// Sends the request asynchronously over the provided connection and stores the HTTP response.
+    async fn send_request_async(
         &mut self,
-        stream: &mut BoxAsyncReadWrite,
-    ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let body_bytes: Vec<u8> = self.get_body_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let request: Vec<u8> = SharedRequestBuilder::build_post_request(
-            path,
-            header_bytes,
-            body_bytes,
-            http_version_str,
-        );
-        stream
-            .write_all(&request)
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        stream
-            .flush()
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        self.read_response_async(stream).await
+        connection: &mut PooledConnection<BoxAsyncReadWrite>,
+    ) -> Result<Option<Vec<u8>>, RequestError> {
+        self.write_request_async(&mut connection.stream).await?;
+        connection.written = true;
+        self.read_response_async(connection).await
     }
 
     /// Reads an async HTTP response from the provided stream.
     async fn read_response_async(
         &mut self,
-        stream: &mut BoxAsyncReadWrite,
-    ) -> Result<BoxResponseTrait, RequestError> {
+        connection: &mut PooledConnection<BoxAsyncReadWrite>,
+    ) -> Result<Option<Vec<u8>>, RequestError> {
         let buffer_size: usize = self
             .config
             .read()
@@ -909,25 +2136,39 @@ impl HttpRequest {
         let initial_capacity: usize = buffer_size.max(8192);
         let mut response_bytes: Vec<u8> = Vec::with_capacity(initial_capacity);
         let mut headers_done: bool = false;
-        let mut content_length: usize = 0;
+        let mut body_framing: BodyFraming = BodyFraming::UntilClose;
+        let mut chunked_decoder: ChunkedDecoder = ChunkedDecoder::default();
+        let mut body: Vec<u8> = Vec::new();
         let mut redirect_url: Option<Vec<u8>> = None;
-        let mut headers_end_pos: usize = 0;
-        let http_version: String = self
-            .config
-            .read()
-            .map_or(HttpVersion::default().to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let http_version_bytes: Vec<u8> = http_version.to_lowercase().into_bytes();
         let location_sign_key: Vec<u8> = format!("{}:", LOCATION.to_lowercase()).into_bytes();
+        let is_head: bool = self.get_methods().is_head();
+        let mut completed: bool = false;
         'read_loop: loop {
-            let n: usize = stream
-                .read(&mut buffer)
-                .await
-                .map_err(|err| RequestError::Request(err.to_string()))?;
+            let n: usize = self
+                .with_timeout(TimeoutKind::Read, READING_RESPONSE, async {
+                    connection
+                        .stream
+                        .read(&mut buffer)
+                        .await
+                        .map_err(|err| RequestError::Request(err.to_string()))
+                })
+                .await?;
             if n == 0 {
                 break;
             }
+            connection.received = true;
+            if headers_done {
+                if SharedResponseHandler::read_body_bytes(
+                    &body_framing,
+                    &mut chunked_decoder,
+                    &mut body,
+                    &buffer[..n],
+                )? {
+                    completed = true;
+                    break 'read_loop;
+                }
+                continue;
+            }
             let new_capacity: usize = SharedResponseHandler::calculate_buffer_capacity(
                 &response_bytes,
                 n,
@@ -938,33 +2179,53 @@ impl HttpRequest {
             }
             let old_len: usize = response_bytes.len();
             response_bytes.extend_from_slice(&buffer[..n]);
-            if !headers_done {
-                let search_start: usize = old_len.saturating_sub(3);
-                if let Some(pos) =
-                    SharedResponseHandler::find_double_crlf(&response_bytes, search_start)
-                {
-                    headers_done = true;
-                    headers_end_pos = pos + 4;
-                    SharedResponseHandler::parse_response_headers(
-                        &response_bytes[..headers_end_pos],
-                        &http_version_bytes,
-                        &location_sign_key,
-                        &mut content_length,
-                        &mut redirect_url,
-                    )?;
-                }
-            }
-            if headers_done {
-                let total_expected_length: usize = headers_end_pos + content_length;
-                if response_bytes.len() >= total_expected_length {
-                    response_bytes.truncate(total_expected_length);
+            let search_start: usize = old_len.saturating_sub(3);
+            if let Some(pos) =
+                SharedResponseHandler::find_double_crlf(&response_bytes, search_start)
+            {
+                headers_done = true;
+                let headers_end_pos: usize = pos + 4;
+                SharedResponseHandler::parse_response_headers(
+                    &response_bytes[..headers_end_pos],
+                    &location_sign_key,
+                    is_head,
+                    &mut body_framing,
+                    &mut redirect_url,
+                )?;
+                let received_body: Vec<u8> = response_bytes.split_off(headers_end_pos);
+                if SharedResponseHandler::read_body_bytes(
+                    &body_framing,
+                    &mut chunked_decoder,
+                    &mut body,
+                    &received_body,
+                )? {
+                    completed = true;
                     break 'read_loop;
                 }
             }
         }
-        self.response = Arc::new(RwLock::new(<HttpResponseBinary as ResponseTrait>::from(
-            &response_bytes,
-        )));
+        let response: HttpResponseBinary = if headers_done {
+            SharedResponseHandler::build_response(&response_bytes, &chunked_decoder, body)
+        } else {
+            <HttpResponseBinary as ResponseTrait>::from(&response_bytes)
+        };
+        connection.keep_alive = completed
+            && body_framing != BodyFraming::UntilClose
+            && SharedResponseHandler::is_keep_alive(&response_bytes)
+            && self
+                .config
+                .read()
+                .is_ok_and(|config| config.http_version == HttpVersion::HTTP1_1);
+        self.response = Arc::new(RwLock::new(response));
+        Ok(redirect_url)
+    }
+
+    /// Decodes the stored response or follows its redirect location asynchronously.
+    async fn handle_response_async(&mut self, redirect_url: Option<Vec<u8>>) -> RequestResult {
+        self.store_response_cookies();
+        if self.prepare_digest_retry() {
+            return self.handle_digest_challenge_async().await;
+        }
         let (should_redirect, should_decode, buffer_size) = {
             if let Ok(config) = self.config.read() {
                 (config.redirect, config.decode, config.buffer)
@@ -973,6 +2234,8 @@ impl HttpRequest {
             }
         };
         if !should_redirect || redirect_url.is_none() {
+            self.attach_redirect_history();
+            self.attach_tls_info();
             if should_decode {
                 if let Ok(mut response) = self.response.write() {
                     *response = response.decode(buffer_size);
@@ -995,34 +2258,71 @@ impl HttpRequest {
         url: String,
     ) -> Pin<Box<dyn Future<Output = Result<BoxResponseTrait, RequestError>> + Send + '_>> {
         Box::pin(async move {
-            {
-                if let Ok(mut config) = self.config.write() {
-                    if !config.redirect {
-                        return Err(RequestError::NeedOpenRedirect);
-                    }
-                    if let Ok(mut tmp) = self.tmp.clone().write() {
-                        if tmp.visit_url.contains(&url) {
-                            return Err(RequestError::RedirectUrlDeadLoop);
-                        }
-                        tmp.visit_url.insert(url.clone());
-                        if config.redirect_times >= config.max_redirect_times {
-                            return Err(RequestError::MaxRedirectTimes);
-                        }
-                        config.redirect_times += 1;
-                    }
-                }
-            }
-            self.url(url.clone());
+            self.prepare_redirect(url)?;
             self.send_async().await
         })
     }
 
+    /// Sends the request again asynchronously to answer a Digest challenge.
+    fn handle_digest_challenge_async(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<BoxResponseTrait, RequestError>> + Send + '_>> {
+        Box::pin(self.send_async())
+    }
+
+    /// Checks out an idle async connection from the client pool, or opens a new one.
+    async fn get_pooled_connection_async(
+        &self,
+        host: String,
+        port: u16,
+        reuse: bool,
+    ) -> Result<PooledConnection<BoxAsyncReadWrite>, RequestError> {
+        let Some(client) = self.client.clone() else {
+            let stream: BoxAsyncReadWrite = self.get_connection_stream_async(host, port).await?;
+            return Ok(PooledConnection::new(stream, None, None));
+        };
+        let key: PoolKey = self.get_pool_key(host.clone(), port);
+        match client
+            .pool
+            .checkout_async(&key, reuse, self.get_timeout())
+            .await?
+        {
+            Checkout::Reused(idle_connection) => {
+                self.set_tls_info(idle_connection.tls_info.clone());
+                Ok(PooledConnection::reused(key, idle_connection))
+            }
+            Checkout::New(permit) => {
+                let stream: BoxAsyncReadWrite =
+                    self.get_connection_stream_async(host, port).await?;
+                let mut connection: PooledConnection<BoxAsyncReadWrite> =
+                    PooledConnection::new(stream, Some(key), Some(permit));
+                connection.tls_info = self.get_tls_info();
+                Ok(connection)
+            }
+        }
+    }
+
+    /// Returns a kept-alive async connection to the client pool, closing it otherwise.
+    fn release_connection_async(&self, connection: PooledConnection<BoxAsyncReadWrite>) {
+        if !connection.keep_alive {
+            return;
+        }
+        if let (Some(client), Some(key), Some(permit)) =
+            (&self.client, connection.key, connection.permit)
+        {
+            client
+                .pool
+                .release_async(key, connection.stream, permit, connection.tls_info);
+        }
+    }
+
     /// Establishes an async connection stream to the specified host and port.
     async fn get_connection_stream_async(
         &self,
         host: String,
         port: u16,
     ) -> Result<BoxAsyncReadWrite, RequestError> {
+        self.set_tls_info(None);
         let config: Config = self
             .config
             .read()
@@ -1033,31 +2333,16 @@ impl HttpRequest {
                 .await;
         }
         let host_port: (String, u16) = (host.clone(), port);
-        let tcp_stream: AsyncTcpStream = AsyncTcpStream::connect(host_port.clone())
-            .await
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
+        let tcp_stream: AsyncTcpStream = self
+            .with_timeout(TimeoutKind::Connect, CONNECTING, async {
+                AsyncTcpStream::connect(host_port)
+                    .await
+                    .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))
+            })
+            .await?;
         if Self::get_protocol(&config).is_https() {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(err) => {
-                        return Err(RequestError::Unknown(format!(
-                            "error reading temporary configuration: {}",
-                            err
-                        )));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(host.clone())
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            let tls_stream: TlsStream<AsyncTcpStream> = connector
-                .connect(dns_name, tcp_stream)
-                .await
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
+            let tls_stream: TlsStream<AsyncTcpStream> =
+                self.connect_tls_async(tcp_stream, &host, true).await?;
             Ok(Box::new(tls_stream))
         } else {
             Ok(Box::new(tcp_stream))
@@ -1083,106 +2368,115 @@ impl HttpRequest {
         }
     }
 
+    /// Opens the async TCP or TLS connection to an HTTP/HTTPS proxy.
+    async fn connect_http_proxy_async(
+        &self,
+        proxy_config: &ProxyConfig,
+    ) -> Result<BoxAsyncReadWrite, RequestError> {
+        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
+        let tcp_stream: AsyncTcpStream = self
+            .with_timeout(TimeoutKind::Connect, CONNECTING, async {
+                AsyncTcpStream::connect(proxy_host_port)
+                    .await
+                    .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))
+            })
+            .await?;
+        if proxy_config.proxy_type == ProxyType::Https {
+            let tls_stream: TlsStream<AsyncTcpStream> = self
+                .connect_tls_async(tcp_stream, &proxy_config.host, false)
+                .await?;
+            return Ok(Box::new(tls_stream));
+        }
+        Ok(Box::new(tcp_stream))
+    }
+
+    /// Sends a CONNECT request asynchronously and reads the proxy's reply, including the body of
+    /// a failure reply.
+    async fn send_proxy_connect_async(
+        &self,
+        proxy_stream: &mut BoxAsyncReadWrite,
+        connect_request: &str,
+    ) -> Result<HttpResponseBinary, RequestError> {
+        let head: Vec<u8> = self
+            .with_timeout(TimeoutKind::Connect, PROXY_NEGOTIATION, async {
+                proxy_stream
+                    .write_all(connect_request.as_bytes())
+                    .await
+                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                proxy_stream
+                    .flush()
+                    .await
+                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                let mut head: Vec<u8> = Vec::new();
+                let mut byte: [u8; 1] = [0u8; 1];
+                while !head.ends_with(HTTP_DOUBLE_BR_BYTES)
+                    && head.len() < PROXY_RESPONSE_HEAD_LIMIT
+                {
+                    let bytes_read: usize = proxy_stream
+                        .read(&mut byte)
+                        .await
+                        .map_err(|err| RequestError::Request(err.to_string()))?;
+                    if bytes_read == 0 {
+                        break;
+                    }
+                    head.push(byte[0]);
+                }
+                Ok(head)
+            })
+            .await?;
+        let mut response: HttpResponseBinary = Self::parse_proxy_response_head(&head)?;
+        if !Self::is_proxy_tunnel_established(&response) {
+            let mut body: Vec<u8> = Vec::new();
+            let body_length: u64 = Self::get_proxy_response_body_length(&response);
+            let _ = self
+                .with_timeout(TimeoutKind::Connect, PROXY_NEGOTIATION, async {
+                    AsyncReadExt::take(proxy_stream, body_length)
+                        .read_to_end(&mut body)
+                        .await
+                        .map_err(|err| RequestError::Request(err.to_string()))
+                })
+                .await;
+            response.set_body(body);
+        }
+        Ok(response)
+    }
+
     /// Establishes an async HTTP/HTTPS proxy connection.
+    ///
+    /// A `407` reply carrying a Digest challenge is answered once on a new connection, as in
+    /// `get_http_proxy_connection`.
     async fn get_http_proxy_connection_async(
         &self,
         target_host: String,
         target_port: u16,
         proxy_config: &ProxyConfig,
     ) -> Result<BoxAsyncReadWrite, RequestError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let tcp_stream: AsyncTcpStream = AsyncTcpStream::connect(proxy_host_port)
-            .await
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
-        let mut proxy_stream: BoxAsyncReadWrite = if proxy_config.proxy_type == ProxyType::Https {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(err) => {
-                        return Err(RequestError::Unknown(format!(
-                            "error reading temporary configuration: {}",
-                            err
-                        )));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(proxy_config.host.clone())
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            let tls_stream: TlsStream<AsyncTcpStream> = connector
-                .connect(dns_name, tcp_stream)
-                .await
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            Box::new(tls_stream)
-        } else {
-            Box::new(tcp_stream)
-        };
-        let connect_request: String = if let (Some(username), Some(password)) =
-            (&proxy_config.username, &proxy_config.password)
-        {
-            let auth: String = format!("{}:{}", username, password);
-            let auth_encoded: String = base64_encode(auth.as_bytes());
-            format!(
-                "CONNECT {}:{} HTTP/1.1\r\nHost: {}:{}\r\nProxy-Authorization: Basic {}\r\n\r\n",
-                target_host, target_port, target_host, target_port, auth_encoded
-            )
-        } else {
-            format!(
-                "CONNECT {}:{} HTTP/1.1\r\nHost: {}:{}\r\n\r\n",
-                target_host, target_port, target_host, target_port
-            )
+        let authority: String = format!("{}:{}", target_host, target_port);
+        let mut digest_retried: bool = false;
+        let proxy_stream: BoxAsyncReadWrite = loop {
+            let mut proxy_stream: BoxAsyncReadWrite =
+                self.connect_http_proxy_async(proxy_config).await?;
+            let connect_request: String = self.get_proxy_connect_request(&authority, proxy_config);
+            let response: HttpResponseBinary = self
+                .send_proxy_connect_async(&mut proxy_stream, &connect_request)
+                .await?;
+            if Self::is_proxy_tunnel_established(&response) {
+                break proxy_stream;
+            }
+            if digest_retried || !self.prepare_proxy_digest_retry(proxy_config, &response) {
+                return Err(Self::get_proxy_connect_error(&response));
+            }
+            digest_retried = true;
         };
-        proxy_stream
-            .write_all(connect_request.as_bytes())
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        proxy_stream
-            .flush()
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        let mut response_buffer: [u8; 1024] = [0u8; 1024];
-        let bytes_read: usize = proxy_stream
-            .read(&mut response_buffer)
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        let response: Cow<'_, str> = String::from_utf8_lossy(&response_buffer[..bytes_read]);
-        if !response.starts_with("HTTP/1.1 200") && !response.starts_with("HTTP/1.0 200") {
-            return Err(RequestError::Request(format!(
-                "Proxy connection failed: {}",
-                response.lines().next().unwrap_or("Unknown error")
-            )));
-        }
         let config: Config = self
             .config
             .read()
             .map_or(Config::default(), |config| config.clone());
         if Self::get_protocol(&config).is_https() {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(err) => {
-                        return Err(RequestError::Unknown(format!(
-                            "error reading temporary configuration: {}",
-                            err
-                        )));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            let tunnel_stream: crate::request::ProxyTunnelStream =
-                crate::request::ProxyTunnelStream::new(proxy_stream);
-            let tls_stream: TlsStream<crate::request::ProxyTunnelStream> = connector
-                .connect(dns_name, tunnel_stream)
-                .await
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
+            let tunnel_stream: ProxyTunnelStream = ProxyTunnelStream::new(proxy_stream);
+            let tls_stream: TlsStream<ProxyTunnelStream> = self
+                .connect_tls_async(tunnel_stream, &target_host, true)
+                .await?;
             return Ok(Box::new(tls_stream));
         }
 
@@ -1197,196 +2491,875 @@ impl HttpRequest {
         proxy_config: &ProxyConfig,
     ) -> Result<BoxAsyncReadWrite, RequestError> {
         let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let mut tcp_stream: AsyncTcpStream = AsyncTcpStream::connect(proxy_host_port)
-            .await
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
-        let auth_methods: Vec<u8> =
-            if proxy_config.username.is_some() && proxy_config.password.is_some() {
-                vec![0x05, 0x02, 0x00, 0x02]
+        let mut tcp_stream: AsyncTcpStream = self
+            .with_timeout(TimeoutKind::Connect, CONNECTING, async {
+                AsyncTcpStream::connect(proxy_host_port)
+                    .await
+                    .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))
+            })
+            .await?;
+        self.with_timeout(TimeoutKind::Connect, PROXY_NEGOTIATION, async {
+            let auth_methods: Vec<u8> =
+                if proxy_config.username.is_some() && proxy_config.password.is_some() {
+                    vec![0x05, 0x02, 0x00, 0x02]
+                } else {
+                    vec![0x05, 0x01, 0x00]
+                };
+            tcp_stream
+                .write_all(&auth_methods)
+                .await
+                .map_err(|err| RequestError::Request(err.to_string()))?;
+            let mut response: [u8; 2] = [0u8; 2];
+            tcp_stream
+                .read_exact(&mut response)
+                .await
+                .map_err(|err| RequestError::Request(err.to_string()))?;
+            if response[0] != 0x05 {
+                return Err(RequestError::Request("Invalid SOCKS5 response".to_string()));
+            }
+            match response[1] {
+                0x00 => {}
+                0x02 => {
+                    if let (Some(username), Some(password)) =
+                        (&proxy_config.username, &proxy_config.password)
+                    {
+                        let mut auth_request = vec![0x01];
+                        auth_request.push(username.len() as u8);
+                        auth_request.extend_from_slice(username.as_bytes());
+                        auth_request.push(password.len() as u8);
+                        auth_request.extend_from_slice(password.as_bytes());
+
+                        tcp_stream
+                            .write_all(&auth_request)
+                            .await
+                            .map_err(|err| RequestError::Request(err.to_string()))?;
+
+                        let mut auth_response = [0u8; 2];
+                        tcp_stream
+                            .read_exact(&mut auth_response)
+                            .await
+                            .map_err(|err| RequestError::Request(err.to_string()))?;
+
+                        if auth_response[1] != 0x00 {
+                            return Err(RequestError::Request(
+                                "SOCKS5 authentication failed".to_string(),
+                            ));
+                        }
+                    } else {
+                        return Err(RequestError::Request(
+                            "SOCKS5 proxy requires authentication".to_string(),
+                        ));
+                    }
+                }
+                0xFF => {
+                    return Err(RequestError::Request(
+                        "No acceptable SOCKS5 authentication methods".to_string(),
+                    ));
+                }
+                _ => {
+                    return Err(RequestError::Request(
+                        "Unsupported SOCKS5 authentication method".to_string(),
+                    ));
+                }
+            }
+            let mut connect_request: Vec<u8> = vec![0x05, 0x01, 0x00];
+            if target_host.parse::<Ipv4Addr>().is_ok() {
+                connect_request.push(0x01);
+                let ip: Ipv4Addr = target_host.parse().unwrap();
+                connect_request.extend_from_slice(&ip.octets());
+            } else if target_host.parse::<Ipv6Addr>().is_ok() {
+                connect_request.push(0x04);
+                let ip: Ipv6Addr = target_host.parse().unwrap();
+                connect_request.extend_from_slice(&ip.octets());
             } else {
-                vec![0x05, 0x01, 0x00]
-            };
-        tcp_stream
-            .write_all(&auth_methods)
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        let mut response: [u8; 2] = [0u8; 2];
-        tcp_stream
-            .read_exact(&mut response)
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        if response[0] != 0x05 {
-            return Err(RequestError::Request("Invalid SOCKS5 response".to_string()));
-        }
-        match response[1] {
-            0x00 => {}
-            0x02 => {
-                if let (Some(username), Some(password)) =
-                    (&proxy_config.username, &proxy_config.password)
-                {
-                    let mut auth_request = vec![0x01];
-                    auth_request.push(username.len() as u8);
-                    auth_request.extend_from_slice(username.as_bytes());
-                    auth_request.push(password.len() as u8);
-                    auth_request.extend_from_slice(password.as_bytes());
+                connect_request.push(0x03);
+                connect_request.push(target_host.len() as u8);
+                connect_request.extend_from_slice(target_host.as_bytes());
+            }
+            connect_request.extend_from_slice(&target_port.to_be_bytes());
+            tcp_stream
+                .write_all(&connect_request)
+                .await
+                .map_err(|err| RequestError::Request(err.to_string()))?;
 
+            let mut connect_response: [u8; 4] = [0u8; 4];
+            tcp_stream
+                .read_exact(&mut connect_response)
+                .await
+                .map_err(|err| RequestError::Request(err.to_string()))?;
+
+            if connect_response[0] != 0x05 || connect_response[1] != 0x00 {
+                return Err(RequestError::Request(format!(
+                    "SOCKS5 connection failed with code: {}",
+                    connect_response[1]
+                )));
+            }
+            match connect_response[3] {
+                0x01 => {
+                    let mut skip: [u8; 6] = [0u8; 6];
                     tcp_stream
-                        .write_all(&auth_request)
+                        .read_exact(&mut skip)
                         .await
                         .map_err(|err| RequestError::Request(err.to_string()))?;
-
-                    let mut auth_response = [0u8; 2];
+                }
+                0x03 => {
+                    let mut len: [u8; 1] = [0u8; 1];
                     tcp_stream
-                        .read_exact(&mut auth_response)
+                        .read_exact(&mut len)
                         .await
                         .map_err(|err| RequestError::Request(err.to_string()))?;
-
-                    if auth_response[1] != 0x00 {
-                        return Err(RequestError::Request(
-                            "SOCKS5 authentication failed".to_string(),
-                        ));
-                    }
-                } else {
+                    let mut skip: Vec<u8> = vec![0u8; len[0] as usize + 2];
+                    tcp_stream
+                        .read_exact(&mut skip)
+                        .await
+                        .map_err(|err| RequestError::Request(err.to_string()))?;
+                }
+                0x04 => {
+                    let mut skip: [u8; 18] = [0u8; 18];
+                    tcp_stream
+                        .read_exact(&mut skip)
+                        .await
+                        .map_err(|err| RequestError::Request(err.to_string()))?;
+                }
+                _ => {
                     return Err(RequestError::Request(
-                        "SOCKS5 proxy requires authentication".to_string(),
+                        "Invalid SOCKS5 address type".to_string(),
                     ));
                 }
             }
-            0xFF => {
-                return Err(RequestError::Request(
-                    "No acceptable SOCKS5 authentication methods".to_string(),
-                ));
+            Ok(())
+        })
+        .await?;
+        let proxy_stream: BoxAsyncReadWrite = Box::new(tcp_stream);
+        let config: Config = self
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        if Self::get_protocol(&config).is_https() {
+            let tunnel_stream: ProxyTunnelStream = ProxyTunnelStream::new(proxy_stream);
+            let tls_stream: TlsStream<ProxyTunnelStream> = self
+                .connect_tls_async(tunnel_stream, &target_host, true)
+                .await?;
+            return Ok(Box::new(tls_stream));
+        }
+
+        Ok(proxy_stream)
+    }
+
+    /// Sends the HTTP request asynchronously.
+    pub(crate) async fn send_async(&mut self) -> RequestResult {
+        let (host, port) = self.resolve_target()?;
+        if self.is_http2() {
+            let redirect_url: Option<Vec<u8>> = self.send_http2_request(host, port).await?;
+            return self.handle_response_async(redirect_url).await;
+        }
+        let mut connection: PooledConnection<BoxAsyncReadWrite> = self
+            .get_pooled_connection_async(host.clone(), port, self.can_resend_body())
+            .await?;
+        let mut result: Result<Option<Vec<u8>>, RequestError> =
+            self.send_request_async(&mut connection).await;
+        if self.is_stale_connection(&connection, &result) {
+            connection = self.get_pooled_connection_async(host, port, false).await?;
+            result = self.send_request_async(&mut connection).await;
+        }
+        self.release_connection_async(connection);
+        let redirect_url: Option<Vec<u8>> = result?;
+        self.handle_response_async(redirect_url).await
+    }
+
+    /// Sends the HTTP request asynchronously through its middleware chain.
+    ///
+    /// The chain works on a copy of the request, so changes made by middleware apply to this
+    /// send only.
+    async fn send_with_middleware_async(&mut self) -> RequestResult {
+        let middlewares: Vec<ArcMiddleware> = self.get_middlewares();
+        if middlewares.is_empty() {
+            return self.send_with_retry_async().await;
+        }
+        let mut http_request: HttpRequest = self.clone();
+        let (ran, result) = http_request.run_request_middlewares(&middlewares);
+        let result: Result<HttpResponseBinary, RequestError> = match result {
+            Some(result) => result,
+            None => http_request
+                .send_with_retry_async()
+                .await
+                .map(|response| response.binary()),
+        };
+        http_request.run_response_middlewares(&middlewares[..ran], result)
+    }
+
+    /// Sends the HTTP request asynchronously through its middleware chain and returns as soon
+    /// as the response head is read.
+    ///
+    /// The chain works on a copy of the request, so changes made by middleware apply to this
+    /// send only.
+    async fn send_stream_with_middleware_async(&mut self) -> AsyncResponseStreamResult {
+        let middlewares: Vec<ArcMiddleware> = self.get_middlewares();
+        if middlewares.is_empty() {
+            return self.send_stream_with_retry_async().await;
+        }
+        let timeouts: RequestTimeouts = self.get_timeouts();
+        let from_response = |response: HttpResponseBinary| -> AsyncResponseStream {
+            AsyncResponseStream::from_response(response, timeouts)
+        };
+        let mut http_request: HttpRequest = self.clone();
+        let (ran, result) = http_request.run_request_middlewares(&middlewares);
+        let result: AsyncResponseStreamResult = match result {
+            Some(result) => result.map(from_response),
+            None => http_request.send_stream_with_retry_async().await,
+        };
+        http_request.run_stream_response_middlewares(
+            &middlewares[..ran],
+            result,
+            |stream| &mut stream.response,
+            from_response,
+        )
+    }
+
+    /// Sends the HTTP request asynchronously and returns as soon as the response head is read,
+    /// sending it again as allowed by the retry policy.
+    ///
+    /// The response head decides whether the request is retried; the body of a response that
+    /// is retried is discarded unread.
+    async fn send_stream_with_retry_async(&mut self) -> AsyncResponseStreamResult {
+        let Some(retry_policy) = self.get_retry_policy() else {
+            return self.send_stream_async().await;
+        };
+        let original: HttpRequest = self.clone();
+        let mut attempt: usize = 1;
+        loop {
+            let result: AsyncResponseStreamResult = self.send_stream_async().await;
+            let Some(delay) =
+                self.get_retry_delay(&retry_policy, &original, attempt, result.as_ref().err())
+            else {
+                return result;
+            };
+            drop(result);
+            sleep(delay).await;
+            self.begin_retry(&original);
+            attempt += 1;
+        }
+    }
+
+    /// Sends the HTTP request asynchronously, sending it again as allowed by the retry policy.
+    async fn send_with_retry_async(&mut self) -> RequestResult {
+        let Some(retry_policy) = self.get_retry_policy() else {
+            return self.send_async().await;
+        };
+        let original: HttpRequest = self.clone();
+        let mut attempt: usize = 1;
+        loop {
+            let result: RequestResult = self.send_async().await;
+            let Some(delay) =
+                self.get_retry_delay(&retry_policy, &original, attempt, result.as_ref().err())
+            else {
+                return result;
+            };
+            sleep(delay).await;
+            self.begin_retry(&original);
+            attempt += 1;
+        }
+    }
+}
+
+/// HTTP/2 implementation for HttpRequest
+impl HttpRequest {
+    /// Sends the HTTP/2 request synchronously by driving the async implementation to completion.
+    ///
+    /// Requests made through a client share its runtime so that its HTTP/2 connections stay open
+    /// between calls; otherwise a runtime is created for the duration of the request. Called from
+    /// async code, the request is driven from a separate thread.
+    fn send_http2_sync(&mut self) -> RequestResult {
+        let runtime: StreamRuntime = StreamRuntime::new(self.client.clone())?;
+        runtime.block_on(self.send_async())?
+    }
+
+    /// Opens a new HTTP/2 connection and spawns the task driving it.
+    ///
+    /// Plaintext connections use prior knowledge (h2c); TLS connections must negotiate `h2` through ALPN.
+    async fn connect_http2(
+        &self,
+        host: String,
+        port: u16,
+    ) -> Result<SendRequest<Bytes>, RequestError> {
+        let stream: BoxAsyncReadWrite = self.get_connection_stream_async(host, port).await?;
+        let (send_request, connection) = self
+            .with_timeout(TimeoutKind::Connect, HTTP2_HANDSHAKE, async {
+                h2::client::handshake(stream)
+                    .await
+                    .map_err(|err| RequestError::Request(err.to_string()))
+            })
+            .await?;
+        tokio::spawn(async move {
+            let _ = connection.await;
+        });
+        Ok(send_request)
+    }
+
+    /// Returns a ready handle to an HTTP/2 connection for the specified host and port.
+    ///
+    /// Requests made through a client multiplex over a single shared connection per origin.
+    async fn get_http2_connection(
+        &self,
+        host: String,
+        port: u16,
+    ) -> Result<SendRequest<Bytes>, RequestError> {
+        let client: Client = match &self.client {
+            Some(client) => client.clone(),
+            None => {
+                return self
+                    .connect_http2(host, port)
+                    .await?
+                    .ready()
+                    .await
+                    .map_err(|err| RequestError::Request(err.to_string()));
             }
-            _ => {
-                return Err(RequestError::Request(
-                    "Unsupported SOCKS5 authentication method".to_string(),
-                ));
+        };
+        let key: PoolKey = self.get_pool_key(host.clone(), port);
+        let lock: Arc<AsyncMutex<()>> = client.pool.get_http2_lock(&key);
+        let _guard: AsyncMutexGuard<'_, ()> = lock.lock().await;
+        if let Some((send_request, tls_info)) = client.pool.get_http2(&key) {
+            match send_request.ready().await {
+                Ok(send_request) => {
+                    self.set_tls_info(tls_info);
+                    return Ok(send_request);
+                }
+                Err(_) => client.pool.remove_http2(&key),
             }
         }
-        let mut connect_request: Vec<u8> = vec![0x05, 0x01, 0x00];
-        if target_host.parse::<Ipv4Addr>().is_ok() {
-            connect_request.push(0x01);
-            let ip: Ipv4Addr = target_host.parse().unwrap();
-            connect_request.extend_from_slice(&ip.octets());
-        } else if target_host.parse::<Ipv6Addr>().is_ok() {
-            connect_request.push(0x04);
-            let ip: Ipv6Addr = target_host.parse().unwrap();
-            connect_request.extend_from_slice(&ip.octets());
+        let permit: ConnectionPermit = client.pool.reserve_async(&key, self.get_timeout()).await?;
+        let send_request: SendRequest<Bytes> = self.connect_http2(host, port).await?;
+        client
+            .pool
+            .put_http2(key, send_request.clone(), permit, self.get_tls_info());
+        send_request
+            .ready()
+            .await
+            .map_err(|err| RequestError::Request(err.to_string()))
+    }
+
+    /// Builds the HTTP/2 request head.
+    ///
+    /// The `Host` header becomes the `:authority` pseudo-header and connection-specific headers,
+    /// which HTTP/2 forbids, are left out.
+    fn get_http2_request(&self) -> Result<Request, RequestError> {
+        let (https, host, port) =
+            self.config
+                .read()
+                .map_or((false, String::new(), None), |config| {
+                    (
+                        Self::get_protocol(&config).is_https(),
+                        config.url_obj.host.clone().unwrap_or_default(),
+                        config.url_obj.port,
+                    )
+                });
+        let mut authority: String = if host.contains(':') {
+            format!("[{}]", host)
         } else {
-            connect_request.push(0x03);
-            connect_request.push(target_host.len() as u8);
-            connect_request.extend_from_slice(target_host.as_bytes());
+            host
+        };
+        if let Some(port) = port {
+            authority.push_str(&format!(":{}", port));
         }
-        connect_request.extend_from_slice(&target_port.to_be_bytes());
-        tcp_stream
-            .write_all(&connect_request)
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+        let scheme: &str = if https { HTTPS_SCHEME } else { HTTP_SCHEME };
+        let uri: String = format!("{}://{}{}", scheme, authority, self.get_path());
+        let mut request_builder = Request::builder()
+            .method(self.get_methods().to_string().as_str())
+            .uri(uri)
+            .version(Version::HTTP_2);
+        for (key, value) in self.get_request_headers() {
+            if HTTP2_CONNECTION_HEADERS
+                .iter()
+                .any(|header| key.eq_ignore_ascii_case(header))
+            {
+                continue;
+            }
+            request_builder = request_builder.header(key.to_lowercase(), value);
+        }
+        request_builder
+            .body(())
+            .map_err(|err| RequestError::Request(err.to_string()))
+    }
 
-        let mut connect_response: [u8; 4] = [0u8; 4];
-        tcp_stream
-            .read_exact(&mut connect_response)
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+    /// Sends request body data on an HTTP/2 stream, honouring the peer's flow control window.
+    ///
+    /// The stream is ended with the last of the data when `end_of_stream` is set.
+    async fn send_http2_body(
+        send_stream: &mut SendStream<Bytes>,
+        mut body: Bytes,
+        end_of_stream: bool,
+    ) -> Result<(), RequestError> {
+        while !body.is_empty() {
+            send_stream.reserve_capacity(body.len());
+            let capacity: usize = match poll_fn(|cx| send_stream.poll_capacity(cx)).await {
+                Some(Ok(capacity)) => capacity,
+                Some(Err(err)) => return Err(RequestError::Request(err.to_string())),
+                None => {
+                    return Err(RequestError::Request(
+                        "HTTP/2 stream closed while sending the request body".to_string(),
+                    ));
+                }
+            };
+            let chunk: Bytes = body.split_to(capacity.min(body.len()));
+            send_stream
+                .send_data(chunk, end_of_stream && body.is_empty())
+                .map_err(|err| RequestError::Request(err.to_string()))?;
+        }
+        Ok(())
+    }
 
-        if connect_response[0] != 0x05 || connect_response[1] != 0x00 {
-            return Err(RequestError::Request(format!(
-                "SOCKS5 connection failed with code: {}",
-                connect_response[1]
-            )));
+    /// Sends a streamed request body on an HTTP/2 stream as it is read from its source.
+    async fn send_http2_body_stream(
+        &self,
+        send_stream: &mut SendStream<Bytes>,
+        body_stream: BodyStream,
+    ) -> Result<(), RequestError> {
+        let mut body_reader: BodyReader = body_stream.open_async().await?;
+        let mut buffer: Vec<u8> = vec![0; self.get_buffer_size()];
+        loop {
+            let n: usize = body_reader.read_async(&mut buffer).await?;
+            if n == 0 {
+                return send_stream
+                    .send_data(Bytes::new(), true)
+                    .map_err(|err| RequestError::Request(err.to_string()));
+            }
+            Self::send_http2_body(send_stream, Bytes::copy_from_slice(&buffer[..n]), false).await?;
         }
-        match connect_response[3] {
-            0x01 => {
-                let mut skip: [u8; 6] = [0u8; 6];
-                tcp_stream
-                    .read_exact(&mut skip)
-                    .await
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+    }
+
+    /// Sends the request over HTTP/2 and returns the response head and body stream.
+    async fn send_http2_head(
+        &mut self,
+        host: String,
+        port: u16,
+    ) -> Result<(ResponseParts, RecvStream), RequestError> {
+        let mut send_request: SendRequest<Bytes> = self.get_http2_connection(host, port).await?;
+        let request: Request = self.get_http2_request()?;
+        let body_stream: Option<BodyStream> = self.get_body_stream();
+        let body: Vec<u8> = self.get_request_body_bytes().unwrap_or_default();
+        let (response_future, mut send_stream): (ResponseFuture, SendStream<Bytes>) = send_request
+            .send_request(request, body.is_empty() && body_stream.is_none())
+            .map_err(|err| RequestError::Request(err.to_string()))?;
+        match body_stream {
+            Some(body_stream) => {
+                self.send_http2_body_stream(&mut send_stream, body_stream)
+                    .await?
             }
-            0x03 => {
-                let mut len: [u8; 1] = [0u8; 1];
-                tcp_stream
-                    .read_exact(&mut len)
+            None => Self::send_http2_body(&mut send_stream, Bytes::from(body), true).await?,
+        }
+        let response: Response<RecvStream> = self
+            .with_timeout(TimeoutKind::Read, READING_RESPONSE, async {
+                response_future
                     .await
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
-                let mut skip: Vec<u8> = vec![0u8; len[0] as usize + 2];
-                tcp_stream
-                    .read_exact(&mut skip)
+                    .map_err(|err| RequestError::Request(err.to_string()))
+            })
+            .await?;
+        Ok(response.into_parts())
+    }
+
+    /// Renders an HTTP/2 response head, and any trailers, in HTTP/1 syntax with an `HTTP/2`
+    /// status line, so the rest of the response handling is shared with HTTP/1.
+    fn get_http2_headers_bytes(parts: &ResponseParts, trailers: Option<&HeaderMap>) -> Vec<u8> {
+        let mut headers_bytes: Vec<u8> = format!(
+            "{} {} {}",
+            HttpVersion::HTTP2,
+            parts.status.as_u16(),
+            parts.status.canonical_reason().unwrap_or_default()
+        )
+        .into_bytes();
+        headers_bytes.extend_from_slice(HTTP_BR_BYTES);
+        for (key, value) in parts.headers.iter().chain(trailers.into_iter().flatten()) {
+            headers_bytes.extend_from_slice(key.as_str().as_bytes());
+            headers_bytes.extend_from_slice(b": ");
+            headers_bytes.extend_from_slice(value.as_bytes());
+            headers_bytes.extend_from_slice(HTTP_BR_BYTES);
+        }
+        headers_bytes.extend_from_slice(HTTP_BR_BYTES);
+        headers_bytes
+    }
+
+    /// Sends the request over HTTP/2 and stores the HTTP response.
+    async fn send_http2_request(
+        &mut self,
+        host: String,
+        port: u16,
+    ) -> Result<Option<Vec<u8>>, RequestError> {
+        let (parts, mut recv_stream) = self.send_http2_head(host, port).await?;
+        let mut body: Vec<u8> = Vec::new();
+        while let Some(data) = self
+            .with_timeout(TimeoutKind::Read, READING_RESPONSE, async {
+                Ok(recv_stream.data().await)
+            })
+            .await?
+        {
+            let data: Bytes = data.map_err(|err| RequestError::Request(err.to_string()))?;
+            let _ = recv_stream.flow_control().release_capacity(data.len());
+            body.extend_from_slice(&data);
+        }
+        let trailers: Option<HeaderMap> = self
+            .with_timeout(TimeoutKind::Read, READING_RESPONSE, async {
+                recv_stream
+                    .trailers()
                     .await
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                    .map_err(|err| RequestError::Request(err.to_string()))
+            })
+            .await?;
+        let headers_bytes: Vec<u8> = Self::get_http2_headers_bytes(&parts, trailers.as_ref());
+        let mut body_framing: BodyFraming = BodyFraming::Empty;
+        let mut redirect_url: Option<Vec<u8>> = None;
+        let location_sign_key: Vec<u8> = format!("{}:", LOCATION.to_lowercase()).into_bytes();
+        SharedResponseHandler::parse_response_headers(
+            &headers_bytes,
+            &location_sign_key,
+            self.get_methods().is_head(),
+            &mut body_framing,
+            &mut redirect_url,
+        )?;
+        self.response = Arc::new(RwLock::new(HttpResponseBinary::from_parts(
+            &headers_bytes,
+            body,
+        )));
+        Ok(redirect_url)
+    }
+}
+
+/// Streaming implementation for HttpRequest
+impl HttpRequest {
+    /// Returns the size of the buffer used to read from connections.
+    fn get_buffer_size(&self) -> usize {
+        self.config
+            .read()
+            .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer)
+    }
+
+    /// Returns whether the connection may be reused once the body of this response is read.
+    fn is_keep_alive_response(&self, headers_bytes: &[u8]) -> bool {
+        SharedResponseHandler::is_keep_alive(headers_bytes)
+            && self
+                .config
+                .read()
+                .is_ok_and(|config| config.http_version == HttpVersion::HTTP1_1)
+    }
+
+    /// Returns the decoder for the content encoding of the response, when decoding is enabled.
+    fn get_content_decoder(&self, headers_bytes: &[u8]) -> Option<ContentDecoder> {
+        let (decode, buffer_size) = self
+            .config
+            .read()
+            .map_or((false, DEFAULT_BUFFER_SIZE), |config| {
+                (config.decode, config.buffer)
+            });
+        if !decode {
+            return None;
+        }
+        ContentDecoder::from_headers(headers_bytes, buffer_size)
+    }
+
+    /// Reads the response head from the connection, leaving the body unread.
+    ///
+    /// # Returns
+    /// Returns the head along with the body bytes received with it, or `Err(RequestError)` if
+    /// the connection fails or closes before the head is complete.
+    fn read_response_head(
+        &mut self,
+        connection: &mut PooledConnection<BoxReadWrite>,
+    ) -> Result<ResponseHead, RequestError> {
+        let buffer_size: usize = self.get_buffer_size();
+        let mut buffer: Vec<u8> = vec![0; buffer_size];
+        let mut response_bytes: Vec<u8> = Vec::with_capacity(buffer_size.max(8192));
+        let location_sign_key: Vec<u8> = format!("{}:", LOCATION.to_lowercase()).into_bytes();
+        let is_head: bool = self.get_methods().is_head();
+        let timeouts: RequestTimeouts = self.get_timeouts();
+        loop {
+            if timeouts.is_expired() {
+                return Err(timeouts.get_error(TimeoutKind::Read, READING_RESPONSE));
             }
-            0x04 => {
-                let mut skip: [u8; 18] = [0u8; 18];
-                tcp_stream
-                    .read_exact(&mut skip)
-                    .await
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+            let n: usize = connection
+                .stream
+                .read(&mut buffer)
+                .map_err(|err| self.get_io_error(err, TimeoutKind::Read, READING_RESPONSE))?;
+            if n == 0 {
+                return Err(RequestError::Request(
+                    "connection closed before the response head was received".to_string(),
+                ));
             }
-            _ => {
+            connection.received = true;
+            let search_start: usize = response_bytes.len().saturating_sub(3);
+            response_bytes.extend_from_slice(&buffer[..n]);
+            if let Some(response_head) = SharedResponseHandler::take_response_head(
+                &mut response_bytes,
+                search_start,
+                &location_sign_key,
+                is_head,
+            )? {
+                return Ok(response_head);
+            }
+        }
+    }
+
+    /// Reads an async response head from the connection, leaving the body unread.
+    async fn read_response_head_async(
+        &mut self,
+        connection: &mut PooledConnection<BoxAsyncReadWrite>,
+    ) -> Result<ResponseHead, RequestError> {
+        let buffer_size: usize = self.get_buffer_size();
+        let mut buffer: Vec<u8> = vec![0; buffer_size];
+        let mut response_bytes: Vec<u8> = Vec::with_capacity(buffer_size.max(8192));
+        let location_sign_key: Vec<u8> = format!("{}:", LOCATION.to_lowercase()).into_bytes();
+        let is_head: bool = self.get_methods().is_head();
+        loop {
+            let n: usize = self
+                .with_timeout(TimeoutKind::Read, READING_RESPONSE, async {
+                    connection
+                        .stream
+                        .read(&mut buffer)
+                        .await
+                        .map_err(|err| RequestError::Request(err.to_string()))
+                })
+                .await?;
+            if n == 0 {
                 return Err(RequestError::Request(
-                    "Invalid SOCKS5 address type".to_string(),
+                    "connection closed before the response head was received".to_string(),
                 ));
             }
+            connection.received = true;
+            let search_start: usize = response_bytes.len().saturating_sub(3);
+            response_bytes.extend_from_slice(&buffer[..n]);
+            if let Some(response_head) = SharedResponseHandler::take_response_head(
+                &mut response_bytes,
+                search_start,
+                &location_sign_key,
+                is_head,
+            )? {
+                return Ok(response_head);
+            }
         }
-        let proxy_stream: BoxAsyncReadWrite = Box::new(tcp_stream);
-        let config: Config = self
-            .config
-            .read()
-            .map_or(Config::default(), |config| config.clone());
-        if Self::get_protocol(&config).is_https() {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(err) => {
-                        return Err(RequestError::Unknown(format!(
-                            "error reading temporary configuration: {}",
-                            err
-                        )));
+    }
+
+    /// Sends the request and reads the response head, retrying once on a stale pooled connection.
+    fn send_request_head(
+        &mut self,
+        host: String,
+        port: u16,
+    ) -> Result<(PooledConnection<BoxReadWrite>, ResponseHead), RequestError> {
+        let mut reuse: bool = self.can_resend_body();
+        loop {
+            let mut connection: PooledConnection<BoxReadWrite> =
+                self.get_pooled_connection(host.clone(), port, reuse)?;
+            let result: Result<ResponseHead, RequestError> =
+                self.write_request(&mut connection.stream).and_then(|_| {
+                    connection.written = true;
+                    self.read_response_head(&mut connection)
+                });
+            if reuse && self.is_stale_connection(&connection, &result) {
+                reuse = false;
+                continue;
+            }
+            return result.map(|response_head| (connection, response_head));
+        }
+    }
+
+    /// Sends the request and reads the async response head, retrying once on a stale pooled connection.
+    async fn send_request_head_async(
+        &mut self,
+        host: String,
+        port: u16,
+    ) -> Result<(PooledConnection<BoxAsyncReadWrite>, ResponseHead), RequestError> {
+        let mut reuse: bool = self.can_resend_body();
+        loop {
+            let mut connection: PooledConnection<BoxAsyncReadWrite> = self
+                .get_pooled_connection_async(host.clone(), port, reuse)
+                .await?;
+            let result: Result<ResponseHead, RequestError> =
+                match self.write_request_async(&mut connection.stream).await {
+                    Ok(()) => {
+                        connection.written = true;
+                        self.read_response_head_async(&mut connection).await
                     }
-                }
+                    Err(err) => Err(err),
+                };
+            if reuse && self.is_stale_connection(&connection, &result) {
+                reuse = false;
+                continue;
+            }
+            return result.map(|response_head| (connection, response_head));
+        }
+    }
+
+    /// Stores the response head and returns the redirect location to follow, if any.
+    fn handle_response_head(
+        &mut self,
+        headers_bytes: &[u8],
+        redirect_url: Option<Vec<u8>>,
+    ) -> Result<Option<String>, RequestError> {
+        self.response = Arc::new(RwLock::new(HttpResponseBinary::from_parts(
+            headers_bytes,
+            Vec::new(),
+        )));
+        self.store_response_cookies();
+        let should_redirect: bool = self.config.read().is_ok_and(|config| config.redirect);
+        match redirect_url {
+            Some(redirect_url) if should_redirect => String::from_utf8(redirect_url)
+                .map(Some)
+                .map_err(|err| RequestError::InvalidUrl(err.to_string())),
+            _ => {
+                self.attach_redirect_history();
+                self.attach_tls_info();
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns a copy of the stored response.
+    fn get_stored_response(&self) -> HttpResponseBinary {
+        self.response
+            .read()
+            .map_or(HttpResponseBinary::default(), |response| response.clone())
+    }
+
+    /// Sends the HTTP request synchronously and returns as soon as the response head is read.
+    ///
+    /// Redirects are followed and a Digest challenge is answered before the stream is returned.
+    /// The body of each of those responses is drained so that its connection can be reused, up
+    /// to `DRAINED_BODY_LIMIT` bytes; a connection with a longer body is closed instead.
+    pub(crate) fn send_stream_sync(&mut self) -> ResponseStreamResult {
+        loop {
+            let (host, port) = self.resolve_target()?;
+            if self.is_http2() {
+                return self.send_http2_stream_sync();
+            }
+            let (connection, response_head) = self.send_request_head(host, port)?;
+            let redirect_url: Option<String> = self.handle_response_head(
+                &response_head.headers_bytes,
+                response_head.redirect_url.clone(),
+            )?;
+            let keep_alive: bool = self.is_keep_alive_response(&response_head.headers_bytes);
+            let content_decoder: Option<ContentDecoder> = match redirect_url {
+                Some(_) => None,
+                None => self.get_content_decoder(&response_head.headers_bytes),
             };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            let tunnel_stream: crate::request::ProxyTunnelStream =
-                crate::request::ProxyTunnelStream::new(proxy_stream);
-            let tls_stream: TlsStream<crate::request::ProxyTunnelStream> = connector
-                .connect(dns_name, tunnel_stream)
-                .await
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            return Ok(Box::new(tls_stream));
+            let source: ResponseStreamSource = ResponseStreamSource::Http1 {
+                connection: Box::new(connection),
+                client: self.client.clone(),
+                decoder: Box::new(StreamBodyDecoder::new(
+                    response_head.body_framing,
+                    content_decoder,
+                )),
+                keep_alive,
+            };
+            let mut response_stream: ResponseStream = ResponseStream::new(
+                self.get_stored_response(),
+                source,
+                &response_head.remaining,
+                self.get_buffer_size(),
+            )?;
+            if redirect_url.is_none() && !self.prepare_digest_retry() {
+                return Ok(response_stream);
+            }
+            if keep_alive && response_head.body_framing != BodyFraming::UntilClose {
+                let _ = std::io::copy(
+                    &mut Read::by_ref(&mut response_stream).take(DRAINED_BODY_LIMIT as u64),
+                    &mut std::io::sink(),
+                );
+            }
+            drop(response_stream);
+            if let Some(url) = redirect_url {
+                self.prepare_redirect(url)?;
+            }
         }
+    }
 
-        Ok(proxy_stream)
+    /// Streams an HTTP/2 response synchronously by blocking on the async stream.
+    ///
+    /// Requests made through a client share its runtime; otherwise the stream owns a runtime
+    /// that drives the connection while the body is read.
+    fn send_http2_stream_sync(&mut self) -> ResponseStreamResult {
+        let runtime: StreamRuntime = StreamRuntime::new(self.client.clone())?;
+        let stream: AsyncResponseStream = runtime.block_on(self.send_stream_async())??;
+        ResponseStream::new(
+            stream.get_response(),
+            ResponseStreamSource::Http2 {
+                stream: Box::new(stream),
+                runtime,
+            },
+            &[],
+            self.get_buffer_size(),
+        )
     }
 
-    /// Sends the HTTP request asynchronously.
-    pub(crate) async fn send_async(&mut self) -> RequestResult {
-        let methods: Method = self.get_methods();
-        let (host, port) = {
-            if let Ok(mut config) = self.config.write() {
-                config.url_obj = self
-                    .parse_url()
-                    .map_err(|err| RequestError::InvalidUrl(err.to_string()))?;
-                let host: String = config.url_obj.host.clone().unwrap_or_default();
-                let port = self.get_port(config.url_obj.port.clone().unwrap_or_default(), &config);
-                (host, port)
+    /// Sends the HTTP request asynchronously and returns as soon as the response head is read.
+    ///
+    /// Redirects are followed and a Digest challenge is answered before the stream is returned.
+    /// The body of each of those responses is drained so that its connection can be reused, up
+    /// to `DRAINED_BODY_LIMIT` bytes; a connection with a longer body is closed instead.
+    pub(crate) async fn send_stream_async(&mut self) -> AsyncResponseStreamResult {
+        loop {
+            let (host, port) = self.resolve_target()?;
+            let (response_head, source) = if self.is_http2() {
+                let (parts, recv_stream) = self.send_http2_head(host, port).await?;
+                let headers_bytes: Vec<u8> = Self::get_http2_headers_bytes(&parts, None);
+                let mut body_framing: BodyFraming = BodyFraming::Empty;
+                let mut redirect_url: Option<Vec<u8>> = None;
+                let location_sign_key: Vec<u8> =
+                    format!("{}:", LOCATION.to_lowercase()).into_bytes();
+                SharedResponseHandler::parse_response_headers(
+                    &headers_bytes,
+                    &location_sign_key,
+                    self.get_methods().is_head(),
+                    &mut body_framing,
+                    &mut redirect_url,
+                )?;
+                if body_framing != BodyFraming::Empty {
+                    body_framing = BodyFraming::UntilClose;
+                }
+                let response_head: ResponseHead = ResponseHead {
+                    headers_bytes,
+                    remaining: Vec::new(),
+                    redirect_url,
+                    body_framing,
+                };
+                (response_head, AsyncResponseStreamSource::Http2(recv_stream))
             } else {
-                (String::new(), 0u16)
+                let (connection, response_head) = self.send_request_head_async(host, port).await?;
+                let keep_alive: bool = self.is_keep_alive_response(&response_head.headers_bytes);
+                let source: AsyncResponseStreamSource = AsyncResponseStreamSource::Http1 {
+                    connection: Box::new(connection),
+                    client: self.client.clone(),
+                    keep_alive,
+                };
+                (response_head, source)
+            };
+            let redirect_url: Option<String> = self.handle_response_head(
+                &response_head.headers_bytes,
+                response_head.redirect_url.clone(),
+            )?;
+            let content_decoder: Option<ContentDecoder> = match redirect_url {
+                Some(_) => None,
+                None => self.get_content_decoder(&response_head.headers_bytes),
+            };
+            let mut response_stream: AsyncResponseStream = AsyncResponseStream::new(
+                self.get_stored_response(),
+                source,
+                StreamBodyDecoder::new(response_head.body_framing, content_decoder),
+                &response_head.remaining,
+                self.get_buffer_size(),
+                self.get_timeouts(),
+            )?;
+            if redirect_url.is_none() && !self.prepare_digest_retry() {
+                return Ok(response_stream);
             }
-        };
-        let mut stream: BoxAsyncReadWrite = self.get_connection_stream_async(host, port).await?;
-        let res: Result<BoxResponseTrait, RequestError> = match methods {
-            m if m.is_get() => self.send_get_request_async(&mut stream).await,
-            m if m.is_post() => self.send_post_request_async(&mut stream).await,
-            err => Err(RequestError::Request(format!(
-                "do not support {} method",
-                err
-            ))),
-        };
-        res
+            if response_stream.is_reusable() {
+                let mut drained: usize = 0;
+                while drained <= DRAINED_BODY_LIMIT
+                    && let Some(Ok(chunk)) = response_stream.next().await
+                {
+                    drained += chunk.len();
+                }
+            }
+            drop(response_stream);
+            if let Some(url) = redirect_url {
+                self.prepare_redirect(url)?;
+            }
+        }
     }
 }
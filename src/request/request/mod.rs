@@ -4,8 +4,8 @@ pub(crate) mod r#struct;
 pub(crate) mod r#trait;
 pub(crate) mod r#type;
 
+pub use r#struct::*;
 pub use r#trait::*;
 pub use r#type::*;
 
 pub(crate) use r#const::*;
-pub(crate) use r#struct::*;
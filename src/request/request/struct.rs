@@ -2,8 +2,11 @@ use crate::*;
 
 /// Represents an HTTP request, encapsulating various components such as the method, URL, protocol,
 /// headers, body, and additional metadata.
-#[derive(Debug, Clone)]
-pub(crate) struct HttpRequest {
+///
+/// Middleware receives it to inspect or change the method, URL, headers and body of a request
+/// before it is sent.
+#[derive(Clone)]
+pub struct HttpRequest {
     pub(crate) methods: Arc<Method>,
     pub(crate) url: Arc<String>,
     pub(crate) header: Arc<RequestHeaders>,
@@ -11,4 +14,7 @@ pub(crate) struct HttpRequest {
     pub(crate) config: ArcRwLock<Config>,
     pub(crate) tmp: ArcRwLock<Tmp>,
     pub(crate) response: ArcRwLock<HttpResponseBinary>,
+    pub(crate) client: Option<Client>,
+    pub(crate) cookie_jar: Option<CookieJar>,
+    pub(crate) middlewares: Vec<ArcMiddleware>,
 }
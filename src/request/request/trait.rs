@@ -1,16 +1,22 @@
 use crate::*;
 
 pub(crate) trait AsyncReadWrite: AsyncRead + AsyncWrite + Unpin + Send {}
-pub(crate) trait ReadWrite: Read + Write {}
+pub(crate) trait ReadWrite: Read + Write + Send {}
 
 pub trait AsyncRequestTrait: Send + Debug {
     type RequestResult: Sized;
 
     fn send(&mut self) -> Pin<Box<dyn Future<Output = Self::RequestResult> + Send + '_>>;
+
+    fn send_stream(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = AsyncResponseStreamResult> + Send + '_>>;
 }
 
 pub trait RequestTrait: Send + Debug {
     type RequestResult: Sized;
 
     fn send(&mut self) -> Self::RequestResult;
+
+    fn send_stream(&mut self) -> ResponseStreamResult;
 }
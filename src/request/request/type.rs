@@ -1,6 +1,8 @@
 use crate::*;
 
 pub type RequestResult = Result<BoxResponseTrait, RequestError>;
+pub type ResponseStreamResult = Result<ResponseStream, RequestError>;
+pub type AsyncResponseStreamResult = Result<AsyncResponseStream, RequestError>;
 pub type BoxAsyncRequestTrait = Box<dyn AsyncRequestTrait<RequestResult = RequestResult>>;
 pub type BoxRequestTrait = Box<dyn RequestTrait<RequestResult = RequestResult>>;
 
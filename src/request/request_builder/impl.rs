@@ -5,6 +5,7 @@ impl Default for RequestBuilder {
         Self {
             http_request: HttpRequest::default(),
             builder: HttpRequest::default(),
+            query: String::new(),
         }
     }
 }
@@ -53,6 +54,96 @@ impl RequestBuilder {
         self
     }
 
+    /// Sets the HTTP method to `PUT` and the target URL for the request.
+    ///
+    /// The configured body is always sent, together with its `Content-Length`.
+    ///
+    /// # Parameters
+    /// - `url`: The target URL of the request.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn put(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::PUT);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to `PATCH` and the target URL for the request.
+    ///
+    /// The configured body is always sent, together with its `Content-Length`.
+    ///
+    /// # Parameters
+    /// - `url`: The target URL of the request.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn patch(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::PATCH);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to `DELETE` and the target URL for the request.
+    ///
+    /// A body is only sent when one has been configured and it is not empty.
+    ///
+    /// # Parameters
+    /// - `url`: The target URL of the request.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn delete(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::DELETE);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to `HEAD` and the target URL for the request.
+    ///
+    /// No body is sent, and the response is returned as soon as its headers arrive.
+    ///
+    /// # Parameters
+    /// - `url`: The target URL of the request.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn head(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::HEAD);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to `OPTIONS` and the target URL for the request.
+    ///
+    /// A body is only sent when one has been configured and it is not empty.
+    ///
+    /// # Parameters
+    /// - `url`: The target URL of the request.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn options(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::OPTIONS);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to `TRACE` and the target URL for the request.
+    ///
+    /// No body is sent, as required for `TRACE` requests.
+    ///
+    /// # Parameters
+    /// - `url`: The target URL of the request.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn trace(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::TRACE);
+        self.url(url);
+        self
+    }
+
     /// Sets the target URL of the request.
     ///
     /// This method allows you to specify the URL for the request being built.
@@ -90,6 +181,9 @@ impl RequestBuilder {
     /// request to use HTTP 2.0 only, overriding any other version that may
     /// have been previously set.
     ///
+    /// HTTPS requests negotiate `h2` through ALPN and fail if the server does not agree;
+    /// plain HTTP requests use HTTP/2 with prior knowledge (h2c).
+    ///
     /// # Returns
     /// Returns a mutable reference to `self` to allow method chaining.
     pub fn http2_only(&mut self) -> &mut Self {
@@ -197,6 +291,448 @@ impl RequestBuilder {
         self
     }
 
+    /// Sets the request body to URL-encoded form data.
+    ///
+    /// The form can be a slice of key/value pairs, a map or any other `Serialize` value that
+    /// serializes to a flat sequence of pairs or a struct. Keys and values are percent-encoded as
+    /// `application/x-www-form-urlencoded` and the `Content-Type` header is set accordingly. A
+    /// form that cannot be serialized this way leaves the request unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `form` - The form fields to send.
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to the current instance of the struct, allowing method chaining.
+    pub fn form<T: Serialize + ?Sized>(&mut self, form: &T) -> &mut Self {
+        if let Ok(encoded) = serde_urlencoded::to_string(form) {
+            let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+            header.insert(CONTENT_TYPE, FORM_URLENCODED);
+            self.headers(header);
+            self.http_request.body = Arc::new(Body::Form(encoded));
+        }
+        self
+    }
+
+    /// Adds query parameters to the request URL.
+    ///
+    /// The parameters can be a slice of key/value pairs, a map or any other `Serialize` value
+    /// that serializes to a flat sequence of pairs or a struct. Keys and values are
+    /// percent-encoded and appended to any query already in the URL when the request is built,
+    /// so this method can be called before or after the URL is set and more than once.
+    /// Parameters that cannot be serialized this way are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `query` - The query parameters to add.
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to the current instance of the struct, allowing method chaining.
+    pub fn query<T: Serialize + ?Sized>(&mut self, query: &T) -> &mut Self {
+        if let Ok(encoded) = serde_urlencoded::to_string(query)
+            && !encoded.is_empty()
+        {
+            if !self.query.is_empty() {
+                self.query.push('&');
+            }
+            self.query.push_str(&encoded);
+        }
+        self
+    }
+
+    /// Sets the HTTP request body to data read from a blocking reader while the request is sent.
+    ///
+    /// The body is sent with a `Content-Length` header when `length` is given, and with
+    /// `Transfer-Encoding: chunked` otherwise. The reader is consumed by the first request that
+    /// sends it, so a redirect that keeps the body cannot be followed.
+    ///
+    /// # Parameters
+    ///
+    /// - `reader` - The reader the body data is read from.
+    /// - `length` - The number of bytes the reader provides, if known.
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to the current instance of the struct, allowing method chaining.
+    pub fn body_reader<R: Read + Send + 'static>(
+        &mut self,
+        reader: R,
+        length: Option<u64>,
+    ) -> &mut Self {
+        self.http_request.body = Arc::new(Body::Stream(BodyStream::new(
+            BodySource::Reader(Box::new(reader)),
+            length,
+        )));
+        self
+    }
+
+    /// Sets the HTTP request body to data read from an async reader while the request is sent.
+    ///
+    /// The body is sent with a `Content-Length` header when `length` is given, and with
+    /// `Transfer-Encoding: chunked` otherwise. The reader is consumed by the first request that
+    /// sends it, so a redirect that keeps the body cannot be followed.
+    ///
+    /// # Parameters
+    ///
+    /// - `reader` - The async reader the body data is read from.
+    /// - `length` - The number of bytes the reader provides, if known.
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to the current instance of the struct, allowing method chaining.
+    pub fn body_async_reader<R: AsyncRead + Unpin + Send + 'static>(
+        &mut self,
+        reader: R,
+        length: Option<u64>,
+    ) -> &mut Self {
+        self.http_request.body = Arc::new(Body::Stream(BodyStream::new(
+            BodySource::AsyncReader(Box::new(reader)),
+            length,
+        )));
+        self
+    }
+
+    /// Sets the HTTP request body to the contents of a file, read while the request is sent.
+    ///
+    /// The file is opened when the request is sent and its size is sent as the `Content-Length`.
+    /// Unlike readers, a file body can be sent again, for example when a redirect keeps the body.
+    ///
+    /// # Parameters
+    ///
+    /// - `path` - The path of the file to upload.
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to the current instance of the struct, allowing method chaining.
+    pub fn body_file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.http_request.body = Arc::new(Body::Stream(BodyStream::new(
+            BodySource::File(path.as_ref().to_path_buf()),
+            None,
+        )));
+        self
+    }
+
+    /// Sets the HTTP request body to a `multipart/form-data` form.
+    ///
+    /// The `Content-Type` header is set with the boundary of the form. The form is sent with a
+    /// `Content-Length` header when the length of every part is known, as for text, bytes and
+    /// file parts, and with `Transfer-Encoding: chunked` otherwise.
+    ///
+    /// # Parameters
+    ///
+    /// - `form` - The multipart form to send.
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to the current instance of the struct, allowing method chaining.
+    pub fn multipart(&mut self, form: &MultipartForm) -> &mut Self {
+        let mut header: HashMapXxHash3_64<&str, String> = hash_map_xx_hash3_64();
+        header.insert(CONTENT_TYPE, form.get_content_type());
+        self.headers(header);
+        self.http_request.body = Arc::new(Body::Stream(BodyStream::new(
+            BodySource::Multipart(form.clone()),
+            None,
+        )));
+        self
+    }
+
+    /// Sends the request through a `Client`, reusing its pooled keep-alive connections.
+    ///
+    /// Without a client, every request opens and closes its own connection.
+    ///
+    /// # Parameters
+    /// - `client`: The client whose connection pool should be used.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn client(&mut self, client: &Client) -> &mut Self {
+        self.http_request.client = Some(client.clone());
+        self
+    }
+
+    /// Authenticates the request with HTTP Basic authentication.
+    ///
+    /// The credentials are only sent to the origin the request is first sent to; redirects to
+    /// another origin drop them unless the redirect policy keeps credentials. An
+    /// `Authorization` header set explicitly takes precedence, and `Debug` output never shows
+    /// the credentials.
+    ///
+    /// # Parameters
+    /// - `username`: The user name.
+    /// - `password`: The password.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn basic_auth(&mut self, username: &str, password: &str) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.authorization = Some(Secret(basic_authorization(username, password)));
+        }
+        self
+    }
+
+    /// Authenticates the request with a bearer token, such as an OAuth 2.0 access token.
+    ///
+    /// The token is handled like the credentials of `basic_auth`.
+    ///
+    /// # Parameters
+    /// - `token`: The bearer token.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn bearer_auth(&mut self, token: &str) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.authorization = Some(Secret(format!("Bearer {}", token)));
+        }
+        self
+    }
+
+    /// Authenticates the request with HTTP Digest authentication (RFC 7616).
+    ///
+    /// When `send` or `send_stream` receives a `401` with a `WWW-Authenticate: Digest`
+    /// challenge, the request is sent once more with the computed `Authorization` header. MD5,
+    /// SHA-256 and their session variants are supported with `qop=auth`. Later sends to the
+    /// same origin answer the last challenge right away, counting every request against its
+    /// nonce. An `Authorization` header set explicitly takes precedence.
+    ///
+    /// # Parameters
+    /// - `username`: The user name.
+    /// - `password`: The password.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn digest_auth(&mut self, username: &str, password: &str) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.digest_auth = Some(DigestAuth::new(username, password));
+        }
+        self
+    }
+
+    /// Adds a middleware run around every `send` and `send_stream` of the request.
+    ///
+    /// Middleware runs in the order it is added, after any middleware of the client.
+    ///
+    /// # Parameters
+    /// - `middleware`: The middleware to add.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn middleware<M: Middleware + 'static>(&mut self, middleware: M) -> &mut Self {
+        self.http_request.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Stores cookies set by responses in a cookie jar and sends matching cookies with the request.
+    ///
+    /// Cookies set by intermediate redirect responses are stored as well. Cookies from the jar are
+    /// appended to any `Cookie` header set explicitly.
+    ///
+    /// # Parameters
+    /// - `cookie_jar`: The cookie jar to use.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn cookie_jar(&mut self, cookie_jar: &CookieJar) -> &mut Self {
+        self.http_request.cookie_jar = Some(cookie_jar.clone());
+        self
+    }
+
+    /// Trusts every certificate of a PEM bundle as a root certificate, in addition to the
+    /// current roots.
+    ///
+    /// The roots are used for HTTPS origins and HTTPS proxies. A bundle that cannot be parsed
+    /// makes the request fail when it opens a TLS connection.
+    ///
+    /// # Parameters
+    /// - `pem`: One or more PEM encoded certificates.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn add_root_certificate_pem<T: AsRef<[u8]>>(&mut self, pem: T) -> &mut Self {
+        if let Ok(mut tmp) = self.http_request.tmp.write() {
+            tmp.tls.add_root_certificate_pem(pem.as_ref());
+        }
+        self
+    }
+
+    /// Trusts a DER encoded certificate as a root certificate, in addition to the current roots.
+    ///
+    /// # Parameters
+    /// - `der`: The DER encoded certificate.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn add_root_certificate_der<T: AsRef<[u8]>>(&mut self, der: T) -> &mut Self {
+        if let Ok(mut tmp) = self.http_request.tmp.write() {
+            tmp.tls.add_root_certificate_der(der.as_ref());
+        }
+        self
+    }
+
+    /// Trusts the root certificates stored in a file or directory.
+    ///
+    /// A file may be a PEM bundle or a DER certificate. A directory such as `/etc/ssl/certs`
+    /// is loaded like a system certificate store: files without a usable certificate are
+    /// skipped. The certificates are read when this method is called.
+    ///
+    /// # Parameters
+    /// - `path`: The certificate file or directory.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn add_root_certificates_from_path<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        if let Ok(mut tmp) = self.http_request.tmp.write() {
+            tmp.tls.add_root_certificates_from_path(path.as_ref());
+        }
+        self
+    }
+
+    /// Controls whether the built-in Mozilla root certificates are trusted.
+    ///
+    /// They are trusted by default. Disabling them replaces the default roots entirely with the
+    /// certificates added through the `add_root_certificate*` methods, whichever order the
+    /// methods are called in.
+    ///
+    /// # Parameters
+    /// - `enable`: Whether the built-in roots are trusted.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn tls_built_in_root_certs(&mut self, enable: bool) -> &mut Self {
+        if let Ok(mut tmp) = self.http_request.tmp.write() {
+            tmp.tls.built_in_root_certs(enable);
+        }
+        self
+    }
+
+    /// Presents a client certificate to servers that require mutual TLS.
+    ///
+    /// The certificate is used for HTTPS origins and for the TLS connection to an HTTPS proxy.
+    /// A certificate or key that cannot be parsed makes the request fail when it opens a TLS
+    /// connection.
+    ///
+    /// # Parameters
+    /// - `cert_chain_pem`: The PEM encoded client certificate, followed by any intermediates.
+    /// - `private_key_pem`: The PEM encoded private key, in PKCS#8, PKCS#1 (RSA) or SEC1 form.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn client_certificate_pem<C, K>(
+        &mut self,
+        cert_chain_pem: C,
+        private_key_pem: K,
+    ) -> &mut Self
+    where
+        C: AsRef<[u8]>,
+        K: AsRef<[u8]>,
+    {
+        if let Ok(mut tmp) = self.http_request.tmp.write() {
+            tmp.tls
+                .client_certificate_pem(cert_chain_pem.as_ref(), private_key_pem.as_ref());
+        }
+        self
+    }
+
+    /// Presents a DER encoded client certificate to servers that require mutual TLS.
+    ///
+    /// # Parameters
+    /// - `cert_chain_der`: The DER encoded client certificate, followed by any intermediates.
+    /// - `private_key_der`: The DER encoded private key, in PKCS#8, PKCS#1 (RSA) or SEC1 form.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn client_certificate_der<K: AsRef<[u8]>>(
+        &mut self,
+        cert_chain_der: Vec<Vec<u8>>,
+        private_key_der: K,
+    ) -> &mut Self {
+        if let Ok(mut tmp) = self.http_request.tmp.write() {
+            tmp.tls
+                .client_certificate_der(cert_chain_der, private_key_der.as_ref());
+        }
+        self
+    }
+
+    /// Accepts any server certificate, including self-signed, expired and untrusted ones, and
+    /// certificates issued for another host.
+    ///
+    /// # Warning
+    ///
+    /// This disables certificate verification for HTTPS origins and HTTPS proxies, so anyone
+    /// able to intercept the connection can read and modify it. Only use it against local
+    /// development servers. Handshake signatures are still checked.
+    ///
+    /// # Parameters
+    /// - `accept`: Whether invalid certificates are accepted.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn danger_accept_invalid_certs(&mut self, accept: bool) -> &mut Self {
+        if let Ok(mut tmp) = self.http_request.tmp.write() {
+            tmp.tls.accept_invalid_certs = accept;
+        }
+        self
+    }
+
+    /// Accepts server certificates issued for another host, as long as they chain to a trusted
+    /// root.
+    ///
+    /// # Warning
+    ///
+    /// Any server holding a certificate from a trusted root can impersonate the requested host.
+    /// Only use it against local development servers.
+    ///
+    /// # Parameters
+    /// - `accept`: Whether hostname mismatches are accepted.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn danger_accept_invalid_hostnames(&mut self, accept: bool) -> &mut Self {
+        if let Ok(mut tmp) = self.http_request.tmp.write() {
+            tmp.tls.accept_invalid_hostnames = accept;
+        }
+        self
+    }
+
+    /// Pins the server's public key by the SHA-256 hash of its SubjectPublicKeyInfo.
+    ///
+    /// Pins are checked after the usual chain and hostname validation, against the leaf
+    /// certificate only. When several pins are set, matching any one of them is enough, which
+    /// allows a backup key to be pinned ahead of a rotation. A server that matches none fails
+    /// with an error for which [`is_certificate_pin_mismatch`] returns `true`. An invalid pin is
+    /// reported when the request is sent.
+    ///
+    /// # Parameters
+    /// - `pin`: The base64 encoded hash, optionally prefixed with `sha256/` as in
+    ///   `sha256/47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=`.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn pin_public_key_sha256(&mut self, pin: &str) -> &mut Self {
+        if let Ok(mut tmp) = self.http_request.tmp.write() {
+            tmp.tls.pin_public_key_sha256(pin);
+        }
+        self
+    }
+
+    /// Pins the server's leaf certificate to exactly the given certificate.
+    ///
+    /// Works like `pin_public_key_sha256`, but the whole certificate must match, so the pin has
+    /// to be updated whenever the server renews its certificate.
+    ///
+    /// # Parameters
+    /// - `certificate`: The PEM or DER encoded certificate.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn pin_certificate<T: AsRef<[u8]>>(&mut self, certificate: T) -> &mut Self {
+        if let Ok(mut tmp) = self.http_request.tmp.write() {
+            tmp.tls.pin_certificate(certificate.as_ref());
+        }
+        self
+    }
+
     /// Sets the timeout value for the current connection.
     ///
     /// This method sets the timeout duration for the connection, which is used to determine
@@ -204,10 +740,15 @@ impl RequestBuilder {
     /// as failed. The timeout value is stored in an `Arc` to allow it to be shared safely across
     /// multiple threads if needed.
     ///
+    /// Async requests apply it to connecting, the TLS handshake, proxy negotiation and every
+    /// write and read, including reads of a streamed response body, and fail with an error for
+    /// which [`is_timeout`] returns `true`. Use `connect_timeout`, `read_timeout` and
+    /// `total_timeout` to set the limits separately.
+    ///
     /// # Parameters
     ///
-    /// - `timeout`: The timeout duration in seconds. This value will be used to configure the
-    ///   connection timeout.
+    /// - `timeout`: The timeout duration in milliseconds. This value will be used to configure
+    ///   the connection timeout.
     ///
     /// # Returns
     /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
@@ -218,6 +759,62 @@ impl RequestBuilder {
         self
     }
 
+    /// Sets how long establishing a connection may take.
+    ///
+    /// Covers the TCP connect and, for async requests, proxy negotiation and the TLS and HTTP/2
+    /// handshakes. Exceeding it fails with an error for which [`get_timeout_kind`] returns
+    /// `RequestTimeoutKind::Connect`. Defaults to the value set by `timeout`.
+    ///
+    /// # Parameters
+    /// - `timeout`: The connect timeout in milliseconds.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn connect_timeout(&mut self, timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.connect_timeout = Some(timeout);
+        }
+        self
+    }
+
+    /// Sets how long a single read may wait for response data.
+    ///
+    /// The timer restarts whenever data arrives, so it bounds idle time rather than the length
+    /// of the whole response. Exceeding it fails with an error for which [`get_timeout_kind`]
+    /// returns `RequestTimeoutKind::Read`. Defaults to the value set by `timeout`.
+    ///
+    /// # Parameters
+    /// - `timeout`: The read timeout in milliseconds.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn read_timeout(&mut self, timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.read_timeout = Some(timeout);
+        }
+        self
+    }
+
+    /// Sets an overall deadline for each send of the request.
+    ///
+    /// The deadline starts when `send` or `send_stream` is called and covers connecting, proxy
+    /// handshakes, every redirect and retry and reading the response, so a server trickling
+    /// data cannot keep the request alive. Exceeding it fails with an error for which
+    /// [`get_timeout_kind`] returns `RequestTimeoutKind::Deadline`. Sync requests check it between
+    /// reads, so it may be overrun by up to one read timeout. There is no deadline by default.
+    ///
+    /// # Parameters
+    /// - `timeout`: The deadline in milliseconds.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn total_timeout(&mut self, timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.total_timeout = Some(timeout);
+        }
+        self
+    }
+
     /// Enables HTTP redirection for the request.
     ///
     /// This method sets the `redirect` property of the `http_request` to `true`.
@@ -240,6 +837,39 @@ impl RequestBuilder {
         self
     }
 
+    /// Sets the policy applied to the method, body and credentials when following redirects.
+    ///
+    /// # Parameters
+    /// - `redirect_policy`: The redirect policy to use.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn redirect_policy(&mut self, redirect_policy: &RedirectPolicy) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.redirect_policy = *redirect_policy;
+        }
+        self
+    }
+
+    /// Retries failed requests according to a retry policy.
+    ///
+    /// Applies to `send` and `send_stream`; a streamed request is retried on its response head,
+    /// before the stream is returned. Retries stop early when the next attempt could not start
+    /// before the `total_timeout` deadline, and bodies read from a non-replayable source are
+    /// never resent.
+    ///
+    /// # Parameters
+    /// - `retry_policy`: The retry policy to use.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RequestBuilder` to allow method chaining.
+    pub fn retry_policy(&mut self, retry_policy: &RetryPolicy) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.retry_policy = Some(retry_policy.clone());
+        }
+        self
+    }
+
     /// Sets the maximum number of allowed redirections for the HTTP request.
     ///
     /// This method updates the `max_redirect_times` field in the configuration and returns a mutable
@@ -472,6 +1102,15 @@ impl RequestBuilder {
         self
     }
 
+    /// Merges the query parameters added with `query` into the URL of the request being built.
+    fn merge_query(&mut self) {
+        if !self.query.is_empty() {
+            let url: String = append_query(&self.http_request.url, &self.query);
+            self.http_request.url(url);
+            self.query.clear();
+        }
+    }
+
     /// Finalizes the builder and returns a fully constructed async `HttpRequest` instance.
     ///
     /// This method takes the current configuration stored in `http_request`, creates a new
@@ -481,6 +1120,7 @@ impl RequestBuilder {
     /// # Returns
     /// Returns a fully constructed `BoxAsyncRequestTrait` instance based on the current builder state.
     pub fn build_async(&mut self) -> BoxAsyncRequestTrait {
+        self.merge_query();
         self.builder = self.http_request.clone();
         self.http_request = HttpRequest::default();
         Box::new(self.builder.clone())
@@ -495,6 +1135,7 @@ impl RequestBuilder {
     /// # Returns
     /// Returns a fully constructed `BoxRequestTrait` instance based on the current builder state.
     pub fn build_sync(&mut self) -> BoxRequestTrait {
+        self.merge_query();
         self.builder = self.http_request.clone();
         self.http_request = HttpRequest::default();
         Box::new(self.builder.clone())
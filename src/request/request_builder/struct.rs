@@ -11,6 +11,8 @@ use crate::*;
 ///   the construction process. It holds the current state of the builder.
 /// - `builder`: A finalized `HttpRequest` instance that holds the result after the
 ///   builder process has been completed. It is returned when the builder is finalized.
+/// - `query`: The encoded query parameters added with `query`, merged into the URL when the
+///   request is built.
 ///
 /// This builder simplifies the creation of `HttpRequest` objects, ensuring thread-safety
 /// and immutability of shared references, while providing a fluent API for constructing
@@ -19,4 +21,5 @@ use crate::*;
 pub struct RequestBuilder {
     pub(crate) http_request: HttpRequest,
     pub(crate) builder: HttpRequest,
+    pub(crate) query: String,
 }
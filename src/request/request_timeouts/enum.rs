@@ -0,0 +1,23 @@
+/// The kind of step a request timeout applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimeoutKind {
+    /// Establishing the connection: TCP connect, proxy negotiation and the TLS and HTTP/2 handshakes.
+    Connect,
+    /// Waiting for the next bytes of the response.
+    Read,
+    /// Writing the request.
+    Write,
+}
+
+/// Which limit a request exceeded, as reported by `get_timeout_kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RequestTimeoutKind {
+    /// Establishing the connection took longer than the connect timeout.
+    Connect,
+    /// No response data arrived within the read timeout.
+    Read,
+    /// Writing the request took longer than the timeout.
+    Write,
+    /// The request, including its redirects and retries, missed its overall deadline.
+    Deadline,
+}
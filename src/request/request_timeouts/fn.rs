@@ -0,0 +1,33 @@
+use crate::*;
+
+/// Returns which timeout a request failed with, if it failed because of one.
+///
+/// # Parameters
+/// - `error`: The error returned by `send`, `send_stream` or a response stream.
+///
+/// # Returns
+/// Returns the kind of limit that was exceeded, or `None` for any other error.
+pub fn get_timeout_kind(error: &RequestError) -> Option<RequestTimeoutKind> {
+    let RequestError::Request(message) = error else {
+        return None;
+    };
+    if message.starts_with(CONNECT_TIMEOUT) {
+        Some(RequestTimeoutKind::Connect)
+    } else if message.starts_with(READ_TIMEOUT) {
+        Some(RequestTimeoutKind::Read)
+    } else if message.starts_with(REQUEST_DEADLINE_EXCEEDED) {
+        Some(RequestTimeoutKind::Deadline)
+    } else if message.starts_with(REQUEST_TIMEOUT) {
+        Some(RequestTimeoutKind::Write)
+    } else {
+        None
+    }
+}
+
+/// Returns whether a request failed because one of its timeouts or its deadline elapsed.
+///
+/// # Parameters
+/// - `error`: The error returned by `send`, `send_stream` or a response stream.
+pub fn is_timeout(error: &RequestError) -> bool {
+    get_timeout_kind(error).is_some()
+}
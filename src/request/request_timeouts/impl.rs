@@ -0,0 +1,65 @@
+use crate::*;
+
+impl RequestTimeouts {
+    /// Resolves the time limits of a request from its configuration.
+    ///
+    /// # Parameters
+    /// - `config`: The request configuration; unset limits fall back to `Config.timeout`.
+    /// - `deadline`: When the current send must be complete, if it has an overall deadline.
+    pub(crate) fn new(config: &Config, deadline: Option<Instant>) -> Self {
+        Self {
+            connect: Duration::from_millis(config.connect_timeout.unwrap_or(config.timeout)),
+            read: Duration::from_millis(config.read_timeout.unwrap_or(config.timeout)),
+            write: Duration::from_millis(config.timeout),
+            deadline,
+        }
+    }
+
+    /// Returns how long a step may take, shortened to the time left before the deadline.
+    pub(crate) fn get_duration(&self, kind: TimeoutKind) -> Duration {
+        let duration: Duration = match kind {
+            TimeoutKind::Connect => self.connect,
+            TimeoutKind::Read => self.read,
+            TimeoutKind::Write => self.write,
+        };
+        match self.deadline {
+            Some(deadline) => duration.min(deadline.saturating_duration_since(Instant::now())),
+            None => duration,
+        }
+    }
+
+    /// Returns whether the overall deadline has passed.
+    pub(crate) fn is_expired(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Returns the error reported when a step did not complete in time.
+    ///
+    /// The error names the overall deadline when it has passed and the limit of the step
+    /// otherwise.
+    ///
+    /// # Parameters
+    /// - `kind`: The kind of step that timed out.
+    /// - `step`: What the request was doing, such as `connecting`.
+    pub(crate) fn get_error(&self, kind: TimeoutKind, step: &str) -> RequestError {
+        let (prefix, duration): (&str, Duration) = if self.is_expired() {
+            (REQUEST_DEADLINE_EXCEEDED, Duration::ZERO)
+        } else {
+            match kind {
+                TimeoutKind::Connect => (CONNECT_TIMEOUT, self.connect),
+                TimeoutKind::Read => (READ_TIMEOUT, self.read),
+                TimeoutKind::Write => (REQUEST_TIMEOUT, self.write),
+            }
+        };
+        if duration.is_zero() {
+            return RequestError::Request(format!("{} while {}", prefix, step));
+        }
+        RequestError::Request(format!(
+            "{} while {} after {}ms",
+            prefix,
+            step,
+            duration.as_millis()
+        ))
+    }
+}
@@ -0,0 +1,10 @@
+pub(crate) mod r#enum;
+pub(crate) mod r#fn;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+
+pub use r#enum::RequestTimeoutKind;
+pub use r#fn::*;
+
+pub(crate) use r#enum::*;
+pub(crate) use r#struct::*;
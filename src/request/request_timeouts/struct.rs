@@ -0,0 +1,16 @@
+use crate::*;
+
+/// The time limits applied to one send of a request.
+///
+/// # Fields
+/// - `connect`: The limit for establishing a connection.
+/// - `read`: The longest a single read may wait for data.
+/// - `write`: The longest a single write may take.
+/// - `deadline`: When the whole send, including redirects, must be complete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RequestTimeouts {
+    pub(crate) connect: Duration,
+    pub(crate) read: Duration,
+    pub(crate) write: Duration,
+    pub(crate) deadline: Option<Instant>,
+}
@@ -0,0 +1,43 @@
+use crate::*;
+
+impl RetryAttempt {
+    /// Retrieves the number of the attempt.
+    ///
+    /// # Returns
+    /// - `usize`: The attempt number, where `1` is the first request.
+    pub fn get_attempt(&self) -> usize {
+        self.attempt
+    }
+
+    /// Retrieves the status code of the response received by the attempt.
+    ///
+    /// # Returns
+    /// - `Option<ResponseStatusCode>`: The status code, or `None` if the attempt failed with an error.
+    pub fn get_status_code(&self) -> Option<ResponseStatusCode> {
+        self.status_code
+    }
+
+    /// Retrieves the error the attempt failed with.
+    ///
+    /// # Returns
+    /// - `Option<RequestError>`: The error, or `None` if a response was received.
+    pub fn get_error(&self) -> Option<RequestError> {
+        self.error.clone()
+    }
+
+    /// Retrieves the delay before the next attempt.
+    ///
+    /// # Returns
+    /// - `Option<Duration>`: The delay, or `None` if the request will not be retried.
+    pub fn get_delay(&self) -> Option<Duration> {
+        self.delay
+    }
+
+    /// Returns whether another attempt follows this one.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the request will be retried.
+    pub fn will_retry(&self) -> bool {
+        self.delay.is_some()
+    }
+}
@@ -0,0 +1,16 @@
+use crate::*;
+
+/// The outcome of one attempt made under a retry policy, passed to its `on_attempt` hook.
+///
+/// # Fields
+/// - `attempt`: The number of the attempt, starting at 1.
+/// - `status_code`: The status of the response, if one was received.
+/// - `error`: The error the attempt failed with, if any.
+/// - `delay`: How long until the next attempt, or `None` if this attempt is the last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryAttempt {
+    pub(crate) attempt: usize,
+    pub(crate) status_code: Option<ResponseStatusCode>,
+    pub(crate) error: Option<RequestError>,
+    pub(crate) delay: Option<Duration>,
+}
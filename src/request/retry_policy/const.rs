@@ -0,0 +1,13 @@
+use crate::*;
+
+/// The number of attempts made by a default retry policy, including the first one.
+pub(crate) const DEFAULT_RETRY_MAX_ATTEMPTS: usize = 3;
+
+/// The backoff before the first retry, in milliseconds.
+pub(crate) const DEFAULT_RETRY_INITIAL_BACKOFF: u64 = 100;
+
+/// The longest backoff between two attempts, in milliseconds.
+pub(crate) const DEFAULT_RETRY_MAX_BACKOFF: u64 = 10_000;
+
+/// The response statuses retried by default.
+pub(crate) const DEFAULT_RETRY_STATUSES: [ResponseStatusCode; 4] = [429, 502, 503, 504];
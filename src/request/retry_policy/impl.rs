@@ -0,0 +1,238 @@
+use crate::*;
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_RETRY_MAX_ATTEMPTS,
+            initial_backoff: DEFAULT_RETRY_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_RETRY_MAX_BACKOFF,
+            jitter: true,
+            retry_statuses: DEFAULT_RETRY_STATUSES.to_vec(),
+            retry_connect_errors: true,
+            retry_timeouts: true,
+            retry_non_idempotent: false,
+            on_attempt: None,
+        }
+    }
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("jitter", &self.jitter)
+            .field("retry_statuses", &self.retry_statuses)
+            .field("retry_connect_errors", &self.retry_connect_errors)
+            .field("retry_timeouts", &self.retry_timeouts)
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
+            .field("on_attempt", &self.on_attempt.is_some())
+            .finish()
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a retry policy with the default settings.
+    ///
+    /// # Returns
+    /// Returns a new instance of `RetryPolicy`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of attempts, including the first request.
+    ///
+    /// # Parameters
+    /// - `max_attempts`: The maximum number of attempts. `1` disables retries.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RetryPolicy` to allow method chaining.
+    pub fn max_attempts(&mut self, max_attempts: usize) -> &mut Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the backoff before the first retry, which doubles for every further retry.
+    ///
+    /// # Parameters
+    /// - `initial_backoff`: The first backoff in milliseconds.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RetryPolicy` to allow method chaining.
+    pub fn initial_backoff(&mut self, initial_backoff: u64) -> &mut Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the longest wait between two attempts.
+    ///
+    /// A `Retry-After` asking for a longer wait ends the retries instead.
+    ///
+    /// # Parameters
+    /// - `max_backoff`: The longest wait in milliseconds.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RetryPolicy` to allow method chaining.
+    pub fn max_backoff(&mut self, max_backoff: u64) -> &mut Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Randomizes each backoff to between half and all of its computed value.
+    ///
+    /// # Parameters
+    /// - `jitter`: Whether backoffs are randomized.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RetryPolicy` to allow method chaining.
+    pub fn jitter(&mut self, jitter: bool) -> &mut Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the response statuses that are retried.
+    ///
+    /// # Parameters
+    /// - `retry_statuses`: The statuses to retry, replacing the defaults.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RetryPolicy` to allow method chaining.
+    pub fn retry_statuses(&mut self, retry_statuses: &[ResponseStatusCode]) -> &mut Self {
+        self.retry_statuses = retry_statuses.to_vec();
+        self
+    }
+
+    /// Retries requests that fail to connect to the server or proxy.
+    ///
+    /// # Parameters
+    /// - `retry_connect_errors`: Whether connect errors are retried.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RetryPolicy` to allow method chaining.
+    pub fn retry_connect_errors(&mut self, retry_connect_errors: bool) -> &mut Self {
+        self.retry_connect_errors = retry_connect_errors;
+        self
+    }
+
+    /// Retries requests that fail with a connect, read or write timeout.
+    ///
+    /// An exceeded `total_timeout` is never retried.
+    ///
+    /// # Parameters
+    /// - `retry_timeouts`: Whether timeouts are retried.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RetryPolicy` to allow method chaining.
+    pub fn retry_timeouts(&mut self, retry_timeouts: bool) -> &mut Self {
+        self.retry_timeouts = retry_timeouts;
+        self
+    }
+
+    /// Retries requests whose method is not idempotent, such as `POST` and `PATCH`.
+    ///
+    /// # Parameters
+    /// - `retry_non_idempotent`: Whether every method is retried.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RetryPolicy` to allow method chaining.
+    pub fn retry_non_idempotent(&mut self, retry_non_idempotent: bool) -> &mut Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Sets a hook invoked after every attempt, including the last one.
+    ///
+    /// # Parameters
+    /// - `on_attempt`: The hook, called with the outcome of the attempt.
+    ///
+    /// # Returns
+    /// Returns a mutable reference to the `RetryPolicy` to allow method chaining.
+    pub fn on_attempt<F>(&mut self, on_attempt: F) -> &mut Self
+    where
+        F: Fn(&RetryAttempt) + Send + Sync + 'static,
+    {
+        self.on_attempt = Some(Arc::new(on_attempt));
+        self
+    }
+
+    /// Returns whether requests with the given method may be retried.
+    pub(crate) fn is_retryable_method(&self, methods: &Method) -> bool {
+        self.retry_non_idempotent || Self::is_idempotent_method(methods)
+    }
+
+    /// Returns whether sending a request with the given method twice has the same effect as once.
+    pub(crate) fn is_idempotent_method(methods: &Method) -> bool {
+        methods.is_get()
+            || methods.is_head()
+            || methods.is_put()
+            || methods.is_delete()
+            || methods.is_options()
+            || methods.is_trace()
+    }
+
+    /// Returns whether a response with the given status is retried.
+    pub(crate) fn is_retryable_status(&self, status_code: ResponseStatusCode) -> bool {
+        self.retry_statuses.contains(&status_code)
+    }
+
+    /// Returns whether a request failing with the given error is retried.
+    pub(crate) fn is_retryable_error(&self, error: &RequestError) -> bool {
+        match (error, get_timeout_kind(error)) {
+            (RequestError::TcpStreamConnect(_) | RequestError::GetTcpStream, _) => {
+                self.retry_connect_errors
+            }
+            (_, Some(RequestTimeoutKind::Connect)) => {
+                self.retry_connect_errors || self.retry_timeouts
+            }
+            (_, Some(RequestTimeoutKind::Deadline)) => false,
+            (_, Some(_)) => self.retry_timeouts,
+            _ => false,
+        }
+    }
+
+    /// Returns the backoff before the attempt following `attempt`.
+    ///
+    /// # Parameters
+    /// - `attempt`: The number of the attempt that just failed, starting at 1.
+    pub(crate) fn get_backoff(&self, attempt: usize) -> Duration {
+        let shift: u32 = attempt.saturating_sub(1).min(32) as u32;
+        let backoff: u64 = self
+            .initial_backoff
+            .saturating_mul(1u64 << shift)
+            .min(self.max_backoff);
+        if !self.jitter || backoff < 2 {
+            return Duration::from_millis(backoff);
+        }
+        let mut hasher: DefaultHasher = RandomState::new().build_hasher();
+        hasher.write_usize(attempt);
+        let half: u64 = backoff / 2;
+        Duration::from_millis(half + hasher.finish() % (backoff - half + 1))
+    }
+
+    /// Parses a `Retry-After` header given as delay seconds or an HTTP date.
+    ///
+    /// # Parameters
+    /// - `value`: The header value.
+    ///
+    /// # Returns
+    /// Returns the time to wait, or `None` if the value cannot be parsed.
+    pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+        let value: &str = value.trim();
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+        let date: u64 = parse_http_date(value)?;
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        Some(Duration::from_secs(date.saturating_sub(now)))
+    }
+
+    /// Passes the outcome of an attempt to the `on_attempt` hook, if one is set.
+    pub(crate) fn notify(&self, retry_attempt: &RetryAttempt) {
+        if let Some(on_attempt) = &self.on_attempt {
+            on_attempt(retry_attempt);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub(crate) mod r#const;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+pub(crate) mod r#type;
+
+pub use r#struct::*;
+
+pub(crate) use r#const::*;
+pub(crate) use r#type::*;
@@ -0,0 +1,19 @@
+use crate::*;
+
+/// Controls when a failed request is sent again and how long to wait in between.
+///
+/// The default makes up to three attempts, retrying connect errors, timeouts and `429`, `502`,
+/// `503` and `504` responses with exponential backoff and jitter, honoring `Retry-After`, and
+/// only for idempotent methods.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: usize,
+    pub(crate) initial_backoff: u64,
+    pub(crate) max_backoff: u64,
+    pub(crate) jitter: bool,
+    pub(crate) retry_statuses: Vec<ResponseStatusCode>,
+    pub(crate) retry_connect_errors: bool,
+    pub(crate) retry_timeouts: bool,
+    pub(crate) retry_non_idempotent: bool,
+    pub(crate) on_attempt: Option<RetryHook>,
+}
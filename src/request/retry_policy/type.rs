@@ -0,0 +1,4 @@
+use crate::*;
+
+/// A callback invoked after every attempt made under a retry policy.
+pub(crate) type RetryHook = Arc<dyn Fn(&RetryAttempt) + Send + Sync>;
@@ -0,0 +1,2 @@
+pub(crate) const MAX_CHUNK_LINE_SIZE: usize = 8192;
+pub(crate) const MAX_CHUNK_TRAILERS_SIZE: usize = 65536;
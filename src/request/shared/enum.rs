@@ -0,0 +1,31 @@
+use crate::*;
+
+/// Describes how the body of an HTTP/1.x response is delimited on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BodyFraming {
+    /// The response carries no body (HEAD requests, 1xx, 204 and 304 responses).
+    Empty,
+    /// The body length is given by the `Content-Length` header.
+    ContentLength(usize),
+    /// The body is sent with `Transfer-Encoding: chunked`.
+    Chunked,
+    /// The body extends until the server closes the connection.
+    UntilClose,
+}
+
+/// The position of a `ChunkedDecoder` within the chunked body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ChunkedState {
+    Size,
+    Data,
+    DataEnd,
+    Trailer,
+    Done,
+}
+
+/// Incremental decoder for a `Content-Encoding` applied to a streamed body.
+pub(crate) enum ContentDecoder {
+    Gzip(GzDecoder<Vec<u8>>),
+    Deflate(DeflateDecoder<Vec<u8>>),
+    Brotli(Box<DecompressorWriter<Vec<u8>>>),
+}
@@ -25,66 +25,179 @@ impl SharedRequestBuilder {
         }
         request
     }
-
-    pub(crate) fn build_get_request(
-        path: String,
-        header_bytes: Vec<u8>,
-        http_version_str: String,
-    ) -> Vec<u8> {
-        Self::build_http_request("GET", path, header_bytes, None, http_version_str)
-    }
-
-    pub(crate) fn build_post_request(
-        path: String,
-        header_bytes: Vec<u8>,
-        body_bytes: Vec<u8>,
-        http_version_str: String,
-    ) -> Vec<u8> {
-        Self::build_http_request(
-            "POST",
-            path,
-            header_bytes,
-            Some(body_bytes),
-            http_version_str,
-        )
-    }
 }
 
 impl SharedResponseHandler {
     pub(crate) fn parse_response_headers(
         headers_bytes: &[u8],
-        http_version_bytes: &[u8],
         location_sign_key: &[u8],
-        content_length: &mut usize,
+        is_head: bool,
+        body_framing: &mut BodyFraming,
         redirect_url: &mut Option<Vec<u8>>,
     ) -> Result<(), RequestError> {
-        if let Some(status_pos) =
-            Self::find_pattern_case_insensitive(headers_bytes, http_version_bytes)
-        {
-            let status_code_start: usize = status_pos + http_version_bytes.len() + 1;
-            let status_code_end: usize = status_code_start + 3;
-            if status_code_end <= headers_bytes.len() {
-                let status_code: usize =
-                    Self::parse_status_code(&headers_bytes[status_code_start..status_code_end]);
-
-                if (300..=399).contains(&status_code) {
-                    if let Some(location_pos) =
-                        Self::find_pattern_case_insensitive(headers_bytes, location_sign_key)
-                    {
-                        let start: usize = location_pos + location_sign_key.len();
-                        if let Some(end_pos) = Self::find_crlf(headers_bytes, start) {
-                            let mut url_vec = Vec::with_capacity(end_pos - start);
-                            url_vec.extend_from_slice(&headers_bytes[start..end_pos]);
-                            *redirect_url = Some(url_vec);
-                        }
-                    }
+        let status_code: usize = Self::get_status_code(headers_bytes);
+        if (300..=399).contains(&status_code) {
+            if let Some(location_pos) =
+                Self::find_pattern_case_insensitive(headers_bytes, location_sign_key)
+            {
+                let start: usize = location_pos + location_sign_key.len();
+                if let Some(end_pos) = Self::find_crlf(headers_bytes, start) {
+                    let mut url_vec = Vec::with_capacity(end_pos - start);
+                    url_vec.extend_from_slice(&headers_bytes[start..end_pos]);
+                    *redirect_url = Some(url_vec);
                 }
             }
         }
-        *content_length = Self::get_content_length(headers_bytes);
+        *body_framing = Self::get_body_framing(headers_bytes, status_code, is_head);
         Ok(())
     }
 
+    pub(crate) fn get_status_code(headers_bytes: &[u8]) -> usize {
+        let status_line_end: usize =
+            Self::find_crlf(headers_bytes, 0).unwrap_or(headers_bytes.len());
+        let status_line: &[u8] = &headers_bytes[..status_line_end];
+        if let Some(space_pos) = status_line.iter().position(|&byte| byte == b' ') {
+            let status_code_start: usize = space_pos + 1;
+            let status_code_end: usize = status_code_start + 3;
+            if status_code_end <= status_line.len() {
+                return Self::parse_status_code(&status_line[status_code_start..status_code_end]);
+            }
+        }
+        0
+    }
+
+    pub(crate) fn get_body_framing(
+        headers_bytes: &[u8],
+        status_code: usize,
+        is_head: bool,
+    ) -> BodyFraming {
+        if is_head || (100..=199).contains(&status_code) || status_code == 204 || status_code == 304
+        {
+            return BodyFraming::Empty;
+        }
+        if Self::is_chunked(headers_bytes) {
+            return BodyFraming::Chunked;
+        }
+        if Self::find_pattern_case_insensitive(headers_bytes, CONTENT_LENGTH_PATTERN).is_some() {
+            return BodyFraming::ContentLength(Self::get_content_length(headers_bytes));
+        }
+        BodyFraming::UntilClose
+    }
+
+    pub(crate) fn is_chunked(headers_bytes: &[u8]) -> bool {
+        if let Some(pos) =
+            Self::find_pattern_case_insensitive(headers_bytes, TRANSFER_ENCODING_PATTERN)
+        {
+            let value_start: usize = pos + TRANSFER_ENCODING_PATTERN.len();
+            if let Some(end_pos) = Self::find_crlf(headers_bytes, value_start) {
+                return headers_bytes[value_start..end_pos]
+                    .split(|&byte| byte == b',')
+                    .next_back()
+                    .map(|coding: &[u8]| coding.trim_ascii().eq_ignore_ascii_case(CHUNKED_CODING))
+                    .unwrap_or(false);
+            }
+        }
+        false
+    }
+
+    /// Returns whether the connection may be reused after this response.
+    ///
+    /// Only HTTP/1.1 responses without a `Connection: close` header keep the connection alive.
+    pub(crate) fn is_keep_alive(headers_bytes: &[u8]) -> bool {
+        if !headers_bytes.starts_with(b"HTTP/1.1 ") {
+            return false;
+        }
+        if let Some(pos) = Self::find_pattern_case_insensitive(headers_bytes, CONNECTION_PATTERN) {
+            let value_start: usize = pos + CONNECTION_PATTERN.len();
+            if let Some(end_pos) = Self::find_crlf(headers_bytes, value_start) {
+                return !headers_bytes[value_start..end_pos]
+                    .split(|&byte| byte == b',')
+                    .any(|option: &[u8]| {
+                        option.trim_ascii().eq_ignore_ascii_case(CONNECTION_CLOSE)
+                    });
+            }
+        }
+        true
+    }
+
+    /// Appends newly received body bytes according to the response framing.
+    ///
+    /// Returns `Ok(true)` once the complete body has been received.
+    pub(crate) fn read_body_bytes(
+        body_framing: &BodyFraming,
+        chunked_decoder: &mut ChunkedDecoder,
+        body: &mut Vec<u8>,
+        data: &[u8],
+    ) -> Result<bool, RequestError> {
+        match body_framing {
+            BodyFraming::Empty => Ok(true),
+            BodyFraming::ContentLength(content_length) => {
+                let take: usize = content_length.saturating_sub(body.len()).min(data.len());
+                body.extend_from_slice(&data[..take]);
+                Ok(body.len() >= *content_length)
+            }
+            BodyFraming::Chunked => {
+                chunked_decoder.decode(data, body)?;
+                Ok(chunked_decoder.is_done())
+            }
+            BodyFraming::UntilClose => {
+                body.extend_from_slice(data);
+                Ok(false)
+            }
+        }
+    }
+
+    /// Builds the response from the raw header block and the already de-framed body.
+    ///
+    /// Trailer fields received after a chunked body are merged into the headers.
+    pub(crate) fn build_response(
+        headers_bytes: &[u8],
+        chunked_decoder: &ChunkedDecoder,
+        body: Vec<u8>,
+    ) -> HttpResponseBinary {
+        let trailers: &[u8] = chunked_decoder.get_trailers();
+        if trailers.is_empty() {
+            return HttpResponseBinary::from_parts(headers_bytes, body);
+        }
+        let headers_len: usize = headers_bytes.len().saturating_sub(HTTP_BR_BYTES.len());
+        let mut head: Vec<u8> =
+            Vec::with_capacity(headers_len + trailers.len() + HTTP_BR_BYTES.len());
+        head.extend_from_slice(&headers_bytes[..headers_len]);
+        head.extend_from_slice(trailers);
+        head.extend_from_slice(HTTP_BR_BYTES);
+        HttpResponseBinary::from_parts(&head, body)
+    }
+
+    /// Splits the response head off `response_bytes` once the blank line ending it has arrived.
+    ///
+    /// The bytes received after the head are kept as the start of the body.
+    pub(crate) fn take_response_head(
+        response_bytes: &mut Vec<u8>,
+        search_start: usize,
+        location_sign_key: &[u8],
+        is_head: bool,
+    ) -> Result<Option<ResponseHead>, RequestError> {
+        let Some(pos) = Self::find_double_crlf(response_bytes, search_start) else {
+            return Ok(None);
+        };
+        let mut body_framing: BodyFraming = BodyFraming::UntilClose;
+        let mut redirect_url: Option<Vec<u8>> = None;
+        let remaining: Vec<u8> = response_bytes.split_off(pos + 4);
+        Self::parse_response_headers(
+            response_bytes,
+            location_sign_key,
+            is_head,
+            &mut body_framing,
+            &mut redirect_url,
+        )?;
+        Ok(Some(ResponseHead {
+            headers_bytes: std::mem::take(response_bytes),
+            remaining,
+            redirect_url,
+            body_framing,
+        }))
+    }
+
     pub(crate) fn find_pattern_case_insensitive(haystack: &[u8], needle: &[u8]) -> Option<usize> {
         if needle.is_empty() || haystack.len() < needle.len() {
             return None;
@@ -198,3 +311,287 @@ impl SharedResponseHandler {
         }
     }
 }
+
+impl Default for ChunkedDecoder {
+    fn default() -> Self {
+        Self {
+            state: ChunkedState::Size,
+            remaining: 0,
+            line: Vec::new(),
+            trailers: Vec::new(),
+        }
+    }
+}
+
+impl ChunkedDecoder {
+    pub(crate) fn is_done(&self) -> bool {
+        self.state == ChunkedState::Done
+    }
+
+    pub(crate) fn get_trailers(&self) -> &[u8] {
+        &self.trailers
+    }
+
+    /// Decodes as much of `input` as possible, appending chunk data to `output`.
+    ///
+    /// Chunk extensions are ignored and trailer fields are kept as raw header lines.
+    /// Returns the number of bytes consumed, which is less than `input.len()` only
+    /// when the terminating chunk and trailers ended before the input did.
+    pub(crate) fn decode(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<usize, RequestError> {
+        let mut pos: usize = 0;
+        while pos < input.len() && self.state != ChunkedState::Done {
+            match self.state {
+                ChunkedState::Size => {
+                    if let Some(line) = self.take_line(input, &mut pos)? {
+                        let chunk_size: usize = Self::parse_chunk_size(&line)?;
+                        if chunk_size == 0 {
+                            self.state = ChunkedState::Trailer;
+                        } else {
+                            self.remaining = chunk_size;
+                            self.state = ChunkedState::Data;
+                        }
+                    }
+                }
+                ChunkedState::Data => {
+                    let take: usize = self.remaining.min(input.len() - pos);
+                    output.extend_from_slice(&input[pos..pos + take]);
+                    pos += take;
+                    self.remaining -= take;
+                    if self.remaining == 0 {
+                        self.state = ChunkedState::DataEnd;
+                    }
+                }
+                ChunkedState::DataEnd => {
+                    if let Some(line) = self.take_line(input, &mut pos)? {
+                        if !line.is_empty() {
+                            return Err(RequestError::Request(
+                                "invalid chunked encoding: missing CRLF after chunk data"
+                                    .to_string(),
+                            ));
+                        }
+                        self.state = ChunkedState::Size;
+                    }
+                }
+                ChunkedState::Trailer => {
+                    if let Some(line) = self.take_line(input, &mut pos)? {
+                        if line.is_empty() {
+                            self.state = ChunkedState::Done;
+                        } else {
+                            if self.trailers.len() + line.len() > MAX_CHUNK_TRAILERS_SIZE {
+                                return Err(RequestError::Request(
+                                    "invalid chunked encoding: trailers too large".to_string(),
+                                ));
+                            }
+                            self.trailers.extend_from_slice(&line);
+                            self.trailers.extend_from_slice(HTTP_BR_BYTES);
+                        }
+                    }
+                }
+                ChunkedState::Done => {}
+            }
+        }
+        Ok(pos)
+    }
+
+    fn take_line(
+        &mut self,
+        input: &[u8],
+        pos: &mut usize,
+    ) -> Result<Option<Vec<u8>>, RequestError> {
+        let rest: &[u8] = &input[*pos..];
+        let line_end: Option<usize> = rest.iter().position(|&byte| byte == b'\n');
+        let segment: &[u8] = &rest[..line_end.unwrap_or(rest.len())];
+        if self.line.len() + segment.len() > MAX_CHUNK_LINE_SIZE {
+            return Err(RequestError::Request(
+                "invalid chunked encoding: line too long".to_string(),
+            ));
+        }
+        self.line.extend_from_slice(segment);
+        match line_end {
+            Some(end) => {
+                *pos += end + 1;
+                let mut line: Vec<u8> = std::mem::take(&mut self.line);
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+                Ok(Some(line))
+            }
+            None => {
+                *pos = input.len();
+                Ok(None)
+            }
+        }
+    }
+
+    fn parse_chunk_size(line: &[u8]) -> Result<usize, RequestError> {
+        let size_end: usize = line
+            .iter()
+            .position(|&byte| byte == b';')
+            .unwrap_or(line.len());
+        let size_bytes: &[u8] = line[..size_end].trim_ascii();
+        if size_bytes.is_empty() {
+            return Err(RequestError::Request(
+                "invalid chunked encoding: empty chunk size".to_string(),
+            ));
+        }
+        let mut size: usize = 0;
+        for &byte in size_bytes {
+            let digit: usize = match byte {
+                b'0'..=b'9' => (byte - b'0') as usize,
+                b'a'..=b'f' => (byte - b'a' + 10) as usize,
+                b'A'..=b'F' => (byte - b'A' + 10) as usize,
+                _ => {
+                    return Err(RequestError::Request(format!(
+                        "invalid chunked encoding: bad chunk size {}",
+                        String::from_utf8_lossy(size_bytes)
+                    )));
+                }
+            };
+            size = size
+                .checked_mul(16)
+                .and_then(|size: usize| size.checked_add(digit))
+                .ok_or_else(|| {
+                    RequestError::Request(
+                        "invalid chunked encoding: chunk size overflow".to_string(),
+                    )
+                })?;
+        }
+        Ok(size)
+    }
+}
+
+impl ContentDecoder {
+    /// Returns a decoder for the `Content-Encoding` of the response head, if it is supported.
+    pub(crate) fn from_headers(headers_bytes: &[u8], buffer_size: usize) -> Option<Self> {
+        let pos: usize = SharedResponseHandler::find_pattern_case_insensitive(
+            headers_bytes,
+            CONTENT_ENCODING_PATTERN,
+        )?;
+        let value_start: usize = pos + CONTENT_ENCODING_PATTERN.len();
+        let value_end: usize = SharedResponseHandler::find_crlf(headers_bytes, value_start)?;
+        let value: String = String::from_utf8_lossy(&headers_bytes[value_start..value_end])
+            .trim()
+            .to_string();
+        match value.parse::<Compress>().unwrap_or_default() {
+            Compress::Gzip => Some(Self::Gzip(GzDecoder::new(Vec::new()))),
+            Compress::Deflate => Some(Self::Deflate(DeflateDecoder::new(Vec::new()))),
+            Compress::Br => Some(Self::Brotli(Box::new(DecompressorWriter::new(
+                Vec::new(),
+                buffer_size,
+            )))),
+            Compress::Unknown => None,
+        }
+    }
+
+    /// Decodes `data`, appending whatever output is already available to `output`.
+    pub(crate) fn write(&mut self, data: &[u8], output: &mut Vec<u8>) -> Result<(), RequestError> {
+        let result: std::io::Result<()> = match self {
+            Self::Gzip(decoder) => decoder.write_all(data).and_then(|_| decoder.flush()),
+            Self::Deflate(decoder) => decoder.write_all(data).and_then(|_| decoder.flush()),
+            Self::Brotli(decoder) => decoder.write_all(data).and_then(|_| decoder.flush()),
+        };
+        result.map_err(|err| {
+            RequestError::Request(format!("failed to decode response body: {}", err))
+        })?;
+        let decoded: &mut Vec<u8> = match self {
+            Self::Gzip(decoder) => decoder.get_mut(),
+            Self::Deflate(decoder) => decoder.get_mut(),
+            Self::Brotli(decoder) => decoder.get_mut(),
+        };
+        output.append(decoded);
+        Ok(())
+    }
+
+    /// Completes decoding, failing when the encoded body was truncated.
+    pub(crate) fn finish(self, output: &mut Vec<u8>) -> Result<(), RequestError> {
+        let decoded: Result<Vec<u8>, String> = match self {
+            Self::Gzip(decoder) => decoder.finish().map_err(|err| err.to_string()),
+            Self::Deflate(decoder) => decoder.finish().map_err(|err| err.to_string()),
+            Self::Brotli(decoder) => decoder
+                .into_inner()
+                .map_err(|_| "incomplete brotli stream".to_string()),
+        };
+        let mut decoded: Vec<u8> = decoded.map_err(|err| {
+            RequestError::Request(format!("failed to decode response body: {}", err))
+        })?;
+        output.append(&mut decoded);
+        Ok(())
+    }
+}
+
+impl StreamBodyDecoder {
+    pub(crate) fn new(body_framing: BodyFraming, content_decoder: Option<ContentDecoder>) -> Self {
+        let done: bool = matches!(
+            body_framing,
+            BodyFraming::Empty | BodyFraming::ContentLength(0)
+        );
+        Self {
+            body_framing,
+            chunked_decoder: ChunkedDecoder::default(),
+            received: 0,
+            content_decoder: if done { None } else { content_decoder },
+            done,
+        }
+    }
+
+    pub(crate) fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Decodes newly received body bytes, appending the body data they carry to `output`.
+    pub(crate) fn feed(&mut self, data: &[u8], output: &mut Vec<u8>) -> Result<(), RequestError> {
+        if self.done {
+            return Ok(());
+        }
+        let mut body: Vec<u8> = Vec::with_capacity(data.len());
+        match self.body_framing {
+            BodyFraming::Empty => self.done = true,
+            BodyFraming::ContentLength(content_length) => {
+                let take: usize = content_length.saturating_sub(self.received).min(data.len());
+                body.extend_from_slice(&data[..take]);
+                self.received += take;
+                self.done = self.received >= content_length;
+            }
+            BodyFraming::Chunked => {
+                self.chunked_decoder.decode(data, &mut body)?;
+                self.done = self.chunked_decoder.is_done();
+            }
+            BodyFraming::UntilClose => body.extend_from_slice(data),
+        }
+        match self.content_decoder.as_mut() {
+            Some(content_decoder) => content_decoder.write(&body, output)?,
+            None => output.append(&mut body),
+        }
+        if self.done {
+            self.finish_content(output)?;
+        }
+        Ok(())
+    }
+
+    /// Handles the end of the connection, failing when the body was cut short.
+    pub(crate) fn finish_eof(&mut self, output: &mut Vec<u8>) -> Result<(), RequestError> {
+        if self.done {
+            return Ok(());
+        }
+        match self.body_framing {
+            BodyFraming::Empty | BodyFraming::UntilClose => {
+                self.done = true;
+                self.finish_content(output)
+            }
+            BodyFraming::ContentLength(_) | BodyFraming::Chunked => Err(RequestError::Request(
+                "connection closed before the response body was complete".to_string(),
+            )),
+        }
+    }
+
+    fn finish_content(&mut self, output: &mut Vec<u8>) -> Result<(), RequestError> {
+        match self.content_decoder.take() {
+            Some(content_decoder) => content_decoder.finish(output),
+            None => Ok(()),
+        }
+    }
+}
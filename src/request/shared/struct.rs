@@ -1,2 +1,33 @@
+use crate::*;
+
 pub(crate) struct SharedRequestBuilder;
 pub(crate) struct SharedResponseHandler;
+
+/// Incremental decoder for `Transfer-Encoding: chunked` bodies.
+#[derive(Debug, Clone)]
+pub(crate) struct ChunkedDecoder {
+    pub(crate) state: ChunkedState,
+    pub(crate) remaining: usize,
+    pub(crate) line: Vec<u8>,
+    pub(crate) trailers: Vec<u8>,
+}
+
+/// The parsed head of an HTTP/1.x response read ahead of its body.
+#[derive(Debug, Clone)]
+pub(crate) struct ResponseHead {
+    pub(crate) headers_bytes: Vec<u8>,
+    pub(crate) remaining: Vec<u8>,
+    pub(crate) redirect_url: Option<Vec<u8>>,
+    pub(crate) body_framing: BodyFraming,
+}
+
+/// Incremental decoder turning raw body bytes into response body data.
+///
+/// Removes the transfer framing and, when requested, the content encoding.
+pub(crate) struct StreamBodyDecoder {
+    pub(crate) body_framing: BodyFraming,
+    pub(crate) chunked_decoder: ChunkedDecoder,
+    pub(crate) received: usize,
+    pub(crate) content_decoder: Option<ContentDecoder>,
+    pub(crate) done: bool,
+}
@@ -1,4 +1,3 @@
-pub(crate) mod r#impl;
 pub(crate) mod r#struct;
 
 pub(crate) use r#struct::*;
@@ -1,7 +1,12 @@
 use crate::*;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Tmp {
     pub visit_url: HashSet<String>,
-    pub root_cert: RootCertStore,
+    pub redirect_history: Vec<RedirectHop>,
+    pub(crate) tls: TlsConfig,
+    pub(crate) tls_info: Option<TlsInfo>,
+    pub(crate) deadline: Option<Instant>,
+    pub(crate) digest_retried: bool,
+    pub(crate) auth_origin: Option<(bool, String, u16)>,
 }
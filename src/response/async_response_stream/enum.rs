@@ -0,0 +1,15 @@
+use crate::*;
+
+/// The source the body of an `AsyncResponseStream` is read from.
+pub(crate) enum AsyncResponseStreamSource {
+    /// An HTTP/1.x connection, returned to the client pool once the body is complete.
+    Http1 {
+        connection: Box<PooledConnection<BoxAsyncReadWrite>>,
+        client: Option<Client>,
+        keep_alive: bool,
+    },
+    /// An HTTP/2 stream, whose end marks the end of the body.
+    Http2(RecvStream),
+    /// The body has been read completely or the connection has failed.
+    Closed,
+}
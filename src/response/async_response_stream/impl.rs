@@ -0,0 +1,193 @@
+use crate::*;
+
+impl Debug for AsyncResponseStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncResponseStream")
+            .field("response", &self.response)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Yields the decoded response body in chunks, reading from the connection as it is polled.
+impl Stream for AsyncResponseStream {
+    type Item = Result<Vec<u8>, RequestError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let response_stream: &mut Self = self.get_mut();
+        if let Some(pending) = response_stream.pending.take() {
+            return Poll::Ready(Some(Ok(pending)));
+        }
+        loop {
+            if response_stream.decoder.is_done() {
+                response_stream.release();
+                return Poll::Ready(None);
+            }
+            let mut output: Vec<u8> = Vec::new();
+            let result: Result<(), RequestError> = match response_stream.poll_source(cx) {
+                Poll::Pending => {
+                    let timeouts: RequestTimeouts = response_stream.timeouts;
+                    let read_deadline: &mut Pin<Box<Sleep>> =
+                        response_stream.read_deadline.get_or_insert_with(|| {
+                            Box::pin(sleep(timeouts.get_duration(TimeoutKind::Read)))
+                        });
+                    if read_deadline.as_mut().poll(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+                    Err(timeouts.get_error(TimeoutKind::Read, READING_RESPONSE))
+                }
+                Poll::Ready(Ok(data)) if data.is_empty() => {
+                    response_stream.decoder.finish_eof(&mut output)
+                }
+                Poll::Ready(Ok(data)) => response_stream.decoder.feed(&data, &mut output),
+                Poll::Ready(Err(err)) => Err(err),
+            };
+            response_stream.read_deadline = None;
+            if let Err(err) = result {
+                response_stream.source = AsyncResponseStreamSource::Closed;
+                response_stream.decoder.done = true;
+                return Poll::Ready(Some(Err(err)));
+            }
+            if !output.is_empty() {
+                return Poll::Ready(Some(Ok(output)));
+            }
+        }
+    }
+}
+
+impl AsyncResponseStream {
+    /// Creates a response stream from the response head and the body bytes received with it.
+    pub(crate) fn new(
+        response: HttpResponseBinary,
+        source: AsyncResponseStreamSource,
+        mut decoder: StreamBodyDecoder,
+        remaining: &[u8],
+        buffer_size: usize,
+        timeouts: RequestTimeouts,
+    ) -> Result<Self, RequestError> {
+        let mut pending: Vec<u8> = Vec::new();
+        decoder.feed(remaining, &mut pending)?;
+        let mut response_stream: Self = Self {
+            response,
+            source,
+            decoder,
+            buffer: vec![0; buffer_size],
+            pending: (!pending.is_empty()).then_some(pending),
+            timeouts,
+            read_deadline: None,
+        };
+        if response_stream.decoder.is_done() {
+            response_stream.release();
+        }
+        Ok(response_stream)
+    }
+
+    /// Creates a response stream that yields the body of an already complete response.
+    pub(crate) fn from_response(
+        mut response: HttpResponseBinary,
+        timeouts: RequestTimeouts,
+    ) -> Self {
+        let pending: Vec<u8> = response.get_body();
+        response.set_body(Vec::new());
+        Self {
+            response,
+            source: AsyncResponseStreamSource::Closed,
+            decoder: StreamBodyDecoder::new(BodyFraming::Empty, None),
+            buffer: Vec::new(),
+            pending: (!pending.is_empty()).then_some(pending),
+            timeouts,
+            read_deadline: None,
+        }
+    }
+
+    /// Retrieves the response head, with an empty body.
+    ///
+    /// # Returns
+    /// - `HttpResponseBinary`: The status line, headers and redirect history of the response.
+    pub fn get_response(&self) -> HttpResponseBinary {
+        self.response.clone()
+    }
+
+    /// Retrieves the HTTP status code associated with this response.
+    ///
+    /// # Returns
+    /// - `ResponseStatusCode`: The HTTP status code as a usize (e.g., 200 for OK, 404 for Not Found).
+    pub fn get_status_code(&self) -> ResponseStatusCode {
+        self.response.get_status_code()
+    }
+
+    /// Retrieves the headers of the HTTP response.
+    ///
+    /// # Returns
+    /// - `ResponseHeaders`: A map of header names and their corresponding values as key-value pairs.
+    pub fn get_headers(&self) -> ResponseHeaders {
+        self.response.get_headers()
+    }
+
+    /// Retrieves every header of the HTTP response, keeping all values of repeated headers.
+    ///
+    /// # Returns
+    /// - `ResponseHeaderMap`: The headers in the order received, looked up case-insensitively.
+    pub fn get_header_map(&self) -> ResponseHeaderMap {
+        self.response.get_header_map()
+    }
+
+    /// Returns whether the body can be drained to reuse its connection.
+    pub(crate) fn is_reusable(&self) -> bool {
+        matches!(
+            self.source,
+            AsyncResponseStreamSource::Http1 {
+                keep_alive: true,
+                ..
+            }
+        ) && self.decoder.body_framing != BodyFraming::UntilClose
+    }
+
+    /// Polls the connection for more body bytes; an empty result marks the end of the body.
+    fn poll_source(&mut self, cx: &mut Context<'_>) -> Poll<Result<Vec<u8>, RequestError>> {
+        match &mut self.source {
+            AsyncResponseStreamSource::Http1 { connection, .. } => {
+                let mut read_buf: tokio::io::ReadBuf<'_> =
+                    tokio::io::ReadBuf::new(&mut self.buffer);
+                match Pin::new(&mut connection.stream).poll_read(cx, &mut read_buf) {
+                    Poll::Pending => Poll::Pending,
+                    Poll::Ready(Ok(())) => Poll::Ready(Ok(read_buf.filled().to_vec())),
+                    Poll::Ready(Err(err)) => {
+                        Poll::Ready(Err(RequestError::Request(err.to_string())))
+                    }
+                }
+            }
+            AsyncResponseStreamSource::Http2(recv_stream) => loop {
+                match recv_stream.poll_data(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Some(Ok(data))) if data.is_empty() => continue,
+                    Poll::Ready(Some(Ok(data))) => {
+                        let _ = recv_stream.flow_control().release_capacity(data.len());
+                        return Poll::Ready(Ok(data.to_vec()));
+                    }
+                    Poll::Ready(Some(Err(err))) => {
+                        return Poll::Ready(Err(RequestError::Request(err.to_string())));
+                    }
+                    Poll::Ready(None) => return Poll::Ready(Ok(Vec::new())),
+                }
+            },
+            AsyncResponseStreamSource::Closed => Poll::Ready(Ok(Vec::new())),
+        }
+    }
+
+    /// Returns a completely read keep-alive connection to the client pool.
+    fn release(&mut self) {
+        let reusable: bool = self.is_reusable();
+        if let AsyncResponseStreamSource::Http1 {
+            connection,
+            client: Some(client),
+            ..
+        } = std::mem::replace(&mut self.source, AsyncResponseStreamSource::Closed)
+            && reusable
+            && let (Some(key), Some(permit)) = (connection.key, connection.permit)
+        {
+            client
+                .pool
+                .release_async(key, connection.stream, permit, connection.tls_info);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::*;
+
+/// A response whose body is read incrementally as a `futures::Stream` of byte chunks.
+///
+/// Returned by `AsyncRequestTrait::send_stream` once the status line and headers have been
+/// received. The body is read from the connection as the stream is polled, with chunked
+/// transfer coding removed and, when decoding is enabled, the content encoding decoded.
+///
+/// # Fields
+/// - `response`: The response head, with an empty body.
+/// - `source`: Where the remaining body is read from.
+/// - `decoder`: Removes the body framing and content encoding.
+/// - `buffer`: The buffer connection reads are made into.
+/// - `pending`: Body data decoded along with the head, yielded first.
+/// - `timeouts`: The read timeout and overall deadline of the request.
+/// - `read_deadline`: The timer of the read currently waiting for data.
+pub struct AsyncResponseStream {
+    pub(crate) response: HttpResponseBinary,
+    pub(crate) source: AsyncResponseStreamSource,
+    pub(crate) decoder: StreamBodyDecoder,
+    pub(crate) buffer: Vec<u8>,
+    pub(crate) pending: Option<Vec<u8>>,
+    pub(crate) timeouts: RequestTimeouts,
+    pub(crate) read_deadline: Option<Pin<Box<Sleep>>>,
+}
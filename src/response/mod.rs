@@ -1,9 +1,20 @@
+pub(crate) mod async_response_stream;
+pub(crate) mod redirect_hop;
 pub(crate) mod response_binary;
+pub(crate) mod response_header_map;
+pub(crate) mod response_stream;
 pub(crate) mod response_text;
 pub(crate) mod r#trait;
 pub(crate) mod r#type;
 
+pub use async_response_stream::*;
+pub use redirect_hop::*;
 pub use response_binary::*;
+pub use response_header_map::*;
+pub use response_stream::*;
 pub use response_text::*;
 pub use r#trait::*;
 pub use r#type::*;
+
+pub(crate) use async_response_stream::AsyncResponseStreamSource;
+pub(crate) use response_stream::{ResponseStreamSource, StreamRuntime};
@@ -0,0 +1,27 @@
+use crate::*;
+
+impl RedirectHop {
+    /// Retrieves the URL that was answered with the redirect.
+    ///
+    /// # Returns
+    /// - `String`: The requested URL of this hop.
+    pub fn get_url(&self) -> String {
+        self.url.clone()
+    }
+
+    /// Retrieves the status code of the redirect response.
+    ///
+    /// # Returns
+    /// - `ResponseStatusCode`: The redirect status code (e.g., 301 for Moved Permanently).
+    pub fn get_status_code(&self) -> ResponseStatusCode {
+        self.status_code
+    }
+
+    /// Retrieves the headers of the redirect response.
+    ///
+    /// # Returns
+    /// - `ResponseHeaders`: A map of header names and their corresponding values.
+    pub fn get_headers(&self) -> ResponseHeaders {
+        self.headers.clone()
+    }
+}
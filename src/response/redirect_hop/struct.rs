@@ -0,0 +1,14 @@
+use crate::*;
+
+/// A redirect response that was followed on the way to the final response.
+///
+/// # Fields
+/// - `url`: The URL that was requested and answered with the redirect.
+/// - `status_code`: The redirect status code (e.g., 301, 302).
+/// - `headers`: The headers of the redirect response, including its `Location`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedirectHop {
+    pub(crate) url: String,
+    pub(crate) status_code: ResponseStatusCode,
+    pub(crate) headers: ResponseHeaders,
+}
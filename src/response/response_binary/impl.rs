@@ -51,6 +51,7 @@ impl ResponseTrait for HttpResponseBinary {
             },
         );
         let mut headers: HashMapXxHash3_64<String, String> = hash_map_xx_hash3_64();
+        let mut header_map: ResponseHeaderMap = ResponseHeaderMap::new();
         for line in lines.by_ref() {
             if line.is_empty() {
                 break;
@@ -75,6 +76,7 @@ impl ResponseTrait for HttpResponseBinary {
                         (from_utf8(key_bytes), from_utf8(value_bytes))
                     {
                         headers.insert(key_str.trim().to_string(), value_str.trim().to_string());
+                        header_map.append(key_str.trim().to_string(), value_str.trim().to_string());
                     }
                 }
             }
@@ -111,7 +113,10 @@ impl ResponseTrait for HttpResponseBinary {
             status_code,
             status_text: Arc::new(RwLock::new(status_text)),
             headers: Arc::new(RwLock::new(headers)),
+            header_map: Arc::new(RwLock::new(header_map)),
             body: Arc::new(RwLock::new(body)),
+            redirect_history: Arc::new(RwLock::new(Vec::new())),
+            tls_info: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -128,7 +133,10 @@ impl ResponseTrait for HttpResponseBinary {
             status_code: self.status_code,
             status_text: Arc::clone(&self.status_text),
             headers: Arc::clone(&self.headers),
+            header_map: Arc::clone(&self.header_map),
             body: Arc::new(RwLock::new(body)),
+            redirect_history: Arc::clone(&self.redirect_history),
+            tls_info: Arc::clone(&self.tls_info),
         }
     }
 
@@ -148,9 +156,20 @@ impl ResponseTrait for HttpResponseBinary {
             status_code: self.status_code,
             status_text: Arc::clone(&self.status_text),
             headers: Arc::clone(&self.headers),
+            header_map: Arc::clone(&self.header_map),
             body: Arc::new(RwLock::new(decoded_body)),
+            redirect_history: Arc::clone(&self.redirect_history),
+            tls_info: Arc::clone(&self.tls_info),
         }
     }
+
+    fn redirect_history(&self) -> Vec<RedirectHop> {
+        self.get_redirect_history()
+    }
+
+    fn tls_info(&self) -> Option<TlsInfo> {
+        self.get_tls_info()
+    }
 }
 
 impl HttpResponseBinary {
@@ -198,6 +217,33 @@ impl HttpResponseBinary {
         return hash_map_xx_hash3_64();
     }
 
+    /// Retrieves every header of the HTTP response, keeping all values of repeated headers.
+    ///
+    /// # Returns
+    /// - `ResponseHeaderMap`: The headers in the order received, looked up case-insensitively.
+    pub fn get_header_map(&self) -> ResponseHeaderMap {
+        self.header_map
+            .read()
+            .map_or(ResponseHeaderMap::new(), |header_map| header_map.clone())
+    }
+
+    /// Retrieves every value of a header in the order received, matching the name case-insensitively.
+    ///
+    /// # Parameters
+    /// - `name`: The header name, such as `Set-Cookie`.
+    ///
+    /// # Returns
+    /// - `Vec<String>`: All values of the header; empty if it is absent.
+    pub fn get_header_all(&self, name: &str) -> Vec<String> {
+        self.header_map.read().map_or(Vec::new(), |header_map| {
+            header_map
+                .get_all(name)
+                .into_iter()
+                .map(str::to_string)
+                .collect()
+        })
+    }
+
     /// Retrieves the body content of the HTTP response.
     ///
     /// # Returns
@@ -208,6 +254,102 @@ impl HttpResponseBinary {
         }
         return RequestBody::new();
     }
+
+    /// Retrieves the redirects followed before this response, in the order they happened.
+    ///
+    /// # Returns
+    /// - `Vec<RedirectHop>`: The URL, status code and headers of every redirect hop.
+    pub fn get_redirect_history(&self) -> Vec<RedirectHop> {
+        self.redirect_history
+            .read()
+            .map_or(Vec::new(), |redirect_history| redirect_history.clone())
+    }
+
+    /// Retrieves the TLS session the response was received over.
+    ///
+    /// # Returns
+    /// - `Option<TlsInfo>`: The protocol version, cipher suite, ALPN protocol and server
+    ///   certificate chain, or `None` if the response was received over plaintext.
+    pub fn get_tls_info(&self) -> Option<TlsInfo> {
+        self.tls_info
+            .read()
+            .ok()
+            .and_then(|tls_info| tls_info.clone())
+    }
+
+    /// Creates an empty HTTP/1.1 response with the given status, such as a synthetic response
+    /// returned by a middleware.
+    ///
+    /// # Parameters
+    /// - `status_code`: The status code of the response.
+    ///
+    /// # Returns
+    /// - `HttpResponseBinary`: A response without headers or body.
+    pub fn new(status_code: ResponseStatusCode) -> Self {
+        let mut response: Self = Self {
+            http_version: Arc::new(RwLock::new(HttpVersion::HTTP1_1)),
+            ..Self::default()
+        };
+        response.set_status_code(status_code);
+        response
+    }
+
+    /// Sets the status code of the response and the matching status text.
+    ///
+    /// # Parameters
+    /// - `status_code`: The new status code.
+    ///
+    /// # Returns
+    /// - `&mut Self`: The response, to allow method chaining.
+    pub fn set_status_code(&mut self, status_code: ResponseStatusCode) -> &mut Self {
+        self.status_code = status_code;
+        self.status_text = Arc::new(RwLock::new(HttpStatus::phrase(status_code)));
+        self
+    }
+
+    /// Sets a header of the response, replacing every value it had, matching the name
+    /// case-insensitively.
+    ///
+    /// # Parameters
+    /// - `name`: The header name.
+    /// - `value`: The header value.
+    ///
+    /// # Returns
+    /// - `&mut Self`: The response, to allow method chaining.
+    pub fn set_header(&mut self, name: &str, value: &str) -> &mut Self {
+        let mut headers: ResponseHeaders = self.get_headers();
+        headers.retain(|key, _| !key.eq_ignore_ascii_case(name));
+        headers.insert(name.to_string(), value.to_string());
+        let mut header_map: ResponseHeaderMap = self.get_header_map();
+        header_map.remove(name);
+        header_map.append(name.to_string(), value.to_string());
+        self.headers = Arc::new(RwLock::new(headers));
+        self.header_map = Arc::new(RwLock::new(header_map));
+        self
+    }
+
+    /// Replaces the body of the response.
+    ///
+    /// # Parameters
+    /// - `body`: The new body.
+    ///
+    /// # Returns
+    /// - `&mut Self`: The response, to allow method chaining.
+    pub fn set_body<T: Into<Vec<u8>>>(&mut self, body: T) -> &mut Self {
+        self.body = Arc::new(RwLock::new(body.into()));
+        self
+    }
+
+    /// Builds a response from its raw header block and an already de-framed body.
+    ///
+    /// Unlike `ResponseTrait::from`, the body is taken verbatim and never split on line breaks.
+    pub(crate) fn from_parts(headers_bytes: &[u8], body: Vec<u8>) -> Self {
+        let response: Self = <Self as ResponseTrait>::from(headers_bytes);
+        if let Ok(mut response_body) = response.body.write() {
+            *response_body = body;
+        }
+        response
+    }
 }
 
 impl Default for HttpResponseBinary {
@@ -217,7 +359,10 @@ impl Default for HttpResponseBinary {
             status_code: HttpStatus::Unknown.code(),
             status_text: Arc::new(RwLock::new(HttpStatus::Unknown.to_string())),
             headers: Arc::new(RwLock::new(hash_map_xx_hash3_64())),
+            header_map: Arc::new(RwLock::new(ResponseHeaderMap::new())),
             body: Arc::new(RwLock::new(Vec::new())),
+            redirect_history: Arc::new(RwLock::new(Vec::new())),
+            tls_info: Arc::new(RwLock::new(None)),
         }
     }
 }
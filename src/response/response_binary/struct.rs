@@ -11,13 +11,19 @@ use crate::*;
 /// - `status_code`: The HTTP status code (e.g., 200 for OK, 404 for Not Found).
 /// - `status_text`: A string containing the status text associated with the status code (e.g., "OK", "Not Found").
 /// - `headers`: A `HashMap<String, String>` containing the headers of the response, where each key is the header name
-///   (e.g., "Content-Type"), and the value is the corresponding header value.
+///   (e.g., "Content-Type"), and the value is the corresponding header value. Repeated headers keep their last value.
+/// - `header_map`: Every header value in the order received, including repeated headers.
 /// - `body`: A `Vec<u8>` representing the body of the HTTP response, which contains the content being returned.
+/// - `redirect_history`: The redirects followed before this response, in the order they happened.
+/// - `tls_info`: The TLS session the response was received over, if any.
 #[derive(Debug, Clone)]
 pub struct HttpResponseBinary {
     pub(crate) http_version: ArcRwLock<HttpVersion>,
     pub(crate) status_code: ResponseStatusCode,
     pub(crate) status_text: ArcRwLock<String>,
     pub(crate) headers: ArcRwLock<ResponseHeaders>,
+    pub(crate) header_map: ArcRwLock<ResponseHeaderMap>,
     pub(crate) body: ArcRwLock<RequestBody>,
+    pub(crate) redirect_history: ArcRwLock<Vec<RedirectHop>>,
+    pub(crate) tls_info: ArcRwLock<Option<TlsInfo>>,
 }
@@ -0,0 +1,85 @@
+use crate::*;
+
+impl ResponseHeaderMap {
+    /// Creates an empty header map.
+    ///
+    /// # Returns
+    /// Returns a new instance of `ResponseHeaderMap`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a header value, keeping any value already present for the same name.
+    ///
+    /// # Parameters
+    /// - `name`: The header name.
+    /// - `value`: The header value.
+    pub(crate) fn append(&mut self, name: String, value: String) {
+        self.entries.push((name, value));
+    }
+
+    /// Removes every value of a header, matching the name case-insensitively.
+    ///
+    /// # Parameters
+    /// - `name`: The header name.
+    pub(crate) fn remove(&mut self, name: &str) {
+        self.entries
+            .retain(|(entry_name, _)| !entry_name.eq_ignore_ascii_case(name));
+    }
+
+    /// Retrieves the first value of a header, matching the name case-insensitively.
+    ///
+    /// # Parameters
+    /// - `name`: The header name.
+    ///
+    /// # Returns
+    /// - `Option<&str>`: The first value received for the header, if any.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Retrieves every value of a header in the order received, matching the name case-insensitively.
+    ///
+    /// # Parameters
+    /// - `name`: The header name.
+    ///
+    /// # Returns
+    /// - `Vec<&str>`: All values received for the header; empty if it is absent.
+    pub fn get_all(&self, name: &str) -> Vec<&str> {
+        self.entries
+            .iter()
+            .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+            .collect()
+    }
+
+    /// Returns whether the header is present, matching the name case-insensitively.
+    ///
+    /// # Parameters
+    /// - `name`: The header name.
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns an iterator over every header name and value, in the order received.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Returns the number of header values, counting each repeated value.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the map contains no headers.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
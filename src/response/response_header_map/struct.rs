@@ -0,0 +1,9 @@
+/// An ordered, case-insensitive map of response headers that keeps every value of repeated
+/// headers such as `Set-Cookie`, `Link`, `WWW-Authenticate` or `Via`.
+///
+/// # Fields
+/// - `entries`: The header names and values, in the order they were received.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseHeaderMap {
+    pub(crate) entries: Vec<(String, String)>,
+}
@@ -0,0 +1,27 @@
+use crate::*;
+
+/// The runtime driving an HTTP/2 response read through a blocking `ResponseStream`.
+pub(crate) enum StreamRuntime {
+    /// A runtime created for a request sent without a client, taken when it is shut down.
+    Owned(Option<Runtime>),
+    /// The runtime of the client whose connection carries the response.
+    Client(Client),
+}
+
+/// The source the body of a `ResponseStream` is read from.
+pub(crate) enum ResponseStreamSource {
+    /// An HTTP/1.x connection, returned to the client pool once the body is complete.
+    Http1 {
+        connection: Box<PooledConnection<BoxReadWrite>>,
+        client: Option<Client>,
+        decoder: Box<StreamBodyDecoder>,
+        keep_alive: bool,
+    },
+    /// An HTTP/2 stream, read by blocking on the runtime driving its connection.
+    Http2 {
+        stream: Box<AsyncResponseStream>,
+        runtime: StreamRuntime,
+    },
+    /// The body has been read completely or the connection has failed.
+    Closed,
+}
@@ -0,0 +1,234 @@
+use crate::*;
+
+impl Debug for ResponseStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseStream")
+            .field("response", &self.response)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Reads the decoded response body, fetching more from the connection as needed.
+impl Read for ResponseStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.pending_pos >= self.pending.len() {
+            match self.next_chunk() {
+                Ok(Some(chunk)) => {
+                    self.pending = chunk;
+                    self.pending_pos = 0;
+                }
+                Ok(None) => return Ok(0),
+                Err(err) => return Err(std::io::Error::other(err.to_string())),
+            }
+        }
+        let size: usize = buf.len().min(self.pending.len() - self.pending_pos);
+        buf[..size].copy_from_slice(&self.pending[self.pending_pos..self.pending_pos + size]);
+        self.pending_pos += size;
+        Ok(size)
+    }
+}
+
+impl StreamRuntime {
+    /// Returns the runtime of `client`, or a new runtime for a request sent without one.
+    pub(crate) fn new(client: Option<Client>) -> Result<Self, RequestError> {
+        match client {
+            Some(client) => Ok(Self::Client(client)),
+            None => RuntimeBuilder::new_current_thread()
+                .enable_all()
+                .build()
+                .map(|runtime| Self::Owned(Some(runtime)))
+                .map_err(|err| RequestError::Unknown(err.to_string())),
+        }
+    }
+
+    /// Runs a future to completion on this runtime.
+    ///
+    /// A thread cannot block on a runtime while it is already driving one, so when called from
+    /// async code the future is run from a separate thread instead.
+    pub(crate) fn block_on<F>(&self, future: F) -> Result<F::Output, RequestError>
+    where
+        F: Future + Send,
+        F::Output: Send,
+    {
+        match self {
+            Self::Owned(runtime) => {
+                let runtime: &Runtime = runtime
+                    .as_ref()
+                    .ok_or_else(|| RequestError::Unknown("runtime shut down".to_string()))?;
+                Ok(Self::run_blocking(|| runtime.block_on(future)))
+            }
+            Self::Client(client) => {
+                let handle: RuntimeHandle = client.pool.get_runtime()?;
+                Ok(Self::run_blocking(|| handle.block_on(future)))
+            }
+        }
+    }
+
+    /// Runs `block_on` on this thread, or on a separate thread when called from async code.
+    fn run_blocking<T, F>(block_on: F) -> T
+    where
+        T: Send,
+        F: FnOnce() -> T + Send,
+    {
+        if RuntimeHandle::try_current().is_err() {
+            return block_on();
+        }
+        thread_scope(|scope| {
+            scope
+                .spawn(block_on)
+                .join()
+                .unwrap_or_else(|panic| resume_unwind(panic))
+        })
+    }
+}
+
+/// Shuts an owned runtime down without blocking, so a stream may be dropped inside async code.
+impl Drop for StreamRuntime {
+    fn drop(&mut self) {
+        if let Self::Owned(runtime) = self
+            && let Some(runtime) = runtime.take()
+        {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+impl ResponseStream {
+    /// Creates a response stream from the response head and the body bytes received with it.
+    pub(crate) fn new(
+        response: HttpResponseBinary,
+        mut source: ResponseStreamSource,
+        remaining: &[u8],
+        buffer_size: usize,
+    ) -> Result<Self, RequestError> {
+        let mut pending: Vec<u8> = Vec::new();
+        let mut done: bool = false;
+        if let ResponseStreamSource::Http1 { decoder, .. } = &mut source {
+            decoder.feed(remaining, &mut pending)?;
+            done = decoder.is_done();
+        }
+        let mut response_stream: Self = Self {
+            response,
+            source,
+            buffer: vec![0; buffer_size],
+            pending,
+            pending_pos: 0,
+        };
+        if done {
+            response_stream.release();
+        }
+        Ok(response_stream)
+    }
+
+    /// Creates a response stream that reads the body of an already complete response.
+    pub(crate) fn from_response(mut response: HttpResponseBinary) -> Self {
+        let pending: Vec<u8> = response.get_body();
+        response.set_body(Vec::new());
+        Self {
+            response,
+            source: ResponseStreamSource::Closed,
+            buffer: Vec::new(),
+            pending,
+            pending_pos: 0,
+        }
+    }
+
+    /// Retrieves the response head, with an empty body.
+    ///
+    /// # Returns
+    /// - `HttpResponseBinary`: The status line, headers and redirect history of the response.
+    pub fn get_response(&self) -> HttpResponseBinary {
+        self.response.clone()
+    }
+
+    /// Retrieves the HTTP status code associated with this response.
+    ///
+    /// # Returns
+    /// - `ResponseStatusCode`: The HTTP status code as a usize (e.g., 200 for OK, 404 for Not Found).
+    pub fn get_status_code(&self) -> ResponseStatusCode {
+        self.response.get_status_code()
+    }
+
+    /// Retrieves the headers of the HTTP response.
+    ///
+    /// # Returns
+    /// - `ResponseHeaders`: A map of header names and their corresponding values as key-value pairs.
+    pub fn get_headers(&self) -> ResponseHeaders {
+        self.response.get_headers()
+    }
+
+    /// Retrieves every header of the HTTP response, keeping all values of repeated headers.
+    ///
+    /// # Returns
+    /// - `ResponseHeaderMap`: The headers in the order received, looked up case-insensitively.
+    pub fn get_header_map(&self) -> ResponseHeaderMap {
+        self.response.get_header_map()
+    }
+
+    /// Returns the next chunk of decoded body data, or `None` once the body is complete.
+    ///
+    /// The connection is closed when reading the body fails.
+    pub(crate) fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, RequestError> {
+        let result: Result<Option<Vec<u8>>, RequestError> = self.read_source();
+        if result.is_err() {
+            self.source = ResponseStreamSource::Closed;
+        }
+        result
+    }
+
+    fn read_source(&mut self) -> Result<Option<Vec<u8>>, RequestError> {
+        loop {
+            let mut output: Vec<u8> = Vec::new();
+            let done: bool = match &mut self.source {
+                ResponseStreamSource::Http1 {
+                    connection,
+                    decoder,
+                    ..
+                } => {
+                    if !decoder.is_done() {
+                        let n: usize = connection
+                            .stream
+                            .read(&mut self.buffer)
+                            .map_err(|err| RequestError::Request(err.to_string()))?;
+                        if n == 0 {
+                            decoder.finish_eof(&mut output)?;
+                        } else {
+                            decoder.feed(&self.buffer[..n], &mut output)?;
+                        }
+                    }
+                    decoder.is_done()
+                }
+                ResponseStreamSource::Http2 { stream, runtime } => {
+                    return runtime.block_on(stream.next())?.transpose();
+                }
+                ResponseStreamSource::Closed => return Ok(None),
+            };
+            if done {
+                self.release();
+            }
+            if !output.is_empty() {
+                return Ok(Some(output));
+            }
+            if done {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Returns a completely read keep-alive connection to the client pool.
+    fn release(&mut self) {
+        if let ResponseStreamSource::Http1 {
+            connection,
+            client: Some(client),
+            decoder,
+            keep_alive: true,
+        } = std::mem::replace(&mut self.source, ResponseStreamSource::Closed)
+            && decoder.body_framing != BodyFraming::UntilClose
+            && let (Some(key), Some(permit)) = (connection.key, connection.permit)
+        {
+            client
+                .pool
+                .release(key, connection.stream, permit, connection.tls_info);
+        }
+    }
+}
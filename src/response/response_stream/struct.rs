@@ -0,0 +1,21 @@
+use crate::*;
+
+/// A response whose body is read incrementally through `std::io::Read`.
+///
+/// Returned by `RequestTrait::send_stream` once the status line and headers have been
+/// received. The body is read from the connection as it is consumed, with chunked transfer
+/// coding removed and, when decoding is enabled, the content encoding decoded.
+///
+/// # Fields
+/// - `response`: The response head, with an empty body.
+/// - `source`: Where the remaining body is read from.
+/// - `buffer`: The buffer connection reads are made into.
+/// - `pending`: Body data already decoded but not yet read.
+/// - `pending_pos`: The position of the next unread byte in `pending`.
+pub struct ResponseStream {
+    pub(crate) response: HttpResponseBinary,
+    pub(crate) source: ResponseStreamSource,
+    pub(crate) buffer: Vec<u8>,
+    pub(crate) pending: Vec<u8>,
+    pub(crate) pending_pos: usize,
+}
@@ -34,7 +34,10 @@ impl ResponseTrait for HttpResponseText {
             status_code: self.status_code,
             status_text: self.status_text.clone(),
             headers: self.headers.clone(),
+            header_map: self.header_map.clone(),
             body: Arc::new(RwLock::new(body)),
+            redirect_history: self.redirect_history.clone(),
+            tls_info: self.tls_info.clone(),
         }
     }
 
@@ -57,9 +60,20 @@ impl ResponseTrait for HttpResponseText {
             status_code: http_response.status_code,
             status_text: http_response.status_text,
             headers: http_response.headers,
+            header_map: http_response.header_map,
             body: Arc::new(RwLock::new(body)),
+            redirect_history: http_response.redirect_history,
+            tls_info: http_response.tls_info,
         }
     }
+
+    fn redirect_history(&self) -> Vec<RedirectHop> {
+        self.get_redirect_history()
+    }
+
+    fn tls_info(&self) -> Option<TlsInfo> {
+        self.get_tls_info()
+    }
 }
 
 impl HttpResponseText {
@@ -107,6 +121,33 @@ impl HttpResponseText {
         return hash_map_xx_hash3_64();
     }
 
+    /// Retrieves every header of the HTTP response, keeping all values of repeated headers.
+    ///
+    /// # Returns
+    /// - `ResponseHeaderMap`: The headers in the order received, looked up case-insensitively.
+    pub fn get_header_map(&self) -> ResponseHeaderMap {
+        self.header_map
+            .read()
+            .map_or(ResponseHeaderMap::new(), |header_map| header_map.clone())
+    }
+
+    /// Retrieves every value of a header in the order received, matching the name case-insensitively.
+    ///
+    /// # Parameters
+    /// - `name`: The header name, such as `Set-Cookie`.
+    ///
+    /// # Returns
+    /// - `Vec<String>`: All values of the header; empty if it is absent.
+    pub fn get_header_all(&self, name: &str) -> Vec<String> {
+        self.header_map.read().map_or(Vec::new(), |header_map| {
+            header_map
+                .get_all(name)
+                .into_iter()
+                .map(str::to_string)
+                .collect()
+        })
+    }
+
     /// Retrieves the body content of the HTTP response as a `String`.
     ///
     /// This method attempts to read the body of the response. If the body can be successfully read,
@@ -121,6 +162,28 @@ impl HttpResponseText {
         }
         return RequestBodyString::new();
     }
+
+    /// Retrieves the redirects followed before this response, in the order they happened.
+    ///
+    /// # Returns
+    /// - `Vec<RedirectHop>`: The URL, status code and headers of every redirect hop.
+    pub fn get_redirect_history(&self) -> Vec<RedirectHop> {
+        self.redirect_history
+            .read()
+            .map_or(Vec::new(), |redirect_history| redirect_history.clone())
+    }
+
+    /// Retrieves the TLS session the response was received over.
+    ///
+    /// # Returns
+    /// - `Option<TlsInfo>`: The protocol version, cipher suite, ALPN protocol and server
+    ///   certificate chain, or `None` if the response was received over plaintext.
+    pub fn get_tls_info(&self) -> Option<TlsInfo> {
+        self.tls_info
+            .read()
+            .ok()
+            .and_then(|tls_info| tls_info.clone())
+    }
 }
 
 impl Default for HttpResponseText {
@@ -130,7 +193,10 @@ impl Default for HttpResponseText {
             status_code: HttpStatus::Unknown.code(),
             status_text: Arc::new(RwLock::new(HttpStatus::Unknown.to_string())),
             headers: Arc::new(RwLock::new(hash_map_xx_hash3_64())),
+            header_map: Arc::new(RwLock::new(ResponseHeaderMap::new())),
             body: Arc::new(RwLock::new(String::new())),
+            redirect_history: Arc::new(RwLock::new(Vec::new())),
+            tls_info: Arc::new(RwLock::new(None)),
         }
     }
 }
@@ -56,4 +56,18 @@ pub trait ResponseTrait: Send + Debug {
     /// # Returns
     /// Returns the decoded data as `Self::OutputBinary`. The exact type of `OutputBinary` depends on the implementation of the `Self` type.
     fn decode(&self, buffer_size: usize) -> Self::OutputBinary;
+
+    /// Returns the redirects followed before this response, in the order they happened.
+    ///
+    /// The list is empty when no redirect was followed.
+    ///
+    /// # Returns
+    /// - `Vec<RedirectHop>`: The URL, status code and headers of every redirect hop.
+    fn redirect_history(&self) -> Vec<RedirectHop>;
+
+    /// Returns the TLS session the response was received over.
+    ///
+    /// # Returns
+    /// - `Option<TlsInfo>`: The negotiated session details, or `None` over plaintext.
+    fn tls_info(&self) -> Option<TlsInfo>;
 }
@@ -0,0 +1,4 @@
+/// The start of the `RequestError::TlsConnectorBuild` message reported when a server does not
+/// match any configured certificate pin.
+pub(crate) const CERTIFICATE_PIN_MISMATCH: &str = "certificate pin mismatch";
+pub(crate) const SHA256_PIN_PREFIX: &str = "sha256/";
@@ -0,0 +1,8 @@
+/// A pin the leaf certificate of a server must match, on top of the usual chain validation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum CertificatePin {
+    /// The base64 encoded SHA-256 hash of the certificate's SubjectPublicKeyInfo.
+    PublicKeySha256(String),
+    /// The exact DER encoding of the certificate.
+    Certificate(Vec<u8>),
+}
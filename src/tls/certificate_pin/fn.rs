@@ -0,0 +1,12 @@
+use crate::*;
+
+/// Returns whether a request failed because the server matched none of its certificate pins.
+///
+/// # Parameters
+/// - `error`: The error returned by `send` or `send_stream`.
+pub fn is_certificate_pin_mismatch(error: &RequestError) -> bool {
+    matches!(
+        error,
+        RequestError::TlsConnectorBuild(message) if message.starts_with(CERTIFICATE_PIN_MISMATCH)
+    )
+}
@@ -0,0 +1,36 @@
+use crate::*;
+
+impl CertificatePin {
+    /// Parses a public key pin given as the base64 SHA-256 hash of a SubjectPublicKeyInfo,
+    /// optionally prefixed with `sha256/`.
+    pub(crate) fn public_key_sha256(pin: &str) -> Result<Self, String> {
+        let hash: &str = pin.trim();
+        let hash: &str = hash.strip_prefix(SHA256_PIN_PREFIX).unwrap_or(hash);
+        let is_base64_sha256: bool = hash.len() == 44
+            && hash.ends_with('=')
+            && hash[..43].bytes().all(|byte| CHARS.contains(&byte));
+        if !is_base64_sha256 {
+            return Err(format!("invalid SHA-256 public key pin: {}", pin));
+        }
+        Ok(Self::PublicKeySha256(hash.to_string()))
+    }
+
+    /// Parses a certificate pin given as a PEM or DER encoded certificate.
+    pub(crate) fn certificate(certificate: &[u8]) -> Result<Self, String> {
+        let der: CertificateDer<'_> = CertificateDer::from_pem_slice(certificate)
+            .unwrap_or_else(|_| CertificateDer::from(certificate));
+        EndEntityCert::try_from(&der)
+            .map_err(|err| format!("invalid pinned certificate: {}", err))?;
+        Ok(Self::Certificate(der.to_vec()))
+    }
+
+    /// Returns whether the leaf certificate presented by a server matches this pin.
+    pub(crate) fn matches(&self, end_entity: &CertificateDer<'_>) -> bool {
+        match self {
+            Self::Certificate(der) => der.as_slice() == end_entity.as_ref(),
+            Self::PublicKeySha256(hash) => EndEntityCert::try_from(end_entity).is_ok_and(|cert| {
+                base64_encode(&sha256(cert.subject_public_key_info().as_ref())) == *hash
+            }),
+        }
+    }
+}
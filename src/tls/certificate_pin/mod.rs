@@ -0,0 +1,9 @@
+pub(crate) mod r#const;
+pub(crate) mod r#enum;
+pub(crate) mod r#fn;
+pub(crate) mod r#impl;
+
+pub use r#fn::*;
+
+pub(crate) use r#const::*;
+pub(crate) use r#enum::*;
@@ -0,0 +1,4 @@
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+
+pub(crate) use r#struct::*;
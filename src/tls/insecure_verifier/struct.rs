@@ -0,0 +1,14 @@
+use crate::*;
+
+/// A server certificate verifier that deliberately skips some checks.
+///
+/// Installed only when `danger_accept_invalid_certs` or `danger_accept_invalid_hostnames` is
+/// enabled. Handshake signatures are always verified, so the peer must still own the key of the
+/// certificate it presents.
+#[derive(Debug)]
+pub(crate) struct InsecureServerCertVerifier {
+    /// Verifies the chain when only hostname mismatches are accepted; `None` accepts any
+    /// certificate.
+    pub(crate) webpki: Option<Arc<WebPkiServerVerifier>>,
+    pub(crate) provider: Arc<CryptoProvider>,
+}
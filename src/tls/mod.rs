@@ -0,0 +1,13 @@
+pub(crate) mod certificate_pin;
+pub(crate) mod insecure_verifier;
+pub(crate) mod pinned_verifier;
+pub(crate) mod tls_config;
+pub(crate) mod tls_info;
+
+pub use certificate_pin::is_certificate_pin_mismatch;
+pub use tls_info::*;
+
+pub(crate) use certificate_pin::*;
+pub(crate) use insecure_verifier::*;
+pub(crate) use pinned_verifier::*;
+pub(crate) use tls_config::*;
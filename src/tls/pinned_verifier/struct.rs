@@ -0,0 +1,9 @@
+use crate::*;
+
+/// A server certificate verifier that requires the leaf certificate to match a pin after the
+/// wrapped verifier has accepted it.
+#[derive(Debug)]
+pub(crate) struct PinnedServerCertVerifier {
+    pub(crate) inner: Arc<dyn ServerCertVerifier>,
+    pub(crate) pins: Vec<CertificatePin>,
+}
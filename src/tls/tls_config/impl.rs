@@ -0,0 +1,343 @@
+use crate::*;
+
+impl Default for TlsConfig {
+    fn default() -> Self {
+        Self {
+            root_cert: RootCertStore {
+                roots: TLS_SERVER_ROOTS.to_vec(),
+            },
+            client_auth: None,
+            accept_invalid_certs: false,
+            accept_invalid_hostnames: false,
+            pins: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+impl TlsConfig {
+    /// Remembers the first error hit while configuring TLS.
+    fn set_error(&mut self, error: String) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+
+    /// Parses every certificate of a PEM bundle.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<CertificateDer>)` - The certificates, possibly none if the data has no
+    ///   `CERTIFICATE` section.
+    /// - `Err(String)` - A section could not be decoded.
+    fn parse_pem(pem: &[u8]) -> Result<Vec<CertificateDer<'static>>, String> {
+        CertificateDer::pem_slice_iter(pem)
+            .collect::<Result<Vec<CertificateDer<'static>>, _>>()
+            .map_err(|err| format!("invalid PEM certificate: {}", err))
+    }
+
+    /// Adds a DER encoded certificate to the trusted roots.
+    fn add_der_certificate(&mut self, der: CertificateDer<'_>) -> Result<(), String> {
+        self.root_cert
+            .add(der)
+            .map_err(|err| format!("invalid root certificate: {}", err))
+    }
+
+    /// Trusts every certificate of a PEM bundle.
+    ///
+    /// # Parameters
+    ///
+    /// - `pem`: One or more PEM encoded certificates.
+    pub(crate) fn add_root_certificate_pem(&mut self, pem: &[u8]) {
+        let result: Result<(), String> = Self::parse_pem(pem).and_then(|certificates| {
+            if certificates.is_empty() {
+                return Err("no certificate found in PEM data".to_string());
+            }
+            certificates
+                .into_iter()
+                .try_for_each(|certificate| self.add_der_certificate(certificate))
+        });
+        if let Err(err) = result {
+            self.set_error(err);
+        }
+    }
+
+    /// Trusts a DER encoded certificate.
+    ///
+    /// # Parameters
+    ///
+    /// - `der`: The DER encoded certificate.
+    pub(crate) fn add_root_certificate_der(&mut self, der: &[u8]) {
+        if let Err(err) = self.add_der_certificate(CertificateDer::from(der)) {
+            self.set_error(err);
+        }
+    }
+
+    /// Reads the certificates of a PEM or DER file.
+    fn read_certificate_file(path: &Path) -> Result<Vec<CertificateDer<'static>>, String> {
+        let data: Vec<u8> = std::fs::read(path)
+            .map_err(|err| format!("error reading {}: {}", path.display(), err))?;
+        let certificates: Vec<CertificateDer<'static>> =
+            Self::parse_pem(&data).map_err(|err| format!("{}: {}", path.display(), err))?;
+        if certificates.is_empty() {
+            return Ok(vec![CertificateDer::from(data)]);
+        }
+        Ok(certificates)
+    }
+
+    /// Trusts the certificates stored at a path.
+    ///
+    /// A file may hold a PEM bundle or a single DER certificate. A directory, such as
+    /// `/etc/ssl/certs`, is loaded the way system stores are: every file it contains is read and
+    /// files that hold no usable certificate are skipped, but at least one certificate must be
+    /// found.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The certificate file or directory.
+    pub(crate) fn add_root_certificates_from_path(&mut self, path: &Path) {
+        if !path.is_dir() {
+            let result: Result<(), String> =
+                Self::read_certificate_file(path).and_then(|certificates| {
+                    certificates
+                        .into_iter()
+                        .try_for_each(|certificate| self.add_der_certificate(certificate))
+                        .map_err(|err| format!("{}: {}", path.display(), err))
+                });
+            if let Err(err) = result {
+                self.set_error(err);
+            }
+            return;
+        }
+        let mut entries: Vec<PathBuf> = match std::fs::read_dir(path) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|entry_path| entry_path.is_file())
+                .collect(),
+            Err(err) => {
+                self.set_error(format!("error reading {}: {}", path.display(), err));
+                return;
+            }
+        };
+        entries.sort();
+        let mut added: usize = 0;
+        for entry_path in entries {
+            if let Ok(certificates) = Self::read_certificate_file(&entry_path) {
+                for certificate in certificates {
+                    if self.add_der_certificate(certificate).is_ok() {
+                        added += 1;
+                    }
+                }
+            }
+        }
+        if added == 0 {
+            self.set_error(format!("no certificate found in {}", path.display()));
+        }
+    }
+
+    /// Enables or disables trusting the built-in Mozilla root certificates.
+    ///
+    /// Certificates added explicitly are kept either way.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Whether the built-in roots are trusted.
+    pub(crate) fn built_in_root_certs(&mut self, enable: bool) {
+        self.root_cert
+            .roots
+            .retain(|anchor| !TLS_SERVER_ROOTS.contains(anchor));
+        if enable {
+            self.root_cert
+                .roots
+                .extend(TLS_SERVER_ROOTS.iter().cloned());
+        }
+    }
+
+    /// Presents a client certificate chain and private key given in PEM.
+    ///
+    /// The key may be a PKCS#8, PKCS#1 (RSA) or SEC1 (EC) key; the first one found is used.
+    ///
+    /// # Parameters
+    ///
+    /// - `cert_chain_pem`: The client certificate followed by its intermediates.
+    /// - `private_key_pem`: The private key of the client certificate.
+    pub(crate) fn client_certificate_pem(&mut self, cert_chain_pem: &[u8], private_key_pem: &[u8]) {
+        let result: Result<TlsClientAuth, String> =
+            Self::parse_pem(cert_chain_pem).and_then(|cert_chain| {
+                if cert_chain.is_empty() {
+                    return Err("no client certificate found in PEM data".to_string());
+                }
+                let private_key: PrivateKeyDer<'static> =
+                    PrivateKeyDer::from_pem_slice(private_key_pem)
+                        .map_err(|err| format!("invalid client private key: {}", err))?;
+                Ok(TlsClientAuth {
+                    cert_chain,
+                    private_key: Arc::new(private_key),
+                })
+            });
+        match result {
+            Ok(client_auth) => self.client_auth = Some(client_auth),
+            Err(err) => self.set_error(err),
+        }
+    }
+
+    /// Presents a client certificate chain and private key given in DER.
+    ///
+    /// The key format (PKCS#8, PKCS#1 or SEC1) is detected from its encoding.
+    ///
+    /// # Parameters
+    ///
+    /// - `cert_chain_der`: The client certificate followed by its intermediates.
+    /// - `private_key_der`: The private key of the client certificate.
+    pub(crate) fn client_certificate_der(
+        &mut self,
+        cert_chain_der: Vec<Vec<u8>>,
+        private_key_der: &[u8],
+    ) {
+        if cert_chain_der.is_empty() {
+            self.set_error("no client certificate given".to_string());
+            return;
+        }
+        match PrivateKeyDer::try_from(private_key_der) {
+            Ok(private_key) => {
+                self.client_auth = Some(TlsClientAuth {
+                    cert_chain: cert_chain_der
+                        .into_iter()
+                        .map(CertificateDer::from)
+                        .collect(),
+                    private_key: Arc::new(private_key.clone_key()),
+                });
+            }
+            Err(err) => self.set_error(format!("invalid client private key: {}", err)),
+        }
+    }
+
+    /// Requires the server's public key to match a SHA-256 pin.
+    ///
+    /// When several pins are added, a server matching any one of them is accepted.
+    ///
+    /// # Parameters
+    ///
+    /// - `pin`: The base64 SHA-256 hash of the SubjectPublicKeyInfo, optionally prefixed with
+    ///   `sha256/`.
+    pub(crate) fn pin_public_key_sha256(&mut self, pin: &str) {
+        match CertificatePin::public_key_sha256(pin) {
+            Ok(pin) => self.pins.push(pin),
+            Err(err) => self.set_error(err),
+        }
+    }
+
+    /// Requires the server's leaf certificate to be exactly the given certificate.
+    ///
+    /// # Parameters
+    ///
+    /// - `certificate`: The PEM or DER encoded certificate.
+    pub(crate) fn pin_certificate(&mut self, certificate: &[u8]) {
+        match CertificatePin::certificate(certificate) {
+            Ok(pin) => self.pins.push(pin),
+            Err(err) => self.set_error(err),
+        }
+    }
+
+    /// Returns the settings used for the TLS connection to an HTTPS proxy.
+    ///
+    /// Pins identify the origin server, so they are dropped; roots, the client certificate and
+    /// the `danger_accept_invalid_*` options are kept.
+    pub(crate) fn get_proxy_config(&self) -> Self {
+        Self {
+            pins: Vec::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Builds the verifier installed by the `danger_accept_invalid_*` options.
+    fn get_insecure_verifier(&self) -> Result<InsecureServerCertVerifier, String> {
+        let provider: Arc<CryptoProvider> = ClientConfig::builder().crypto_provider().clone();
+        let webpki: Option<Arc<WebPkiServerVerifier>> = if self.accept_invalid_certs {
+            None
+        } else {
+            Some(
+                WebPkiServerVerifier::builder_with_provider(
+                    Arc::new(self.root_cert.clone()),
+                    provider.clone(),
+                )
+                .build()
+                .map_err(|err| format!("error building certificate verifier: {}", err))?,
+            )
+        };
+        Ok(InsecureServerCertVerifier { webpki, provider })
+    }
+
+    /// Returns a fingerprint of the settings that decide which servers a TLS connection trusts
+    /// and which identity it presents, so that pooled connections are only shared between
+    /// requests with the same settings.
+    pub(crate) fn get_fingerprint(&self) -> u64 {
+        let mut hasher: DefaultHasher = DefaultHasher::new();
+        self.root_cert.roots.hash(&mut hasher);
+        if let Some(client_auth) = &self.client_auth {
+            client_auth.cert_chain.hash(&mut hasher);
+        }
+        self.accept_invalid_certs.hash(&mut hasher);
+        self.accept_invalid_hostnames.hash(&mut hasher);
+        self.pins.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Builds the rustls client configuration for a connection.
+    ///
+    /// # Parameters
+    ///
+    /// - `alpn_protocols`: The protocols to offer through ALPN.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(ClientConfig)` - The client configuration.
+    /// - `Err(String)` - A certificate or pin could not be loaded, no root certificate is trusted
+    ///   or the client certificate does not match its private key.
+    pub(crate) fn build_client_config(
+        &self,
+        alpn_protocols: Vec<Vec<u8>>,
+    ) -> Result<ClientConfig, String> {
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        if self.root_cert.is_empty() && !self.accept_invalid_certs {
+            return Err("no root certificate is trusted".to_string());
+        }
+        let builder: ConfigBuilder<ClientConfig, WantsClientCert> = if !self.pins.is_empty() {
+            let inner: Arc<dyn ServerCertVerifier> =
+                if self.accept_invalid_certs || self.accept_invalid_hostnames {
+                    Arc::new(self.get_insecure_verifier()?)
+                } else {
+                    WebPkiServerVerifier::builder(Arc::new(self.root_cert.clone()))
+                        .build()
+                        .map_err(|err| format!("error building certificate verifier: {}", err))?
+                };
+            let verifier: PinnedServerCertVerifier = PinnedServerCertVerifier {
+                inner,
+                pins: self.pins.clone(),
+            };
+            ClientConfig::builder()
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(verifier))
+        } else if self.accept_invalid_certs || self.accept_invalid_hostnames {
+            let verifier: InsecureServerCertVerifier = self.get_insecure_verifier()?;
+            ClientConfig::builder()
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(verifier))
+        } else {
+            ClientConfig::builder().with_root_certificates(self.root_cert.clone())
+        };
+        let mut tls_config: ClientConfig = match &self.client_auth {
+            Some(client_auth) => builder
+                .with_client_auth_cert(
+                    client_auth.cert_chain.clone(),
+                    client_auth.private_key.clone_key(),
+                )
+                .map_err(|err| format!("invalid client certificate: {}", err))?,
+            None => builder.with_no_client_auth(),
+        };
+        tls_config.alpn_protocols = alpn_protocols;
+        Ok(tls_config)
+    }
+}
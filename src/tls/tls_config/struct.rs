@@ -0,0 +1,23 @@
+use crate::*;
+
+/// TLS settings shared by HTTP requests and WebSocket connections.
+///
+/// Certificates are parsed when they are added. The first certificate that cannot be used is
+/// remembered in `error` and reported when a TLS connection is attempted, so a broken trust
+/// configuration never silently falls back to a weaker one.
+#[derive(Debug, Clone)]
+pub(crate) struct TlsConfig {
+    pub(crate) root_cert: RootCertStore,
+    pub(crate) client_auth: Option<TlsClientAuth>,
+    pub(crate) accept_invalid_certs: bool,
+    pub(crate) accept_invalid_hostnames: bool,
+    pub(crate) pins: Vec<CertificatePin>,
+    pub(crate) error: Option<String>,
+}
+
+/// A client certificate chain and its private key, presented to servers that request mutual TLS.
+#[derive(Debug, Clone)]
+pub(crate) struct TlsClientAuth {
+    pub(crate) cert_chain: Vec<CertificateDer<'static>>,
+    pub(crate) private_key: Arc<PrivateKeyDer<'static>>,
+}
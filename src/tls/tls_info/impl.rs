@@ -0,0 +1,61 @@
+use crate::*;
+
+impl TlsInfo {
+    /// Captures the negotiated parameters of a connection whose handshake has completed.
+    ///
+    /// # Parameters
+    /// - `connection`: The state shared by rustls client and server connections.
+    pub(crate) fn from_connection(connection: &CommonState) -> Self {
+        Self {
+            protocol_version: connection
+                .protocol_version()
+                .map_or(String::new(), |version| format!("{:?}", version)),
+            cipher_suite: connection
+                .negotiated_cipher_suite()
+                .map_or(String::new(), |suite| format!("{:?}", suite.suite())),
+            alpn_protocol: connection
+                .alpn_protocol()
+                .map(|protocol| String::from_utf8_lossy(protocol).into_owned()),
+            peer_certificates: connection
+                .peer_certificates()
+                .map_or(Vec::new(), |certificates| {
+                    certificates
+                        .iter()
+                        .map(|certificate| certificate.to_vec())
+                        .collect()
+                }),
+        }
+    }
+
+    /// Retrieves the negotiated TLS version.
+    ///
+    /// # Returns
+    /// - `String`: The protocol version (e.g., "TLSv1_2" or "TLSv1_3").
+    pub fn get_protocol_version(&self) -> String {
+        self.protocol_version.clone()
+    }
+
+    /// Retrieves the negotiated cipher suite.
+    ///
+    /// # Returns
+    /// - `String`: The IANA name of the cipher suite (e.g., "TLS13_AES_128_GCM_SHA256").
+    pub fn get_cipher_suite(&self) -> String {
+        self.cipher_suite.clone()
+    }
+
+    /// Retrieves the protocol agreed through ALPN.
+    ///
+    /// # Returns
+    /// - `Option<String>`: The protocol (e.g., "h2"), or `None` if none was negotiated.
+    pub fn get_alpn_protocol(&self) -> Option<String> {
+        self.alpn_protocol.clone()
+    }
+
+    /// Retrieves the certificate chain presented by the server.
+    ///
+    /// # Returns
+    /// - `Vec<Vec<u8>>`: The DER encoded certificates, the server's own certificate first.
+    pub fn get_peer_certificates(&self) -> Vec<Vec<u8>> {
+        self.peer_certificates.clone()
+    }
+}
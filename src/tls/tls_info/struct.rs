@@ -0,0 +1,14 @@
+/// Details of the TLS session a response or WebSocket connection was received over.
+///
+/// # Fields
+/// - `protocol_version`: The negotiated TLS version (e.g., "TLSv1_3").
+/// - `cipher_suite`: The negotiated cipher suite (e.g., "TLS13_AES_256_GCM_SHA384").
+/// - `alpn_protocol`: The protocol agreed through ALPN (e.g., "h2"), if any.
+/// - `peer_certificates`: The DER encoded certificate chain presented by the server, leaf first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsInfo {
+    pub(crate) protocol_version: String,
+    pub(crate) cipher_suite: String,
+    pub(crate) alpn_protocol: Option<String>,
+    pub(crate) peer_certificates: Vec<Vec<u8>>,
+}
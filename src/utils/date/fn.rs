@@ -0,0 +1,80 @@
+use crate::*;
+
+/// Returns the current time as seconds since the Unix epoch.
+pub(crate) fn get_unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration: Duration| duration.as_secs())
+}
+
+/// Returns the number of days between the Unix epoch and the given civil date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year: i64 = if month <= 2 { year - 1 } else { year };
+    let era: i64 = year.div_euclid(400);
+    let year_of_era: i64 = year - era * 400;
+    let month_index: i64 = (i64::from(month) + 9) % 12;
+    let day_of_year: i64 = (153 * month_index + 2) / 5 + i64::from(day) - 1;
+    let day_of_era: i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Parses an HTTP date into seconds since the Unix epoch.
+///
+/// Follows the lenient algorithm of RFC 6265, Section 5.1.1, so the IMF-fixdate
+/// (`Sun, 06 Nov 1994 08:49:37 GMT`), RFC 850 (`Sunday, 06-Nov-94 08:49:37 GMT`) and
+/// asctime (`Sun Nov  6 08:49:37 1994`) formats are all accepted. Dates before the epoch
+/// are clamped to `0`.
+pub(crate) fn parse_http_date(value: &str) -> Option<u64> {
+    let mut time: Option<(u32, u32, u32)> = None;
+    let mut day: Option<u32> = None;
+    let mut month: Option<u32> = None;
+    let mut year: Option<i64> = None;
+    for token in value
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == ':'))
+        .filter(|token: &&str| !token.is_empty())
+    {
+        if time.is_none() && token.contains(':') {
+            let parts: Vec<&str> = token.split(':').collect();
+            if parts.len() == 3
+                && let (Ok(hour), Ok(minute), Ok(second)) = (
+                    parts[0].parse::<u32>(),
+                    parts[1].parse::<u32>(),
+                    parts[2].parse::<u32>(),
+                )
+            {
+                time = Some((hour, minute, second));
+                continue;
+            }
+        }
+        let digits: usize = token.chars().take_while(char::is_ascii_digit).count();
+        if day.is_none() && (1..=2).contains(&digits) && digits == token.len() {
+            day = token.parse::<u32>().ok();
+            continue;
+        }
+        if month.is_none() && token.len() >= 3 {
+            let prefix: String = token[..3].to_ascii_lowercase();
+            if let Some(index) = MONTH_NAMES.iter().position(|name| *name == prefix) {
+                month = Some(index as u32 + 1);
+                continue;
+            }
+        }
+        if year.is_none() && (2..=4).contains(&digits) && digits == token.len() {
+            year = token.parse::<i64>().ok();
+        }
+    }
+    let (hour, minute, second) = time?;
+    let (day, month, mut year) = (day?, month?, year?);
+    if (70..=99).contains(&year) {
+        year += 1900;
+    } else if (0..=69).contains(&year) {
+        year += 2000;
+    }
+    if !(1..=31).contains(&day) || year < 1601 || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    let seconds: i64 = days_from_civil(year, month, day) * 86400
+        + i64::from(hour) * 3600
+        + i64::from(minute) * 60
+        + i64::from(second);
+    Some(seconds.max(0) as u64)
+}
@@ -0,0 +1,5 @@
+pub(crate) mod r#const;
+pub(crate) mod r#fn;
+
+pub(crate) use r#const::*;
+pub(crate) use r#fn::*;
@@ -1 +1,2 @@
 pub(crate) const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+pub(crate) const HEX_CHARS: &[u8] = b"0123456789abcdef";
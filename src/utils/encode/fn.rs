@@ -24,3 +24,21 @@ pub(crate) fn base64_encode(input: &[u8]) -> String {
     }
     result
 }
+
+/// Encodes bytes as lowercase hexadecimal.
+pub(crate) fn hex_encode(input: &[u8]) -> String {
+    let mut result: String = String::with_capacity(input.len() * 2);
+    for byte in input {
+        result.push(HEX_CHARS[(byte >> 4) as usize] as char);
+        result.push(HEX_CHARS[(byte & 15) as usize] as char);
+    }
+    result
+}
+
+/// Returns the value of an `Authorization` header for HTTP Basic authentication.
+pub(crate) fn basic_authorization(username: &str, password: &str) -> String {
+    format!(
+        "Basic {}",
+        base64_encode(format!("{}:{}", username, password).as_bytes())
+    )
+}
@@ -0,0 +1,102 @@
+use crate::*;
+
+/// Computes the SHA-256 digest of `input` (FIPS 180-4).
+pub(crate) fn sha256(input: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = SHA256_INITIAL_STATE;
+    let mut message: Vec<u8> = input.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((input.len() as u64).wrapping_mul(8)).to_be_bytes());
+    for block in message.chunks(64) {
+        let mut schedule: [u32; 64] = [0u32; 64];
+        for (index, word) in block.chunks(4).enumerate() {
+            schedule[index] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for index in 16..64 {
+            let s0: u32 = schedule[index - 15].rotate_right(7)
+                ^ schedule[index - 15].rotate_right(18)
+                ^ (schedule[index - 15] >> 3);
+            let s1: u32 = schedule[index - 2].rotate_right(17)
+                ^ schedule[index - 2].rotate_right(19)
+                ^ (schedule[index - 2] >> 10);
+            schedule[index] = schedule[index - 16]
+                .wrapping_add(s0)
+                .wrapping_add(schedule[index - 7])
+                .wrapping_add(s1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for index in 0..64 {
+            let s1: u32 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let choice: u32 = (e & f) ^ (!e & g);
+            let temp1: u32 = h
+                .wrapping_add(s1)
+                .wrapping_add(choice)
+                .wrapping_add(SHA256_ROUND_CONSTANTS[index])
+                .wrapping_add(schedule[index]);
+            let s0: u32 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let majority: u32 = (a & b) ^ (a & c) ^ (b & c);
+            let temp2: u32 = s0.wrapping_add(majority);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+        for (value, add) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *value = value.wrapping_add(add);
+        }
+    }
+    let mut digest: [u8; 32] = [0u8; 32];
+    for (chunk, value) in digest.chunks_mut(4).zip(state) {
+        chunk.copy_from_slice(&value.to_be_bytes());
+    }
+    digest
+}
+
+/// Computes the MD5 digest of `input` (RFC 1321).
+pub(crate) fn md5(input: &[u8]) -> [u8; 16] {
+    let mut state: [u32; 4] = MD5_INITIAL_STATE;
+    let mut message: Vec<u8> = input.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((input.len() as u64).wrapping_mul(8)).to_le_bytes());
+    for block in message.chunks(64) {
+        let mut words: [u32; 16] = [0u32; 16];
+        for (index, word) in block.chunks(4).enumerate() {
+            words[index] = u32::from_le_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        let [mut a, mut b, mut c, mut d] = state;
+        for index in 0..64 {
+            let (mixed, word_index): (u32, usize) = match index / 16 {
+                0 => ((b & c) | (!b & d), index),
+                1 => ((d & b) | (!d & c), (5 * index + 1) % 16),
+                2 => (b ^ c ^ d, (3 * index + 5) % 16),
+                _ => (c ^ (b | !d), (7 * index) % 16),
+            };
+            let rotated: u32 = a
+                .wrapping_add(mixed)
+                .wrapping_add(MD5_ROUND_CONSTANTS[index])
+                .wrapping_add(words[word_index])
+                .rotate_left(MD5_SHIFTS[index]);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(rotated);
+        }
+        for (value, add) in state.iter_mut().zip([a, b, c, d]) {
+            *value = value.wrapping_add(add);
+        }
+    }
+    let mut digest: [u8; 16] = [0u8; 16];
+    for (chunk, value) in digest.chunks_mut(4).zip(state) {
+        chunk.copy_from_slice(&value.to_le_bytes());
+    }
+    digest
+}
@@ -1,5 +1,13 @@
+pub(crate) mod date;
 pub(crate) mod encode;
+pub(crate) mod hash;
+pub(crate) mod redact;
+pub(crate) mod url;
 pub(crate) mod vec;
 
+pub(crate) use date::*;
 pub(crate) use encode::*;
+pub(crate) use hash::*;
+pub(crate) use redact::*;
+pub(crate) use url::*;
 pub(crate) use vec::*;
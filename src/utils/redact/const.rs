@@ -0,0 +1,7 @@
+use crate::*;
+
+/// Printed in place of credentials in `Debug` output.
+pub(crate) const REDACTED: &str = "[redacted]";
+
+/// Request headers whose values are credentials.
+pub(crate) const SENSITIVE_HEADERS: [&str; 3] = [AUTHORIZATION, PROXY_AUTHORIZATION, COOKIE];
@@ -0,0 +1,15 @@
+use crate::*;
+
+/// Returns a copy of `header` whose credential values are replaced for `Debug` output.
+pub(crate) fn redact_headers(header: &RequestHeaders) -> RequestHeaders {
+    let mut redacted: RequestHeaders = header.clone();
+    for (key, value) in redacted.iter_mut() {
+        if SENSITIVE_HEADERS
+            .iter()
+            .any(|name| key.eq_ignore_ascii_case(name))
+        {
+            *value = REDACTED.to_string();
+        }
+    }
+    redacted
+}
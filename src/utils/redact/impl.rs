@@ -0,0 +1,7 @@
+use crate::*;
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
@@ -0,0 +1,3 @@
+/// A credential that is printed as `[redacted]` in `Debug` output.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Secret(pub(crate) String);
@@ -0,0 +1,174 @@
+/// Splits a URI reference into its scheme, authority, path, query and fragment,
+/// following the regular expression of RFC 3986, Appendix B.
+fn split_url_reference(
+    reference: &str,
+) -> (Option<&str>, Option<&str>, &str, Option<&str>, Option<&str>) {
+    let (rest, fragment) = match reference.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (reference, None),
+    };
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+    let (scheme, rest) = match rest.split_once(':') {
+        Some((scheme, rest))
+            if scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c: char| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                && !scheme.contains('/') =>
+        {
+            (Some(scheme), rest)
+        }
+        _ => (None, rest),
+    };
+    let (authority, path) = match rest.strip_prefix("//") {
+        Some(rest) => {
+            let end: usize = rest.find('/').unwrap_or(rest.len());
+            (Some(&rest[..end]), &rest[end..])
+        }
+        None => (None, rest),
+    };
+    (scheme, authority, path, query, fragment)
+}
+
+/// Removes the `.` and `..` segments of a path as described in RFC 3986, Section 5.2.4.
+fn remove_dot_segments(path: &str) -> String {
+    let mut input: &str = path;
+    let mut output: Vec<&str> = Vec::new();
+    while !input.is_empty() {
+        if let Some(rest) = input.strip_prefix("../") {
+            input = rest;
+        } else if let Some(rest) = input.strip_prefix("./") {
+            input = rest;
+        } else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        } else if input.starts_with("/../") || input == "/.." {
+            input = if input == "/.." { "/" } else { &input[3..] };
+            output.pop();
+        } else if input == "." || input == ".." {
+            input = "";
+        } else {
+            let start: usize = usize::from(input.starts_with('/'));
+            let end: usize = input[start..]
+                .find('/')
+                .map_or(input.len(), |pos: usize| pos + start);
+            output.push(&input[..end]);
+            input = &input[end..];
+        }
+    }
+    output.concat()
+}
+
+/// Resolves a possibly relative URI reference, such as a `Location` header, against a base URL.
+///
+/// Implements the reference resolution of RFC 3986, Section 5.2, so absolute, scheme-relative
+/// (`//host/path`), absolute-path (`/login`) and relative-path (`../v2/item`) references are all
+/// supported. As required for redirects, the fragment of the base URL is kept when the reference
+/// has none.
+pub(crate) fn resolve_url(base: &str, reference: &str) -> String {
+    let reference: &str = reference.trim();
+    let (base_scheme, base_authority, base_path, base_query, base_fragment) =
+        split_url_reference(base);
+    let (scheme, authority, path, query, fragment) = split_url_reference(reference);
+    let (scheme, authority, path, query): (Option<&str>, Option<&str>, String, Option<&str>) =
+        if scheme.is_some() {
+            (scheme, authority, remove_dot_segments(path), query)
+        } else if authority.is_some() {
+            (base_scheme, authority, remove_dot_segments(path), query)
+        } else if path.is_empty() {
+            (
+                base_scheme,
+                base_authority,
+                base_path.to_string(),
+                query.or(base_query),
+            )
+        } else if path.starts_with('/') {
+            (
+                base_scheme,
+                base_authority,
+                remove_dot_segments(path),
+                query,
+            )
+        } else {
+            let merged: String = if base_authority.is_some() && base_path.is_empty() {
+                format!("/{}", path)
+            } else {
+                let directory_end: usize = base_path.rfind('/').map_or(0, |pos: usize| pos + 1);
+                format!("{}{}", &base_path[..directory_end], path)
+            };
+            (
+                base_scheme,
+                base_authority,
+                remove_dot_segments(&merged),
+                query,
+            )
+        };
+    let mut url: String = String::with_capacity(base.len() + reference.len());
+    if let Some(scheme) = scheme {
+        url.push_str(&scheme.to_ascii_lowercase());
+        url.push(':');
+    }
+    if let Some(authority) = authority {
+        url.push_str("//");
+        url.push_str(authority);
+    }
+    url.push_str(&path);
+    if let Some(query) = query {
+        url.push('?');
+        url.push_str(query);
+    }
+    if let Some(fragment) = fragment.or(base_fragment) {
+        url.push('#');
+        url.push_str(fragment);
+    }
+    url
+}
+
+/// Appends an already encoded query string to a URL, keeping any query already present and
+/// placing the new parameters before the fragment.
+pub(crate) fn append_query(url: &str, query: &str) -> String {
+    if query.is_empty() {
+        return url.to_string();
+    }
+    let (rest, fragment): (&str, Option<&str>) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+    let mut result: String = String::with_capacity(url.len() + query.len() + 2);
+    result.push_str(rest);
+    match rest.find('?') {
+        Some(pos) if pos + 1 < rest.len() && !rest.ends_with('&') => result.push('&'),
+        Some(_) => {}
+        None => result.push('?'),
+    }
+    result.push_str(query);
+    if let Some(fragment) = fragment {
+        result.push('#');
+        result.push_str(fragment);
+    }
+    result
+}
+
+/// Percent-encodes the bytes of a request target that may not appear in it unescaped, such as
+/// spaces, control characters and non-ASCII characters, leaving existing escapes untouched.
+pub(crate) fn escape_request_target(target: &str) -> String {
+    let mut result: String = String::with_capacity(target.len());
+    for byte in target.bytes() {
+        if byte <= b' '
+            || byte >= 0x7f
+            || matches!(
+                byte,
+                b'"' | b'<' | b'>' | b'\\' | b'^' | b'`' | b'{' | b'|' | b'}'
+            )
+        {
+            result.push_str(&format!("%{:02X}", byte));
+        } else {
+            result.push(byte as char);
+        }
+    }
+    result
+}
@@ -8,6 +8,7 @@ impl Default for WebSocketConfig {
             buffer: DEFAULT_BUFFER_SIZE,
             protocols: Vec::new(),
             proxy: None,
+            tls: TlsConfig::default(),
         }
     }
 }
@@ -36,7 +36,7 @@ impl WebSocket {
         }
         let url_obj: HttpUrlComponents = SharedWebSocketBuilder::parse_url(&url)?;
         if let Ok(mut config) = self.config.write() {
-            config.url_obj = url_obj;
+            config.url_obj = url_obj.clone();
         }
         let timeout_duration: Duration = Duration::from_millis(
             self.config
@@ -57,79 +57,65 @@ impl WebSocket {
             .read()
             .ok()
             .and_then(|config| config.proxy.clone());
+        if let Ok(mut tls_info) = self.tls_info.write() {
+            *tls_info = None;
+        }
+        let target_host: String = url_obj.host.clone().unwrap_or_default();
+        let target_port: u16 = url_obj.port.unwrap_or_default();
+        let is_tls: bool = url_obj.protocol.is_https();
         let ws_stream: WebSocketConnectionType = if let Some(proxy_config) = proxy_config {
-            let url_obj: HttpUrlComponents = self
-                .config
-                .read()
-                .map(|c| c.url_obj.clone())
-                .unwrap_or_default();
-            let target_host: String = url_obj.host.clone().unwrap_or_default();
-            let target_port: u16 = url_obj.port.unwrap_or_default();
             let proxy_stream: BoxAsyncReadWrite = self
                 .get_proxy_connection_stream_async(target_host.clone(), target_port, &proxy_config)
                 .await?;
-            let proxy_tunnel_stream: WebSocketProxyTunnelStream =
-                WebSocketProxyTunnelStream::new(proxy_stream);
-            let mut proxy_request_builder = Request::builder().uri(&url);
-            proxy_request_builder = proxy_request_builder
-                .header(HOST, format!("{}:{}", target_host, target_port))
-                .header(UPGRADE, "websocket")
-                .header(CONNECTION, "Upgrade")
-                .header(SEC_WEBSOCKET_VERSION, "13")
-                .header(SEC_WEBSOCKET_KEY, Self::generate_websocket_key());
-            for (key, value) in &headers {
-                proxy_request_builder = proxy_request_builder.header(key, value);
-            }
-            let protocols: Vec<String> = self
-                .config
-                .read()
-                .map(|c| c.protocols.clone())
-                .unwrap_or_default();
-            if !protocols.is_empty() {
-                proxy_request_builder =
-                    proxy_request_builder.header("Sec-WebSocket-Protocol", protocols.join(", "));
-            }
-            let proxy_request: Request = proxy_request_builder.body(()).map_err(|e| {
-                WebSocketError::invalid_url(format!("Failed to build proxy request: {}", e))
-            })?;
-            let connect_future = client_async_with_config(proxy_request, proxy_tunnel_stream, None);
-            let (ws_stream, _) = timeout(timeout_duration, connect_future)
+            let proxy_stream: BoxAsyncReadWrite = if is_tls {
+                timeout(
+                    timeout_duration,
+                    self.connect_tls_async(proxy_stream, &target_host),
+                )
                 .await
-                .map_err(|_| WebSocketError::timeout("Connection timeout"))?
-                .map_err(|e| {
-                    let error_msg: String = e.to_string();
-                    if error_msg.contains("tls")
-                        || error_msg.contains("TLS")
-                        || error_msg.contains("ssl")
-                        || error_msg.contains("SSL")
-                        || error_msg.contains("certificate")
-                        || error_msg.contains("handshake")
-                    {
-                        WebSocketError::tls(error_msg)
-                    } else {
-                        WebSocketError::connection(error_msg)
-                    }
-                })?;
+                .map_err(|_| WebSocketError::timeout("Connection timeout"))??
+            } else {
+                proxy_stream
+            };
+            let ws_stream: WebSocketStream<WebSocketProxyTunnelStream> = self
+                .handshake_tunnel_async(
+                    proxy_stream,
+                    &url,
+                    &target_host,
+                    target_port,
+                    &headers,
+                    timeout_duration,
+                )
+                .await?;
             WebSocketConnectionType::Proxy(ws_stream)
+        } else if is_tls {
+            let tls_stream: BoxAsyncReadWrite = timeout(timeout_duration, async {
+                let tcp_stream: AsyncTcpStream =
+                    AsyncTcpStream::connect((target_host.as_str(), target_port))
+                        .await
+                        .map_err(|err| WebSocketError::connection(err.to_string()))?;
+                self.connect_tls_async(Box::new(tcp_stream), &target_host)
+                    .await
+            })
+            .await
+            .map_err(|_| WebSocketError::timeout("Connection timeout"))??;
+            let ws_stream: WebSocketStream<WebSocketProxyTunnelStream> = self
+                .handshake_tunnel_async(
+                    tls_stream,
+                    &url,
+                    &target_host,
+                    target_port,
+                    &headers,
+                    timeout_duration,
+                )
+                .await?;
+            WebSocketConnectionType::Tls(ws_stream)
         } else {
             let connect_future = connect_async_with_config(request, None, false);
             let (ws_stream, _) = timeout(timeout_duration, connect_future)
                 .await
                 .map_err(|_| WebSocketError::timeout("Connection timeout"))?
-                .map_err(|e| {
-                    let error_msg: String = e.to_string();
-                    if error_msg.contains("tls")
-                        || error_msg.contains("TLS")
-                        || error_msg.contains("ssl")
-                        || error_msg.contains("SSL")
-                        || error_msg.contains("certificate")
-                        || error_msg.contains("handshake")
-                    {
-                        WebSocketError::tls(error_msg)
-                    } else {
-                        WebSocketError::connection(error_msg)
-                    }
-                })?;
+                .map_err(Self::map_handshake_error)?;
             WebSocketConnectionType::Direct(ws_stream)
         };
         let mut connection: AsyncMutexGuard<'_, Option<WebSocketConnectionType>> =
@@ -139,6 +125,97 @@ impl WebSocket {
         Ok(())
     }
 
+    fn map_handshake_error(error: tungstenite::Error) -> WebSocketError {
+        let error_msg: String = error.to_string();
+        if error_msg.contains("tls")
+            || error_msg.contains("TLS")
+            || error_msg.contains("ssl")
+            || error_msg.contains("SSL")
+            || error_msg.contains("certificate")
+            || error_msg.contains("handshake")
+        {
+            WebSocketError::tls(error_msg)
+        } else {
+            WebSocketError::connection(error_msg)
+        }
+    }
+
+    fn get_tls_connector(&self, origin: bool) -> Result<TlsConnector, WebSocketError> {
+        let tls: TlsConfig = self
+            .config
+            .read()
+            .map(|config| {
+                if origin {
+                    config.tls.clone()
+                } else {
+                    config.tls.get_proxy_config()
+                }
+            })
+            .map_err(|err| WebSocketError::tls(err.to_string()))?;
+        let tls_config: ClientConfig = tls
+            .build_client_config(Vec::new())
+            .map_err(WebSocketError::tls)?;
+        Ok(TlsConnector::from(Arc::new(tls_config)))
+    }
+
+    async fn connect_tls_async(
+        &self,
+        stream: BoxAsyncReadWrite,
+        host: &str,
+    ) -> Result<BoxAsyncReadWrite, WebSocketError> {
+        let connector: TlsConnector = self.get_tls_connector(true)?;
+        let dns_name: ServerName<'_> = ServerName::try_from(host.to_string())
+            .map_err(|err| WebSocketError::tls(err.to_string()))?;
+        let tls_stream: TlsStream<BoxAsyncReadWrite> = connector
+            .connect(dns_name, stream)
+            .await
+            .map_err(|err| WebSocketError::tls(err.to_string()))?;
+        if let Ok(mut tls_info) = self.tls_info.write() {
+            *tls_info = Some(TlsInfo::from_connection(tls_stream.get_ref().1));
+        }
+        Ok(Box::new(tls_stream))
+    }
+
+    async fn handshake_tunnel_async(
+        &self,
+        stream: BoxAsyncReadWrite,
+        url: &str,
+        target_host: &str,
+        target_port: u16,
+        headers: &[(String, String)],
+        timeout_duration: Duration,
+    ) -> Result<WebSocketStream<WebSocketProxyTunnelStream>, WebSocketError> {
+        let tunnel_stream: WebSocketProxyTunnelStream = WebSocketProxyTunnelStream::new(stream);
+        let mut request_builder = Request::builder().uri(url);
+        request_builder = request_builder
+            .header(HOST, format!("{}:{}", target_host, target_port))
+            .header(UPGRADE, "websocket")
+            .header(CONNECTION, "Upgrade")
+            .header(SEC_WEBSOCKET_VERSION, "13")
+            .header(SEC_WEBSOCKET_KEY, Self::generate_websocket_key());
+        for (key, value) in headers {
+            request_builder = request_builder.header(key, value);
+        }
+        let protocols: Vec<String> = self
+            .config
+            .read()
+            .map(|c| c.protocols.clone())
+            .unwrap_or_default();
+        if !protocols.is_empty() {
+            request_builder =
+                request_builder.header("Sec-WebSocket-Protocol", protocols.join(", "));
+        }
+        let request: Request = request_builder.body(()).map_err(|e| {
+            WebSocketError::invalid_url(format!("Failed to build proxy request: {}", e))
+        })?;
+        let connect_future = client_async_with_config(request, tunnel_stream, None);
+        let (ws_stream, _) = timeout(timeout_duration, connect_future)
+            .await
+            .map_err(|_| WebSocketError::timeout("Connection timeout"))?
+            .map_err(Self::map_handshake_error)?;
+        Ok(ws_stream)
+    }
+
     async fn send_message_async(&self, message: Message) -> Result<(), WebSocketError> {
         if !self.connected.load(Ordering::Relaxed) {
             self.connect_async_internal().await?;
@@ -259,13 +336,7 @@ impl WebSocket {
             .await
             .map_err(|err| WebSocketError::connection(err.to_string()))?;
         let mut proxy_stream: BoxAsyncReadWrite = if proxy_config.proxy_type == ProxyType::Https {
-            let roots: RootCertStore = RootCertStore {
-                roots: TLS_SERVER_ROOTS.iter().cloned().collect(),
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
+            let connector: TlsConnector = self.get_tls_connector(false)?;
             let dns_name: ServerName<'_> = ServerName::try_from(proxy_config.host.clone())
                 .map_err(|err| WebSocketError::tls(err.to_string()))?;
             let tls_stream: TlsStream<AsyncTcpStream> = connector
@@ -484,6 +555,13 @@ impl WebSocket {
         self.connected.load(Ordering::Relaxed)
     }
 
+    pub fn get_tls_info(&self) -> Option<TlsInfo> {
+        self.tls_info
+            .read()
+            .ok()
+            .and_then(|tls_info| tls_info.clone())
+    }
+
     pub async fn send_text_async(&mut self, text: &str) -> WebSocketResult {
         let message: Message = Message::Text(text.into());
         self.send_message_async(message).await
@@ -513,6 +591,19 @@ impl WebSocket {
     }
 }
 
+impl Debug for WebSocket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebSocket")
+            .field("url", &self.url)
+            .field("header", &redact_headers(&self.header))
+            .field("config", &self.config)
+            .field("connected", &self.connected)
+            .field("connection", &self.connection)
+            .field("tls_info", &self.tls_info)
+            .finish()
+    }
+}
+
 impl WebSocketTrait for WebSocket {
     fn send_text(&mut self, text: &str) -> WebSocketResult {
         self.send_text(text)
@@ -541,6 +632,10 @@ impl WebSocketTrait for WebSocket {
     fn is_connected(&self) -> bool {
         self.is_connected()
     }
+
+    fn tls_info(&self) -> Option<TlsInfo> {
+        self.get_tls_info()
+    }
 }
 
 impl AsyncWebSocketTrait for WebSocket {
@@ -583,4 +678,8 @@ impl AsyncWebSocketTrait for WebSocket {
     fn is_connected(&self) -> bool {
         self.is_connected()
     }
+
+    fn tls_info(&self) -> Option<TlsInfo> {
+        self.get_tls_info()
+    }
 }
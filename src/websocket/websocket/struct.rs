@@ -4,15 +4,16 @@ use crate::*;
 pub enum WebSocketConnectionType {
     Direct(WebSocketStream<MaybeTlsStream<AsyncTcpStream>>),
     Proxy(WebSocketStream<WebSocketProxyTunnelStream>),
+    Tls(WebSocketStream<WebSocketProxyTunnelStream>),
 }
 
-#[derive(Debug)]
 pub struct WebSocket {
     pub(crate) url: Arc<String>,
     pub(crate) header: Arc<RequestHeaders>,
     pub(crate) config: ArcRwLock<WebSocketConfig>,
     pub(crate) connected: Arc<AtomicBool>,
     pub(crate) connection: WebSocketConnection,
+    pub(crate) tls_info: ArcRwLock<Option<TlsInfo>>,
 }
 
 impl Clone for WebSocket {
@@ -23,6 +24,7 @@ impl Clone for WebSocket {
             config: self.config.clone(),
             connected: Arc::new(AtomicBool::new(false)),
             connection: Arc::new(AsyncMutex::new(None)),
+            tls_info: Arc::new(RwLock::new(None)),
         }
     }
 }
@@ -35,6 +37,7 @@ impl Default for WebSocket {
             config: Arc::new(RwLock::new(WebSocketConfig::default())),
             connected: Arc::new(AtomicBool::new(false)),
             connection: Arc::new(AsyncMutex::new(None)),
+            tls_info: Arc::new(RwLock::new(None)),
         }
     }
 }
@@ -47,6 +50,7 @@ impl Stream for WebSocketConnectionType {
         match &mut *self {
             WebSocketConnectionType::Direct(stream) => Pin::new(stream).poll_next(cx),
             WebSocketConnectionType::Proxy(stream) => Pin::new(stream).poll_next(cx),
+            WebSocketConnectionType::Tls(stream) => Pin::new(stream).poll_next(cx),
         }
     }
 }
@@ -58,6 +62,7 @@ impl Sink<tokio_tungstenite::tungstenite::Message> for WebSocketConnectionType {
         match &mut *self {
             WebSocketConnectionType::Direct(stream) => Pin::new(stream).poll_ready(cx),
             WebSocketConnectionType::Proxy(stream) => Pin::new(stream).poll_ready(cx),
+            WebSocketConnectionType::Tls(stream) => Pin::new(stream).poll_ready(cx),
         }
     }
 
@@ -68,6 +73,7 @@ impl Sink<tokio_tungstenite::tungstenite::Message> for WebSocketConnectionType {
         match &mut *self {
             WebSocketConnectionType::Direct(stream) => Pin::new(stream).start_send(item),
             WebSocketConnectionType::Proxy(stream) => Pin::new(stream).start_send(item),
+            WebSocketConnectionType::Tls(stream) => Pin::new(stream).start_send(item),
         }
     }
 
@@ -75,6 +81,7 @@ impl Sink<tokio_tungstenite::tungstenite::Message> for WebSocketConnectionType {
         match &mut *self {
             WebSocketConnectionType::Direct(stream) => Pin::new(stream).poll_flush(cx),
             WebSocketConnectionType::Proxy(stream) => Pin::new(stream).poll_flush(cx),
+            WebSocketConnectionType::Tls(stream) => Pin::new(stream).poll_flush(cx),
         }
     }
 
@@ -82,6 +89,7 @@ impl Sink<tokio_tungstenite::tungstenite::Message> for WebSocketConnectionType {
         match &mut *self {
             WebSocketConnectionType::Direct(stream) => Pin::new(stream).poll_close(cx),
             WebSocketConnectionType::Proxy(stream) => Pin::new(stream).poll_close(cx),
+            WebSocketConnectionType::Tls(stream) => Pin::new(stream).poll_close(cx),
         }
     }
 }
@@ -8,6 +8,7 @@ pub trait WebSocketTrait: Send + Sync {
     fn receive(&mut self) -> WebSocketMessageResult;
     fn close(&mut self) -> WebSocketResult;
     fn is_connected(&self) -> bool;
+    fn tls_info(&self) -> Option<TlsInfo>;
 }
 
 pub trait AsyncWebSocketTrait: Send + Sync {
@@ -32,4 +33,5 @@ pub trait AsyncWebSocketTrait: Send + Sync {
     ) -> Pin<Box<dyn Future<Output = WebSocketMessageResult> + Send + 'a>>;
     fn close<'a>(&'a mut self) -> Pin<Box<dyn Future<Output = WebSocketResult> + Send + 'a>>;
     fn is_connected(&self) -> bool;
+    fn tls_info(&self) -> Option<TlsInfo>;
 }
@@ -39,6 +39,18 @@ impl WebSocketBuilder {
         self
     }
 
+    pub fn basic_auth(&mut self, username: &str, password: &str) -> &mut Self {
+        let mut header: HashMapXxHash3_64<&str, String> = hash_map_xx_hash3_64();
+        header.insert(AUTHORIZATION, basic_authorization(username, password));
+        self.headers(header)
+    }
+
+    pub fn bearer_auth(&mut self, token: &str) -> &mut Self {
+        let mut header: HashMapXxHash3_64<&str, String> = hash_map_xx_hash3_64();
+        header.insert(AUTHORIZATION, format!("Bearer {}", token));
+        self.headers(header)
+    }
+
     pub fn timeout(&mut self, timeout: u64) -> &mut Self {
         if let Ok(mut config) = self.websocket.config.write() {
             config.timeout = timeout;
@@ -60,6 +72,92 @@ impl WebSocketBuilder {
         self
     }
 
+    pub fn add_root_certificate_pem<T: AsRef<[u8]>>(&mut self, pem: T) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.add_root_certificate_pem(pem.as_ref());
+        }
+        self
+    }
+
+    pub fn add_root_certificate_der<T: AsRef<[u8]>>(&mut self, der: T) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.add_root_certificate_der(der.as_ref());
+        }
+        self
+    }
+
+    pub fn add_root_certificates_from_path<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.add_root_certificates_from_path(path.as_ref());
+        }
+        self
+    }
+
+    pub fn tls_built_in_root_certs(&mut self, enable: bool) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.built_in_root_certs(enable);
+        }
+        self
+    }
+
+    pub fn client_certificate_pem<C, K>(
+        &mut self,
+        cert_chain_pem: C,
+        private_key_pem: K,
+    ) -> &mut Self
+    where
+        C: AsRef<[u8]>,
+        K: AsRef<[u8]>,
+    {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config
+                .tls
+                .client_certificate_pem(cert_chain_pem.as_ref(), private_key_pem.as_ref());
+        }
+        self
+    }
+
+    pub fn client_certificate_der<K: AsRef<[u8]>>(
+        &mut self,
+        cert_chain_der: Vec<Vec<u8>>,
+        private_key_der: K,
+    ) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config
+                .tls
+                .client_certificate_der(cert_chain_der, private_key_der.as_ref());
+        }
+        self
+    }
+
+    pub fn danger_accept_invalid_certs(&mut self, accept: bool) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.accept_invalid_certs = accept;
+        }
+        self
+    }
+
+    pub fn danger_accept_invalid_hostnames(&mut self, accept: bool) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.accept_invalid_hostnames = accept;
+        }
+        self
+    }
+
+    pub fn pin_public_key_sha256(&mut self, pin: &str) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.pin_public_key_sha256(pin);
+        }
+        self
+    }
+
+    pub fn pin_certificate<T: AsRef<[u8]>>(&mut self, certificate: T) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.pin_certificate(certificate.as_ref());
+        }
+        self
+    }
+
     pub fn http_proxy(&mut self, host: &str, port: u16) -> &mut Self {
         if let Ok(mut config) = self.websocket.config.write() {
             config.proxy = Some(ProxyConfig {